@@ -0,0 +1,47 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! Simple benchmark for serializing interleaved data messages.
+//!
+//! Run with `cargo bench --bench data`.
+
+use std::time::Instant;
+
+const ITERATIONS: usize = 1_000_000;
+
+fn main() {
+    let payload = vec![0u8; 1400];
+    let data = rtsp_types::Data::new(0, &payload[..]);
+
+    let mut buf = Vec::with_capacity(rtsp_types::Data::frame_len(payload.len()));
+
+    let start = Instant::now();
+    let mut total = 0;
+    for _ in 0..ITERATIONS {
+        buf.clear();
+        data.write(&mut buf).unwrap();
+        total += buf.len();
+    }
+    let elapsed = start.elapsed();
+    assert_eq!(
+        total,
+        ITERATIONS * rtsp_types::Data::frame_len(payload.len())
+    );
+    println!(
+        "write with pre-sized buffer: {:?} per frame",
+        elapsed / ITERATIONS as u32
+    );
+
+    let start = Instant::now();
+    let mut total = 0;
+    for _ in 0..ITERATIONS {
+        total += data.write_len();
+    }
+    let elapsed = start.elapsed();
+    assert_eq!(
+        total,
+        (ITERATIONS * rtsp_types::Data::frame_len(payload.len())) as u64
+    );
+    println!("write_len: {:?} per frame", elapsed / ITERATIONS as u32);
+}
@@ -0,0 +1,87 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use rtsp_types::headers::*;
+use rtsp_types::{Message, Method, Request, Version};
+
+fn check_typed_header<H: TypedHeader>(headers: &Headers) {
+    if let Ok(Some(header)) = H::from_headers(headers) {
+        // Serializing and parsing again must not panic either
+        let request = Request::builder(Method::Options, Version::V2_0)
+            .typed_header(&header)
+            .empty();
+        let _unused_result = request.typed_header::<H>();
+    }
+}
+
+fn check_typed_headers(headers: &Headers) {
+    check_typed_header::<Accept>(headers);
+    check_typed_header::<AcceptEncoding>(headers);
+    check_typed_header::<AcceptRanges>(headers);
+    check_typed_header::<Allow>(headers);
+    check_typed_header::<Bandwidth>(headers);
+    check_typed_header::<Blocksize>(headers);
+    check_typed_header::<CacheControl>(headers);
+    check_typed_header::<ContentBase>(headers);
+    check_typed_header::<ContentEncoding>(headers);
+    check_typed_header::<ContentLength>(headers);
+    check_typed_header::<ContentLocation>(headers);
+    check_typed_header::<ContentType>(headers);
+    check_typed_header::<CSeq>(headers);
+    check_typed_header::<Date>(headers);
+    check_typed_header::<Expires>(headers);
+    check_typed_header::<IfModifiedSince>(headers);
+    check_typed_header::<IfNoneMatch>(headers);
+    check_typed_header::<LastModified>(headers);
+    check_typed_header::<Location>(headers);
+    check_typed_header::<MediaProperties>(headers);
+    check_typed_header::<MediaRange>(headers);
+    check_typed_header::<NotifyReason>(headers);
+    check_typed_header::<NotifyReasonParams>(headers);
+    check_typed_header::<PipelinedRequests>(headers);
+    check_typed_header::<Public>(headers);
+    check_typed_header::<Range>(headers);
+    check_typed_header::<Referrer>(headers);
+    check_typed_header::<Require>(headers);
+    check_typed_header::<RtpInfos>(headers);
+    check_typed_header::<Scale>(headers);
+    check_typed_header::<SeekStyle>(headers);
+    check_typed_header::<Session>(headers);
+    check_typed_header::<Speed>(headers);
+    check_typed_header::<Supported>(headers);
+    check_typed_header::<Timestamp>(headers);
+    check_typed_header::<Transports>(headers);
+    check_typed_header::<Unsupported>(headers);
+    check_typed_header::<Via>(headers);
+    check_typed_header::<WwwAuthenticate>(headers);
+}
+
+fuzz_target!(|data: &[u8]| {
+    match Message::<&[u8]>::parse(data) {
+        Ok((Message::Request(request), _)) => {
+            check_typed_headers(request.as_ref());
+            let _unused_result = request.validate();
+        }
+        Ok((Message::Response(response), _)) => {
+            check_typed_headers(response.as_ref());
+            let _unused_result = response.validate();
+        }
+        _ => (),
+    }
+
+    // Also pass the input directly as value of every well-known header to reach the typed
+    // header parsers without having to find a valid message first
+    if let Ok(value) = std::str::from_utf8(data) {
+        let mut request = Request::builder(Method::Options, Version::V2_0).empty();
+        let headers = AsMut::<Headers>::as_mut(&mut request);
+        // Values with control characters are rejected for all headers
+        if well_known_headers()
+            .iter()
+            .all(|name| headers.try_insert(name.as_str(), value).is_ok())
+        {
+            check_typed_headers(request.as_ref());
+        }
+
+        let _unused_result = Range::parse_lenient(value);
+    }
+});
@@ -0,0 +1,356 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! Digest authentication helpers ([RFC 7616](https://tools.ietf.org/html/rfc7616)).
+//!
+//! RTSP uses the HTTP authentication framework, see
+//! [RFC 7826 section 19](https://tools.ietf.org/html/rfc7826#section-19).
+//!
+//! ## Computing the response for a Digest challenge
+//!
+//! ```rust
+//! use rtsp_types::auth::{DigestAlgorithm, DigestParams, DigestQop};
+//!
+//! let params = DigestParams {
+//!     algorithm: DigestAlgorithm::Sha256,
+//!     qop: Some(DigestQop::Auth),
+//!     cnonce: String::from("f2/wE4q74E6zIJEtWaHKaf5wv/H5QzzpXusqGemxURZJ"),
+//!     nc: 1,
+//!     ..DigestParams::new("http-auth@example.org", "7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v")
+//! };
+//!
+//! let response = params.compute_response(
+//!     &rtsp_types::Method::Describe,
+//!     "rtsp://example.com/media",
+//!     "Mufasa",
+//!     "Circle of Life",
+//!     b"",
+//! );
+//! assert_eq!(response.len(), 64);
+//! ```
+
+use crate::headers::HeaderParseError;
+use crate::Method;
+
+use md5::Digest;
+
+use std::fmt;
+
+/// Digest hash algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DigestAlgorithm {
+    /// `MD5`.
+    Md5,
+    /// `MD5-sess`.
+    Md5Sess,
+    /// `SHA-256`.
+    Sha256,
+    /// `SHA-256-sess`.
+    Sha256Sess,
+}
+
+impl DigestAlgorithm {
+    /// Name of the algorithm as used in the `algorithm` parameter.
+    pub fn as_str(&self) -> &str {
+        match self {
+            DigestAlgorithm::Md5 => "MD5",
+            DigestAlgorithm::Md5Sess => "MD5-sess",
+            DigestAlgorithm::Sha256 => "SHA-256",
+            DigestAlgorithm::Sha256Sess => "SHA-256-sess",
+        }
+    }
+
+    fn is_sess(&self) -> bool {
+        matches!(self, DigestAlgorithm::Md5Sess | DigestAlgorithm::Sha256Sess)
+    }
+
+    fn hash(&self, data: &[u8]) -> String {
+        match self {
+            DigestAlgorithm::Md5 | DigestAlgorithm::Md5Sess => hex(&md5::Md5::digest(data)),
+            DigestAlgorithm::Sha256 | DigestAlgorithm::Sha256Sess => {
+                hex(&sha2::Sha256::digest(data))
+            }
+        }
+    }
+}
+
+/// Lowercase hex representation of a digest.
+fn hex(digest: &[u8]) -> String {
+    use std::fmt::Write;
+
+    let mut s = String::with_capacity(2 * digest.len());
+    for b in digest {
+        write!(&mut s, "{b:02x}").unwrap();
+    }
+    s
+}
+
+impl fmt::Display for DigestAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for DigestAlgorithm {
+    type Err = HeaderParseError;
+
+    fn from_str(s: &str) -> Result<Self, HeaderParseError> {
+        if s.eq_ignore_ascii_case("MD5") {
+            Ok(DigestAlgorithm::Md5)
+        } else if s.eq_ignore_ascii_case("MD5-sess") {
+            Ok(DigestAlgorithm::Md5Sess)
+        } else if s.eq_ignore_ascii_case("SHA-256") {
+            Ok(DigestAlgorithm::Sha256)
+        } else if s.eq_ignore_ascii_case("SHA-256-sess") {
+            Ok(DigestAlgorithm::Sha256Sess)
+        } else {
+            Err(HeaderParseError::new())
+        }
+    }
+}
+
+/// Digest quality of protection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DigestQop {
+    /// `auth`.
+    Auth,
+    /// `auth-int`, which also protects the message body.
+    AuthInt,
+}
+
+impl DigestQop {
+    /// Name of the quality of protection as used in the `qop` parameter.
+    pub fn as_str(&self) -> &str {
+        match self {
+            DigestQop::Auth => "auth",
+            DigestQop::AuthInt => "auth-int",
+        }
+    }
+}
+
+impl fmt::Display for DigestQop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for DigestQop {
+    type Err = HeaderParseError;
+
+    fn from_str(s: &str) -> Result<Self, HeaderParseError> {
+        if s.eq_ignore_ascii_case("auth") {
+            Ok(DigestQop::Auth)
+        } else if s.eq_ignore_ascii_case("auth-int") {
+            Ok(DigestQop::AuthInt)
+        } else {
+            Err(HeaderParseError::new())
+        }
+    }
+}
+
+/// Parameters for computing the response to a Digest challenge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigestParams {
+    /// Realm from the challenge.
+    pub realm: String,
+    /// Nonce from the challenge.
+    pub nonce: String,
+    /// Hash algorithm from the challenge.
+    pub algorithm: DigestAlgorithm,
+    /// Selected quality of protection.
+    ///
+    /// `None` for challenges without `qop`, as defined by the obsolete
+    /// [RFC 2069](https://tools.ietf.org/html/rfc2069).
+    pub qop: Option<DigestQop>,
+    /// Client nonce.
+    ///
+    /// Only used with a quality of protection or a `-sess` algorithm.
+    pub cnonce: String,
+    /// Nonce count, i.e. the number of requests sent with this nonce including the current one.
+    ///
+    /// Only used with a quality of protection.
+    pub nc: u32,
+}
+
+impl DigestParams {
+    /// Creates new parameters for the given realm and nonce.
+    ///
+    /// This uses the `MD5` algorithm without quality of protection.
+    pub fn new<R: Into<String>, N: Into<String>>(realm: R, nonce: N) -> Self {
+        DigestParams {
+            realm: realm.into(),
+            nonce: nonce.into(),
+            algorithm: DigestAlgorithm::Md5,
+            qop: None,
+            cnonce: String::new(),
+            nc: 1,
+        }
+    }
+
+    /// Computes the `response` parameter for the `Authorization` header of a request.
+    ///
+    /// `body` is only used with [`DigestQop::AuthInt`].
+    pub fn compute_response(
+        &self,
+        method: &Method,
+        uri: &str,
+        username: &str,
+        password: &str,
+        body: &[u8],
+    ) -> String {
+        let algorithm = self.algorithm;
+
+        let mut ha1 = algorithm.hash(format!("{username}:{}:{password}", self.realm).as_bytes());
+        if algorithm.is_sess() {
+            ha1 = algorithm.hash(format!("{ha1}:{}:{}", self.nonce, self.cnonce).as_bytes());
+        }
+
+        let method = <&str>::from(method);
+        let ha2 = match self.qop {
+            Some(DigestQop::AuthInt) => {
+                algorithm.hash(format!("{method}:{uri}:{}", algorithm.hash(body)).as_bytes())
+            }
+            _ => algorithm.hash(format!("{method}:{uri}").as_bytes()),
+        };
+
+        match self.qop {
+            Some(qop) => algorithm.hash(
+                format!(
+                    "{ha1}:{}:{:08x}:{}:{qop}:{ha2}",
+                    self.nonce, self.nc, self.cnonce
+                )
+                .as_bytes(),
+            ),
+            None => algorithm.hash(format!("{ha1}:{}:{ha2}", self.nonce).as_bytes()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digest_rfc7616() {
+        // RFC 7616 section 3.9.1
+        let get = Method::Extension(String::from("GET"));
+
+        let mut params = DigestParams {
+            qop: Some(DigestQop::Auth),
+            cnonce: String::from("f2/wE4q74E6zIJEtWaHKaf5wv/H5QzzpXusqGemxURZJ"),
+            nc: 1,
+            ..DigestParams::new(
+                "http-auth@example.org",
+                "7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v",
+            )
+        };
+        assert_eq!(
+            params.compute_response(&get, "/dir/index.html", "Mufasa", "Circle of Life", b""),
+            "8ca523f5e9506fed4657c9700eebdbec"
+        );
+
+        params.algorithm = DigestAlgorithm::Sha256;
+        assert_eq!(
+            params.compute_response(&get, "/dir/index.html", "Mufasa", "Circle of Life", b""),
+            "753927fa0e85d155564e2e272a28d1802ca10daf4496794697cf8db5856cb6c1"
+        );
+    }
+
+    #[test]
+    fn test_digest_rfc2617() {
+        // RFC 2617 section 3.5
+        let get = Method::Extension(String::from("GET"));
+
+        let params = DigestParams {
+            qop: Some(DigestQop::Auth),
+            cnonce: String::from("0a4f113b"),
+            nc: 1,
+            ..DigestParams::new("testrealm@host.com", "dcd98b7102dd2f0e8b11d0f600bfb0c093")
+        };
+        assert_eq!(
+            params.compute_response(&get, "/dir/index.html", "Mufasa", "Circle Of Life", b""),
+            "6629fae49393a05397450978507c4ef1"
+        );
+    }
+
+    #[test]
+    fn test_digest_variants() {
+        let params = DigestParams::new("testrealm@host.com", "dcd98b7102dd2f0e8b11d0f600bfb0c093");
+
+        let compute = |params: &DigestParams, body: &[u8]| {
+            params.compute_response(
+                &Method::Describe,
+                "rtsp://example.com/media",
+                "Mufasa",
+                "Circle Of Life",
+                body,
+            )
+        };
+
+        // Without qop the body, nonce count and client nonce are not used
+        let legacy = compute(&params, b"");
+        assert_eq!(legacy.len(), 32);
+        assert_eq!(
+            compute(
+                &DigestParams {
+                    nc: 2,
+                    cnonce: String::from("abc"),
+                    ..params.clone()
+                },
+                b"body"
+            ),
+            legacy
+        );
+
+        // The body is only used with auth-int
+        let auth = DigestParams {
+            qop: Some(DigestQop::Auth),
+            cnonce: String::from("0a4f113b"),
+            ..params.clone()
+        };
+        assert_ne!(compute(&auth, b""), legacy);
+        assert_eq!(compute(&auth, b"body"), compute(&auth, b""));
+        assert_ne!(
+            compute(
+                &DigestParams {
+                    nc: 2,
+                    ..auth.clone()
+                },
+                b""
+            ),
+            compute(&auth, b"")
+        );
+
+        let auth_int = DigestParams {
+            qop: Some(DigestQop::AuthInt),
+            ..auth.clone()
+        };
+        assert_ne!(compute(&auth_int, b"body"), compute(&auth_int, b""));
+
+        // -sess algorithms also depend on the client nonce
+        let sess = DigestParams {
+            algorithm: DigestAlgorithm::Sha256Sess,
+            ..params.clone()
+        };
+        assert_eq!(compute(&sess, b"").len(), 64);
+        assert_ne!(
+            compute(&sess, b""),
+            compute(
+                &DigestParams {
+                    cnonce: String::from("abc"),
+                    ..sess.clone()
+                },
+                b""
+            )
+        );
+
+        assert_eq!(
+            "md5-SESS".parse::<DigestAlgorithm>(),
+            Ok(DigestAlgorithm::Md5Sess)
+        );
+        assert!("SHA-512".parse::<DigestAlgorithm>().is_err());
+        assert_eq!("auth-int".parse::<DigestQop>(), Ok(DigestQop::AuthInt));
+        assert_eq!(DigestAlgorithm::Sha256Sess.to_string(), "SHA-256-sess");
+    }
+}
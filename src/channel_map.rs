@@ -0,0 +1,179 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+use crate::headers::Transports;
+use std::collections::BTreeMap;
+
+/// Kind of an interleaved channel of an RTP transport.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChannelKind {
+    /// RTP channel, or RTP and RTCP if they are muxed on the same channel.
+    Rtp,
+    /// RTCP channel.
+    Rtcp,
+}
+
+/// Map from interleaved channel ids to arbitrary values.
+///
+/// This allows routing [`Data`](struct.Data.html) messages received over RTSP-over-TCP to e.g. the
+/// corresponding RTP session.
+///
+/// ## Routing interleaved data
+///
+/// ```rust
+/// use rtsp_types::headers::{RtpLowerTransport, RtpProfile, RtpTransport, Transport, Transports};
+/// use rtsp_types::{ChannelKind, ChannelMap, Data};
+///
+/// let transports = Transports::from(vec![Transport::Rtp(
+///     RtpTransport::builder(RtpProfile::Avp)
+///         .lower_transport(RtpLowerTransport::Tcp)
+///         .interleaved(0, Some(1))
+///         .build(),
+/// )]);
+///
+/// let mut map = ChannelMap::new();
+/// map.insert_channel_pairs(&transports, |kind| ("video", kind));
+///
+/// let data = Data::new(1, Vec::from(&b"rtcp"[..]));
+/// assert_eq!(map.route(&data), Some(&("video", ChannelKind::Rtcp)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChannelMap<T>(BTreeMap<u8, T>);
+
+impl<T> ChannelMap<T> {
+    /// Creates a new, empty channel map.
+    pub fn new() -> Self {
+        ChannelMap(BTreeMap::new())
+    }
+
+    /// Inserts a value for a channel.
+    ///
+    /// If the channel already had a value then it is replaced and returned.
+    pub fn insert(&mut self, channel_id: u8, value: T) -> Option<T> {
+        self.0.insert(channel_id, value)
+    }
+
+    /// Inserts values for the RTP and RTCP channels of all interleaved RTP transports.
+    ///
+    /// The values are created by `func` for each channel. See
+    /// [`Transports::channel_pairs`](headers/struct.Transports.html#method.channel_pairs) for
+    /// which channels are used.
+    pub fn insert_channel_pairs<F: FnMut(ChannelKind) -> T>(
+        &mut self,
+        transports: &Transports,
+        mut func: F,
+    ) {
+        for (rtp, rtcp) in transports.channel_pairs() {
+            self.0.insert(rtp, func(ChannelKind::Rtp));
+            if let Some(rtcp) = rtcp {
+                self.0.insert(rtcp, func(ChannelKind::Rtcp));
+            }
+        }
+    }
+
+    /// Gets the value for a channel.
+    pub fn get(&self, channel_id: u8) -> Option<&T> {
+        self.0.get(&channel_id)
+    }
+
+    /// Gets a mutable reference to the value for a channel.
+    pub fn get_mut(&mut self, channel_id: u8) -> Option<&mut T> {
+        self.0.get_mut(&channel_id)
+    }
+
+    /// Removes the value for a channel and returns it.
+    pub fn remove(&mut self, channel_id: u8) -> Option<T> {
+        self.0.remove(&channel_id)
+    }
+
+    /// Checks if the channel has a value.
+    pub fn contains(&self, channel_id: u8) -> bool {
+        self.0.contains_key(&channel_id)
+    }
+
+    /// Gets the value for the channel of a data message.
+    pub fn route<Body>(&self, data: &Data<Body>) -> Option<&T> {
+        self.get(data.channel_id())
+    }
+
+    /// Gets a mutable reference to the value for the channel of a data message.
+    pub fn route_mut<Body>(&mut self, data: &Data<Body>) -> Option<&mut T> {
+        self.get_mut(data.channel_id())
+    }
+
+    /// Number of channels with a value.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Checks if no channel has a value.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterator over all channels and their values, ordered by channel id.
+    pub fn iter(&self) -> impl Iterator<Item = (u8, &T)> {
+        self.0
+            .iter()
+            .map(|(channel_id, value)| (*channel_id, value))
+    }
+}
+
+impl<T> Default for ChannelMap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_channel_map() {
+        let request = Request::builder(Method::Setup, Version::V2_0)
+            .header(
+                crate::headers::TRANSPORT,
+                "RTP/AVP/TCP;unicast;interleaved=0-1, RTP/AVP/TCP;unicast;interleaved=4;RTCP-mux, \
+                 RTP/AVP;unicast;client_port=8000-8001, RTP/AVP/TCP;unicast;interleaved=6-9",
+            )
+            .empty();
+        let transports = request.typed_header::<Transports>().unwrap().unwrap();
+        assert_eq!(transports.channel_pairs(), vec![(0, Some(1)), (4, None)]);
+        assert_eq!(transports.assigned_channels(), vec![0..=1, 4..=4, 6..=9]);
+
+        let mut map = ChannelMap::new();
+        assert!(map.is_empty());
+        map.insert_channel_pairs(&transports, |kind| (String::from("stream"), kind, 0));
+        assert_eq!(
+            map.iter()
+                .map(|(id, (_, kind, _))| (id, *kind))
+                .collect::<Vec<_>>(),
+            vec![
+                (0, ChannelKind::Rtp),
+                (1, ChannelKind::Rtcp),
+                (4, ChannelKind::Rtp),
+            ]
+        );
+
+        let data = Data::new(1, Vec::from(&b"rtcp"[..]));
+        let route = map.route_mut(&data).unwrap();
+        assert_eq!(route.1, ChannelKind::Rtcp);
+        route.2 += data.len();
+        assert_eq!(map.get(1).unwrap().2, 4);
+
+        assert!(map.route(&Data::new(2, Vec::<u8>::new())).is_none());
+
+        assert!(map
+            .insert(2, (String::from("other"), ChannelKind::Rtp, 0))
+            .is_none());
+        assert!(map.contains(2));
+        assert_eq!(map.remove(0).unwrap().1, ChannelKind::Rtp);
+        assert!(map.get(0).is_none());
+        assert_eq!(map.len(), 3);
+    }
+}
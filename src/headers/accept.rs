@@ -7,7 +7,7 @@ use super::*;
 use std::fmt;
 
 /// `Accept` header ([RFC 7826 section 18.1](https://tools.ietf.org/html/rfc7826#section-18.1)).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Accept(Vec<MediaTypeRange>);
 
@@ -23,10 +23,28 @@ pub struct MediaTypeRange {
     ///
     /// `None` for `*`.
     pub subtype: Option<String>,
-    /// Media type parameters.
+    /// Media type parameters, in the order they appear in the header.
+    ///
+    /// Quoted values are stored including the quotes and escapes, e.g. `"a,b"`.
     pub params: Vec<(String, Option<String>)>,
 }
 
+impl MediaTypeRange {
+    /// Quality of this media type range from its `q` parameter.
+    ///
+    /// Returns 1.0 if there is no valid `q` parameter.
+    pub fn quality(&self) -> f32 {
+        use super::parser_helpers::parse_quality;
+
+        self.params
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("q"))
+            .and_then(|(_, value)| value.as_deref())
+            .and_then(parse_quality)
+            .unwrap_or(1.0)
+    }
+}
+
 /// Media type.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -50,7 +68,7 @@ impl MediaType {
             MediaType::Video => "video",
             MediaType::Application => "application",
             MediaType::Message => "message",
-            MediaType::Multipart => "Multipart",
+            MediaType::Multipart => "multipart",
             MediaType::Extension(ref s) => s.as_str(),
         }
     }
@@ -62,10 +80,20 @@ impl fmt::Display for MediaType {
     }
 }
 
+/// Parses a media type.
+///
+/// Unknown media types are returned as [`MediaType::Extension`]. This fails if the media type is
+/// not a valid token, e.g. because it contains a `/`.
 impl std::str::FromStr for MediaType {
     type Err = HeaderParseError;
 
     fn from_str(s: &str) -> Result<Self, HeaderParseError> {
+        use super::parser_helpers::token;
+
+        if !matches!(token(s.as_bytes()), Ok((rem, t)) if rem.is_empty() && !t.is_empty()) {
+            return Err(HeaderParseError::new());
+        }
+
         match s {
             "text" => Ok(MediaType::Text),
             "image" => Ok(MediaType::Image),
@@ -117,6 +145,12 @@ impl<'a> From<&'a [MediaTypeRange]> for Accept {
     }
 }
 
+impl std::iter::FromIterator<MediaTypeRange> for Accept {
+    fn from_iter<I: IntoIterator<Item = MediaTypeRange>>(iter: I) -> Self {
+        Accept(iter.into_iter().collect())
+    }
+}
+
 impl Accept {
     /// Creates a new `Accept` header builder.
     pub fn builder() -> AcceptBuilder {
@@ -143,7 +177,7 @@ impl AcceptBuilder {
 
 impl super::TypedHeader for Accept {
     fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError> {
-        use super::parser_helpers::split_once;
+        use super::parser_helpers::{parse_quality, quoted_string, split_once, split_unquoted};
 
         let headers = headers.as_ref();
 
@@ -153,13 +187,16 @@ impl super::TypedHeader for Accept {
         };
 
         let mut media_types = Vec::new();
-        for media_type_range in header.as_str().split(',') {
+        for media_type_range in split_unquoted(header.as_str(), ',') {
             let media_type_range = media_type_range.trim();
 
-            let mut iter = media_type_range.split(';');
-            let media_type = iter.next().ok_or(HeaderParseError)?.trim();
+            let mut iter = split_unquoted(media_type_range, ';').into_iter();
+            let media_type = iter
+                .next()
+                .ok_or_else(|| HeaderParseError::for_header(ACCEPT))?
+                .trim();
             let (media_type, media_subtype) =
-                split_once(media_type, '/').ok_or(HeaderParseError)?;
+                split_once(media_type, '/').ok_or_else(|| HeaderParseError::for_header(ACCEPT))?;
 
             let media_type = if media_type == "*" {
                 None
@@ -176,8 +213,19 @@ impl super::TypedHeader for Accept {
             for param in iter {
                 let param = param.trim();
                 if let Some((param, value)) = split_once(param, '=') {
+                    if param.eq_ignore_ascii_case("q") && parse_quality(value).is_none() {
+                        return Err(HeaderParseError::for_header(ACCEPT));
+                    }
+                    if value.starts_with('"')
+                        && !matches!(quoted_string(value.as_bytes()), Ok((rem, _)) if rem.is_empty())
+                    {
+                        return Err(HeaderParseError::for_header(ACCEPT));
+                    }
                     params.push((String::from(param), Some(String::from(value))));
                 } else {
+                    if param.eq_ignore_ascii_case("q") {
+                        return Err(HeaderParseError::for_header(ACCEPT));
+                    }
                     params.push((String::from(param), None));
                 }
             }
@@ -186,7 +234,7 @@ impl super::TypedHeader for Accept {
                 type_: media_type
                     .map(|s| s.parse())
                     .transpose()
-                    .map_err(|_| HeaderParseError)?,
+                    .map_err(|_| HeaderParseError::for_header(ACCEPT))?,
                 subtype: media_subtype.map(String::from),
                 params,
             });
@@ -269,3 +317,129 @@ impl super::TypedAppendableHeader for Accept {
         headers.append(ACCEPT, media_types);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accept_quality() {
+        let request = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+            .header(
+                ACCEPT,
+                "application/sdp, application/example;q=0.5, text/*;Q=0, */*;q=1.000;level=1",
+            )
+            .empty();
+
+        let accept = request.typed_header::<Accept>().unwrap().unwrap();
+        assert_eq!(
+            accept.iter().map(|m| m.quality()).collect::<Vec<_>>(),
+            [1.0, 0.5, 0.0, 1.0]
+        );
+
+        for q in ["0", "0.", "0.1", "0.12", "0.123", "1", "1.", "1.0", "1.000"] {
+            let request = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+                .header(ACCEPT, format!("application/sdp;q={q}"))
+                .empty();
+            assert!(request.typed_header::<Accept>().is_ok(), "{}", q);
+        }
+
+        for q in [
+            "", "0.1234", "1.001", "2", "-0.5", "0,5", ".5", "00.5", "0.5e1", "abc", "1.0.0",
+        ] {
+            let request = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+                .header(ACCEPT, format!("application/sdp;q={q}"))
+                .empty();
+            assert!(request.typed_header::<Accept>().is_err(), "{}", q);
+        }
+
+        let request = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+            .header(ACCEPT, "application/sdp;q")
+            .empty();
+        assert!(request.typed_header::<Accept>().is_err());
+
+        let media_type = MediaTypeRange {
+            type_: Some(MediaType::Application),
+            subtype: Some(String::from("sdp")),
+            params: vec![(String::from("q"), None)],
+        };
+        assert_eq!(media_type.quality(), 1.0);
+    }
+
+    #[test]
+    fn test_accept_params_round_trip() {
+        let header = "application/sdp;q=0.5;level=1, text/*;charset=\"utf-8\";q=0, \
+                      application/example;name=\"a,b;c \\\"d\\\"\";level";
+
+        let request = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+            .header(ACCEPT, header)
+            .empty();
+        let accept = request.typed_header::<Accept>().unwrap().unwrap();
+
+        assert_eq!(accept.len(), 3);
+        assert_eq!(
+            accept[0].params,
+            vec![
+                (String::from("q"), Some(String::from("0.5"))),
+                (String::from("level"), Some(String::from("1"))),
+            ]
+        );
+        assert_eq!(
+            accept[1].params,
+            vec![
+                (String::from("charset"), Some(String::from("\"utf-8\""))),
+                (String::from("q"), Some(String::from("0"))),
+            ]
+        );
+        assert_eq!(
+            accept[2].params,
+            vec![
+                (
+                    String::from("name"),
+                    Some(String::from("\"a,b;c \\\"d\\\"\""))
+                ),
+                (String::from("level"), None),
+            ]
+        );
+        assert_eq!(
+            accept.iter().map(|m| m.quality()).collect::<Vec<_>>(),
+            [0.5, 0.0, 1.0]
+        );
+
+        let request2 = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+            .typed_header(&accept)
+            .empty();
+        assert_eq!(request2.header(&ACCEPT).unwrap().as_str(), header);
+        assert_eq!(request2.typed_header::<Accept>().unwrap().unwrap(), accept);
+
+        for header in ["application/sdp;name=\"abc", "application/sdp;name=\"a\"bc"] {
+            let request = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+                .header(ACCEPT, header)
+                .empty();
+            assert!(request.typed_header::<Accept>().is_err(), "{}", header);
+        }
+    }
+
+    #[test]
+    fn test_media_type_from_str() {
+        for media_type in [
+            MediaType::Text,
+            MediaType::Image,
+            MediaType::Audio,
+            MediaType::Video,
+            MediaType::Application,
+            MediaType::Message,
+            MediaType::Multipart,
+            MediaType::Extension(String::from("x-custom")),
+        ] {
+            assert_eq!(
+                media_type.to_string().parse::<MediaType>(),
+                Ok(media_type.clone())
+            );
+        }
+
+        for s in ["a/b", "", "a b", "a;b", "a,b", "\"a\""] {
+            assert!(s.parse::<MediaType>().is_err(), "{}", s);
+        }
+    }
+}
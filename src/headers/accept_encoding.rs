@@ -0,0 +1,272 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+/// `Accept-Encoding` header ([RFC 7826 section 18.3](https://tools.ietf.org/html/rfc7826#section-18.3)).
+///
+/// Each content coding has an optional quality value from its `q` parameter. Quality values are
+/// stored in thousandths, i.e. `q=0.5` is stored as `500`, as they have at most three decimals.
+///
+/// ## Checking for an acceptable content coding
+///
+/// ```rust
+/// use rtsp_types::headers::AcceptEncoding;
+///
+/// let request = rtsp_types::Request::builder(
+///         rtsp_types::Method::Describe,
+///         rtsp_types::Version::V2_0
+///     )
+///     .header(rtsp_types::headers::ACCEPT_ENCODING, "gzip;q=0.5, deflate;q=0")
+///     .empty();
+///
+/// let accept_encoding = request.typed_header::<AcceptEncoding>().unwrap().unwrap();
+/// assert!(accept_encoding.contains("gzip"));
+/// assert!(!accept_encoding.contains("deflate"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AcceptEncoding(Vec<(String, Option<u16>)>);
+
+impl std::ops::Deref for AcceptEncoding {
+    type Target = Vec<(String, Option<u16>)>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for AcceptEncoding {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<Vec<(String, Option<u16>)>> for AcceptEncoding {
+    fn as_ref(&self) -> &Vec<(String, Option<u16>)> {
+        &self.0
+    }
+}
+
+impl AsMut<Vec<(String, Option<u16>)>> for AcceptEncoding {
+    fn as_mut(&mut self) -> &mut Vec<(String, Option<u16>)> {
+        &mut self.0
+    }
+}
+
+impl From<Vec<(String, Option<u16>)>> for AcceptEncoding {
+    fn from(v: Vec<(String, Option<u16>)>) -> Self {
+        AcceptEncoding(v)
+    }
+}
+
+impl<'a> From<&'a [(String, Option<u16>)]> for AcceptEncoding {
+    fn from(v: &'a [(String, Option<u16>)]) -> Self {
+        AcceptEncoding(v.to_vec())
+    }
+}
+
+impl std::iter::FromIterator<(String, Option<u16>)> for AcceptEncoding {
+    fn from_iter<I: IntoIterator<Item = (String, Option<u16>)>>(iter: I) -> Self {
+        AcceptEncoding(iter.into_iter().collect())
+    }
+}
+
+impl AcceptEncoding {
+    /// Quality of the content coding.
+    ///
+    /// This falls back to the `*` entry if the content coding is not listed explicitly. A listed
+    /// content coding without `q` parameter has a quality of 1.0.
+    pub fn quality(&self, coding: &str) -> Option<f32> {
+        self.0
+            .iter()
+            .find(|(c, _)| c.eq_ignore_ascii_case(coding))
+            .or_else(|| self.0.iter().find(|(c, _)| c == "*"))
+            .map(|(_, q)| q.map(|q| f32::from(q.min(1000)) / 1000.0).unwrap_or(1.0))
+    }
+
+    /// Check if the content coding is acceptable.
+    ///
+    /// This is the case if it has a non-zero [`quality`](#method.quality). `identity` is always
+    /// acceptable unless excluded explicitly or via `*;q=0`.
+    pub fn contains(&self, coding: &str) -> bool {
+        match self.quality(coding) {
+            Some(q) => q > 0.0,
+            None => coding.eq_ignore_ascii_case("identity"),
+        }
+    }
+
+    fn to_header_value(&self) -> String {
+        let mut value = String::new();
+        for (coding, q) in &self.0 {
+            if !value.is_empty() {
+                value.push_str(", ");
+            }
+            value.push_str(coding);
+            match *q {
+                None => (),
+                Some(0) => value.push_str(";q=0"),
+                // Quality values above 1.0 are not valid
+                Some(q) if q >= 1000 => value.push_str(";q=1"),
+                Some(q) => {
+                    let q = format!("{:03}", q);
+                    value.push_str(";q=0.");
+                    value.push_str(q.trim_end_matches('0'));
+                }
+            }
+        }
+
+        value
+    }
+}
+
+impl super::TypedHeader for AcceptEncoding {
+    fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError> {
+        use super::parser_helpers::{parse_quality, split_once, split_unquoted, token};
+
+        let headers = headers.as_ref();
+
+        let header = match headers.get(&ACCEPT_ENCODING) {
+            None => return Ok(None),
+            Some(header) => header,
+        };
+
+        // An empty header is valid and means that only `identity` is acceptable
+        let mut codings = Vec::new();
+        for coding in split_unquoted(header.as_str(), ',')
+            .into_iter()
+            .map(str::trim)
+        {
+            if coding.is_empty() {
+                continue;
+            }
+
+            let (coding, q) = match split_once(coding, ';') {
+                None => (coding, None),
+                Some((coding, param)) => {
+                    let (name, value) = split_once(param.trim(), '=')
+                        .ok_or_else(|| HeaderParseError::for_header(ACCEPT_ENCODING))?;
+                    if !name.trim().eq_ignore_ascii_case("q") {
+                        return Err(HeaderParseError::for_header(ACCEPT_ENCODING));
+                    }
+                    let q = parse_quality(value.trim())
+                        .ok_or_else(|| HeaderParseError::for_header(ACCEPT_ENCODING))?;
+
+                    (coding.trim(), Some((q * 1000.0).round() as u16))
+                }
+            };
+
+            match token(coding.as_bytes()) {
+                Ok(([], t)) if !t.is_empty() => (),
+                _ => return Err(HeaderParseError::for_header(ACCEPT_ENCODING)),
+            }
+
+            codings.push((String::from(coding), q));
+        }
+
+        Ok(Some(AcceptEncoding(codings)))
+    }
+
+    fn insert_into(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+        headers.insert(ACCEPT_ENCODING, self.to_header_value());
+    }
+}
+
+impl super::TypedAppendableHeader for AcceptEncoding {
+    fn append_to(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+        headers.append(ACCEPT_ENCODING, self.to_header_value());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accept_encoding() {
+        let request = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+            .header(ACCEPT_ENCODING, "gzip;q=0.5, deflate, br;q=0, *;q=0.1")
+            .empty();
+
+        let accept_encoding = request.typed_header::<AcceptEncoding>().unwrap().unwrap();
+        assert_eq!(
+            *accept_encoding,
+            vec![
+                (String::from("gzip"), Some(500)),
+                (String::from("deflate"), None),
+                (String::from("br"), Some(0)),
+                (String::from("*"), Some(100)),
+            ]
+        );
+        assert!(accept_encoding.contains("GZIP"));
+        assert!(accept_encoding.contains("deflate"));
+        assert!(!accept_encoding.contains("br"));
+        assert_eq!(accept_encoding.quality("compress"), Some(0.1));
+        assert!(accept_encoding.contains("compress"));
+
+        let request2 = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+            .typed_header(&accept_encoding)
+            .empty();
+        assert_eq!(
+            request2.header(&ACCEPT_ENCODING).unwrap().as_str(),
+            "gzip;q=0.5, deflate, br;q=0, *;q=0.1"
+        );
+
+        let request = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+            .header(ACCEPT_ENCODING, "")
+            .empty();
+        let accept_encoding = request.typed_header::<AcceptEncoding>().unwrap().unwrap();
+        assert!(accept_encoding.is_empty());
+        assert!(accept_encoding.contains("identity"));
+        assert!(!accept_encoding.contains("gzip"));
+
+        let accept_encoding = AcceptEncoding::from(vec![(String::from("*"), Some(0))]);
+        assert!(!accept_encoding.contains("identity"));
+
+        let accept_encoding = AcceptEncoding::from(vec![
+            (String::from("gzip"), Some(1500)),
+            (String::from("deflate"), Some(1000)),
+            (String::from("br"), Some(123)),
+        ]);
+        assert_eq!(accept_encoding.quality("gzip"), Some(1.0));
+        let request = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+            .typed_header(&accept_encoding)
+            .empty();
+        assert_eq!(
+            request.header(&ACCEPT_ENCODING).unwrap().as_str(),
+            "gzip;q=1, deflate;q=1, br;q=0.123"
+        );
+        assert!(request.typed_header::<AcceptEncoding>().is_ok());
+
+        let request = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+            .header(ACCEPT_ENCODING, "gzip;q=0.001, deflate;q=1.000")
+            .empty();
+        assert_eq!(
+            *request.typed_header::<AcceptEncoding>().unwrap().unwrap(),
+            vec![
+                (String::from("gzip"), Some(1)),
+                (String::from("deflate"), Some(1000)),
+            ]
+        );
+
+        for header in [
+            "gzip;q=1.5",
+            "gzip;q=-0.5",
+            "gzip;q=0.1234",
+            "gzip;q=",
+            "gzip;q",
+            "gzip;level=1",
+            "gz ip",
+            ";q=0.5",
+        ] {
+            let request = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+                .header(ACCEPT_ENCODING, header)
+                .empty();
+            let err = request.typed_header::<AcceptEncoding>().unwrap_err();
+            assert_eq!(err.header_name(), Some(&ACCEPT_ENCODING), "{}", header);
+        }
+    }
+}
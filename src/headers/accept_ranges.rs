@@ -7,7 +7,7 @@ use super::*;
 use std::fmt;
 
 /// `Accept-Ranges` header ([RFC 7826 section 18.5](https://tools.ietf.org/html/rfc7826#section-18.5)).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AcceptRanges(Vec<RangeUnit>);
 
@@ -101,6 +101,12 @@ impl<'a> From<&'a [RangeUnit]> for AcceptRanges {
     }
 }
 
+impl std::iter::FromIterator<RangeUnit> for AcceptRanges {
+    fn from_iter<I: IntoIterator<Item = RangeUnit>>(iter: I) -> Self {
+        AcceptRanges(iter.into_iter().collect())
+    }
+}
+
 impl AcceptRanges {
     /// Creates a new `Accept-Ranges` header builder.
     pub fn builder() -> AcceptRangesBuilder {
@@ -119,6 +125,31 @@ impl AcceptRangesBuilder {
         self
     }
 
+    /// Add the "npt" range unit to the `Accept-Ranges` header.
+    pub fn npt(self) -> Self {
+        self.range(RangeUnit::Npt)
+    }
+
+    /// Add the "smpte" range unit to the `Accept-Ranges` header.
+    pub fn smpte(self) -> Self {
+        self.range(RangeUnit::Smpte)
+    }
+
+    /// Add the "smpte-30-drop" range unit to the `Accept-Ranges` header.
+    pub fn smpte_30_drop(self) -> Self {
+        self.range(RangeUnit::Smpte30Drop)
+    }
+
+    /// Add the "smpte-25" range unit to the `Accept-Ranges` header.
+    pub fn smpte_25(self) -> Self {
+        self.range(RangeUnit::Smpte25)
+    }
+
+    /// Add the "clock" range unit to the `Accept-Ranges` header.
+    pub fn clock(self) -> Self {
+        self.range(RangeUnit::Clock)
+    }
+
     /// Build the `Accept-Ranges` header.
     pub fn build(self) -> AcceptRanges {
         AcceptRanges(self.0)
@@ -138,7 +169,11 @@ impl super::TypedHeader for AcceptRanges {
         for range in header.as_str().split(',') {
             let range = range.trim();
 
-            ranges.push(range.parse()?);
+            ranges.push(
+                range
+                    .parse()
+                    .map_err(|_| HeaderParseError::for_header(ACCEPT_RANGES))?,
+            );
         }
 
         Ok(Some(AcceptRanges(ranges)))
@@ -176,3 +211,22 @@ impl super::TypedAppendableHeader for AcceptRanges {
         headers.append(ACCEPT_RANGES, ranges);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accept_ranges_builder() {
+        let accept_ranges = AcceptRanges::builder().npt().clock().build();
+        assert_eq!(*accept_ranges, vec![RangeUnit::Npt, RangeUnit::Clock]);
+
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .typed_header(&accept_ranges)
+            .empty();
+        assert_eq!(
+            response.header(&ACCEPT_RANGES).unwrap().as_str(),
+            "npt, clock"
+        );
+    }
+}
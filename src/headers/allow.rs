@@ -6,7 +6,7 @@ use super::*;
 use crate::Method;
 
 /// `Allow` header ([RFC 7826 section 18.6](https://tools.ietf.org/html/rfc7826#section-18.6)).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Allow(Vec<Method>);
 
@@ -53,6 +53,30 @@ impl Allow {
     pub fn builder() -> AllowBuilder {
         AllowBuilder(Vec::new())
     }
+
+    /// Check if the method is allowed.
+    pub fn contains(&self, method: &Method) -> bool {
+        self.0.iter().any(|m| m == method)
+    }
+
+    /// Check if all the methods are allowed.
+    pub fn allows_all(&self, methods: &[Method]) -> bool {
+        methods.iter().all(|method| self.contains(method))
+    }
+
+    /// Gets the `Allow` header from `headers`, ignoring the case of known methods.
+    ///
+    /// This maps e.g. `Get_Parameter` to [`Method::GetParameter`] instead of an extension method.
+    ///
+    /// This can't fail as unknown methods are kept as extension methods, so `None` is only returned
+    /// if the header is missing.
+    pub fn from_headers_lenient(headers: impl AsRef<Headers>) -> Option<Self> {
+        let headers = headers.as_ref();
+
+        headers
+            .get(&ALLOW)
+            .map(|header| Allow(split_methods(header.as_str(), true)))
+    }
 }
 
 /// Builder for the 'Allow' header.
@@ -81,45 +105,89 @@ impl super::TypedHeader for Allow {
             Some(header) => header,
         };
 
-        let mut allow = Vec::new();
-        for method in header.as_str().split(',') {
-            let method = method.trim();
-
-            allow.push(method.into());
-        }
-
-        Ok(Some(Allow(allow)))
+        Ok(Some(Allow(split_methods(header.as_str(), false))))
     }
 
     fn insert_into(&self, mut headers: impl AsMut<Headers>) {
         let headers = headers.as_mut();
-
-        let mut allow = String::new();
-        for method in &self.0 {
-            if !allow.is_empty() {
-                allow.push_str(", ");
-            }
-
-            allow.push_str(method.into());
-        }
-
-        headers.insert(ALLOW, allow);
+        headers.insert(ALLOW, join_methods(&self.0));
     }
 }
 
 impl super::TypedAppendableHeader for Allow {
     fn append_to(&self, mut headers: impl AsMut<Headers>) {
         let headers = headers.as_mut();
+        headers.append(ALLOW, join_methods(&self.0));
+    }
+}
 
-        let mut allow = String::new();
-        for method in &self.0 {
-            if !allow.is_empty() {
-                allow.push_str(", ");
-            }
-
-            allow.push_str(method.into());
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allow_lenient_methods() {
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .header(
+                ALLOW,
+                "Describe, get_parameter,SETUP, Play_Notify , X_Custom",
+            )
+            .header(PUBLIC, "OPTIONS, Set_Parameter")
+            .empty();
+
+        let allow = Allow::from_headers_lenient(&response).unwrap();
+        assert_eq!(
+            *allow,
+            vec![
+                Method::Describe,
+                Method::GetParameter,
+                Method::Setup,
+                Method::PlayNotify,
+                Method::Extension(String::from("X_Custom")),
+            ]
+        );
+
+        // Without lenient parsing only the exact spelling is a known method
+        let allow = response.typed_header::<Allow>().unwrap().unwrap();
+        assert_eq!(allow[0], Method::Extension(String::from("Describe")));
+        assert_eq!(allow[2], Method::Setup);
+
+        let public = Public::from_headers_lenient(&response).unwrap();
+        assert_eq!(*public, vec![Method::Options, Method::SetParameter]);
+
+        let request = crate::Request::builder(crate::Method::Options, crate::Version::V2_0).empty();
+        assert!(Allow::from_headers_lenient(&request).is_none());
+    }
 
-        headers.append(ALLOW, allow);
+    #[test]
+    fn test_allow_contains_and_append() {
+        let response =
+            crate::Response::builder(crate::Version::V2_0, crate::StatusCode::MethodNotAllowed)
+                .header(ALLOW, "SETUP, PLAY, ")
+                .empty();
+
+        let allow = response.typed_header::<Allow>().unwrap().unwrap();
+        assert_eq!(*allow, vec![Method::Setup, Method::Play]);
+        assert!(allow.contains(&Method::Play));
+        assert!(!allow.contains(&Method::Record));
+        assert!(!allow.contains(&Method::Extension(String::new())));
+        assert!(allow.allows_all(&[Method::Setup, Method::Play]));
+        assert!(!allow.allows_all(&[Method::Setup, Method::Record]));
+        assert!(allow.allows_all(&[]));
+
+        // Merge the values of multiple upstream responses
+        let mut merged =
+            crate::Response::builder(crate::Version::V2_0, crate::StatusCode::MethodNotAllowed)
+                .empty();
+        merged.append_typed_header(&allow);
+        merged.append_typed_header(&Allow::builder().method(Method::Teardown).build());
+        assert_eq!(
+            merged.header(&ALLOW).unwrap().as_str(),
+            "SETUP, PLAY, TEARDOWN"
+        );
+
+        let merged = merged.typed_header::<Allow>().unwrap().unwrap();
+        assert_eq!(*merged, vec![Method::Setup, Method::Play, Method::Teardown]);
+        assert!(merged.contains(&Method::Teardown));
     }
 }
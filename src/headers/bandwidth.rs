@@ -0,0 +1,76 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+/// `Bandwidth` header ([RFC 7826 section 18.8](https://tools.ietf.org/html/rfc7826#section-18.8)).
+///
+/// The bandwidth is given in bits per second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bandwidth(u64);
+
+impl std::ops::Deref for Bandwidth {
+    type Target = u64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for Bandwidth {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<u64> for Bandwidth {
+    fn as_ref(&self) -> &u64 {
+        &self.0
+    }
+}
+
+impl AsMut<u64> for Bandwidth {
+    fn as_mut(&mut self) -> &mut u64 {
+        &mut self.0
+    }
+}
+
+impl From<u64> for Bandwidth {
+    fn from(v: u64) -> Bandwidth {
+        Bandwidth(v)
+    }
+}
+
+impl From<Bandwidth> for u64 {
+    fn from(v: Bandwidth) -> u64 {
+        v.0
+    }
+}
+
+impl super::TypedHeader for Bandwidth {
+    fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError> {
+        let headers = headers.as_ref();
+
+        let header = match headers.get(&BANDWIDTH) {
+            None => return Ok(None),
+            Some(header) => header,
+        };
+
+        let bandwidth = header
+            .as_str()
+            .trim()
+            .parse::<u64>()
+            .map(Bandwidth)
+            .map_err(|_| HeaderParseError::for_header(BANDWIDTH))?;
+
+        Ok(Some(bandwidth))
+    }
+
+    fn insert_into(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+
+        headers.insert(BANDWIDTH, self.0.to_string());
+    }
+}
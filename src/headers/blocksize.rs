@@ -0,0 +1,76 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+/// `Blocksize` header ([RFC 7826 section 18.10](https://tools.ietf.org/html/rfc7826#section-18.10)).
+///
+/// The block size is given in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Blocksize(u32);
+
+impl std::ops::Deref for Blocksize {
+    type Target = u32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for Blocksize {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<u32> for Blocksize {
+    fn as_ref(&self) -> &u32 {
+        &self.0
+    }
+}
+
+impl AsMut<u32> for Blocksize {
+    fn as_mut(&mut self) -> &mut u32 {
+        &mut self.0
+    }
+}
+
+impl From<u32> for Blocksize {
+    fn from(v: u32) -> Blocksize {
+        Blocksize(v)
+    }
+}
+
+impl From<Blocksize> for u32 {
+    fn from(v: Blocksize) -> u32 {
+        v.0
+    }
+}
+
+impl super::TypedHeader for Blocksize {
+    fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError> {
+        let headers = headers.as_ref();
+
+        let header = match headers.get(&BLOCKSIZE) {
+            None => return Ok(None),
+            Some(header) => header,
+        };
+
+        let blocksize = header
+            .as_str()
+            .trim()
+            .parse::<u32>()
+            .map(Blocksize)
+            .map_err(|_| HeaderParseError::for_header(BLOCKSIZE))?;
+
+        Ok(Some(blocksize))
+    }
+
+    fn insert_into(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+
+        headers.insert(BLOCKSIZE, self.0.to_string());
+    }
+}
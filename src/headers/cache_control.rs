@@ -0,0 +1,227 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+/// `Cache-Control` header ([RFC 7826 section 18.11](https://tools.ietf.org/html/rfc7826#section-18.11)).
+///
+/// The directives are kept in the order they appear in the header, including duplicates.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CacheControl(Vec<CacheDirective>);
+
+/// Cache directive, e.g. `no-cache` or `max-age=60`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CacheDirective {
+    /// Name of the directive.
+    pub name: String,
+    /// Optional value of the directive.
+    pub value: Option<String>,
+}
+
+impl CacheDirective {
+    /// Creates a new cache directive without value.
+    pub fn new<S: Into<String>>(name: S) -> Self {
+        CacheDirective {
+            name: name.into(),
+            value: None,
+        }
+    }
+
+    /// Creates a new cache directive with a value.
+    pub fn with_value<S: Into<String>, V: Into<String>>(name: S, value: V) -> Self {
+        CacheDirective {
+            name: name.into(),
+            value: Some(value.into()),
+        }
+    }
+}
+
+impl std::ops::Deref for CacheControl {
+    type Target = Vec<CacheDirective>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for CacheControl {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<Vec<CacheDirective>> for CacheControl {
+    fn as_ref(&self) -> &Vec<CacheDirective> {
+        &self.0
+    }
+}
+
+impl AsMut<Vec<CacheDirective>> for CacheControl {
+    fn as_mut(&mut self) -> &mut Vec<CacheDirective> {
+        &mut self.0
+    }
+}
+
+impl From<Vec<CacheDirective>> for CacheControl {
+    fn from(v: Vec<CacheDirective>) -> Self {
+        CacheControl(v)
+    }
+}
+
+impl<'a> From<&'a [CacheDirective]> for CacheControl {
+    fn from(v: &'a [CacheDirective]) -> Self {
+        CacheControl(v.to_vec())
+    }
+}
+
+impl CacheControl {
+    /// Creates a new `Cache-Control` header builder.
+    pub fn builder() -> CacheControlBuilder {
+        CacheControlBuilder(Vec::new())
+    }
+
+    /// Get the first directive with the given name.
+    ///
+    /// Directive names are compared case-insensitively.
+    pub fn get(&self, name: &str) -> Option<&CacheDirective> {
+        self.0.iter().find(|d| d.name.eq_ignore_ascii_case(name))
+    }
+
+    fn header_value(&self) -> String {
+        let mut directives = String::new();
+        for directive in &self.0 {
+            if !directives.is_empty() {
+                directives.push_str(", ");
+            }
+
+            directives.push_str(&directive.name);
+            if let Some(ref value) = directive.value {
+                directives.push('=');
+                directives.push_str(value);
+            }
+        }
+
+        directives
+    }
+}
+
+/// Builder for the 'Cache-Control' header.
+#[derive(Debug, Clone)]
+pub struct CacheControlBuilder(Vec<CacheDirective>);
+
+impl CacheControlBuilder {
+    /// Add the provided directive to the `Cache-Control` header.
+    pub fn directive(mut self, directive: CacheDirective) -> Self {
+        self.0.push(directive);
+        self
+    }
+
+    /// Build the `Cache-Control` header.
+    pub fn build(self) -> CacheControl {
+        CacheControl(self.0)
+    }
+}
+
+impl super::TypedHeader for CacheControl {
+    fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError> {
+        use super::parser_helpers::{split_once, split_unquoted};
+
+        let headers = headers.as_ref();
+
+        let header = match headers.get(&CACHE_CONTROL) {
+            None => return Ok(None),
+            Some(header) => header,
+        };
+
+        let mut directives = Vec::new();
+        for directive in split_unquoted(header.as_str(), ',') {
+            let directive = directive.trim();
+            if directive.is_empty() {
+                return Err(HeaderParseError::for_header(CACHE_CONTROL));
+            }
+
+            if let Some((name, value)) = split_once(directive, '=') {
+                directives.push(CacheDirective::with_value(name.trim(), value.trim()));
+            } else {
+                directives.push(CacheDirective::new(directive));
+            }
+        }
+
+        Ok(Some(CacheControl(directives)))
+    }
+
+    fn insert_into(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+        headers.insert(CACHE_CONTROL, self.header_value());
+    }
+}
+
+impl super::TypedAppendableHeader for CacheControl {
+    fn append_to(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+        headers.append(CACHE_CONTROL, self.header_value());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_control() {
+        let header = "no-cache, max-age=60, no-store, Max-Age=30";
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .header(CACHE_CONTROL, header)
+            .empty();
+
+        let cache_control = response.typed_header::<CacheControl>().unwrap().unwrap();
+
+        assert_eq!(
+            cache_control,
+            CacheControl::builder()
+                .directive(CacheDirective::new("no-cache"))
+                .directive(CacheDirective::with_value("max-age", "60"))
+                .directive(CacheDirective::new("no-store"))
+                .directive(CacheDirective::with_value("Max-Age", "30"))
+                .build()
+        );
+        assert_eq!(
+            cache_control.get("max-age"),
+            Some(&CacheDirective::with_value("max-age", "60"))
+        );
+        assert_eq!(
+            cache_control.get("no-store"),
+            Some(&CacheDirective::new("no-store"))
+        );
+        assert_eq!(cache_control.get("private"), None);
+
+        let response2 = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .typed_header(&cache_control)
+            .empty();
+
+        assert_eq!(response, response2);
+
+        // Commas inside quoted strings are part of the value
+        let header = "no-cache=\"Session, Range\", private";
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .header(CACHE_CONTROL, header)
+            .empty();
+
+        let cache_control = response.typed_header::<CacheControl>().unwrap().unwrap();
+        assert_eq!(
+            cache_control,
+            CacheControl::builder()
+                .directive(CacheDirective::with_value("no-cache", "\"Session, Range\""))
+                .directive(CacheDirective::new("private"))
+                .build()
+        );
+
+        let response2 = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .typed_header(&cache_control)
+            .empty();
+        assert_eq!(response, response2);
+    }
+}
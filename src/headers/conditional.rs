@@ -0,0 +1,149 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+/// `If-Modified-Since` and `If-None-Match` headers for conditional requests.
+///
+/// Clients usually set both together for validating a cached description, so this bundles the
+/// [`IfModifiedSince`] and [`IfNoneMatch`] typed headers. Inserting it replaces both headers, or
+/// removes them if unset.
+///
+/// ## Conditional `DESCRIBE` request
+///
+/// ```rust
+/// use rtsp_types::headers::{ConditionalRequest, DateTime, IfNoneMatch};
+///
+/// let conditional = ConditionalRequest {
+///     if_modified_since: DateTime::new(1994, 10, 29, 19, 43, 31).map(Into::into),
+///     if_none_match: Some(IfNoneMatch::from(vec![String::from("\"xyzzy\"")])),
+/// };
+///
+/// let mut request = rtsp_types::Request::builder(
+///         rtsp_types::Method::Describe,
+///         rtsp_types::Version::V2_0
+///     )
+///     .empty();
+/// conditional.insert_into(&mut request);
+///
+/// assert_eq!(
+///     request.header(&rtsp_types::headers::IF_MODIFIED_SINCE).unwrap().as_str(),
+///     "Sat, 29 Oct 1994 19:43:31 GMT"
+/// );
+/// assert_eq!(
+///     request.header(&rtsp_types::headers::IF_NONE_MATCH).unwrap().as_str(),
+///     "\"xyzzy\""
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConditionalRequest {
+    /// Date of the cached entity.
+    pub if_modified_since: Option<IfModifiedSince>,
+    /// Entity tags of the cached entities.
+    pub if_none_match: Option<IfNoneMatch>,
+}
+
+impl ConditionalRequest {
+    /// Returns `true` if neither header is set.
+    pub fn is_empty(&self) -> bool {
+        self.if_modified_since.is_none() && self.if_none_match.is_none()
+    }
+
+    /// Parses both headers from `headers`.
+    ///
+    /// Missing headers are `None`.
+    pub fn from_headers(headers: impl AsRef<Headers>) -> Result<Self, HeaderParseError> {
+        let headers = headers.as_ref();
+
+        Ok(ConditionalRequest {
+            if_modified_since: IfModifiedSince::from_headers(headers)?,
+            if_none_match: IfNoneMatch::from_headers(headers)?,
+        })
+    }
+
+    /// Inserts both headers into `headers`, or removes them if unset.
+    pub fn insert_into(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+
+        match self.if_modified_since {
+            Some(ref if_modified_since) => if_modified_since.insert_into(&mut *headers),
+            None => headers.remove(&IF_MODIFIED_SINCE),
+        }
+
+        match self.if_none_match {
+            Some(ref if_none_match) => if_none_match.insert_into(&mut *headers),
+            None => headers.remove(&IF_NONE_MATCH),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conditional_describe() {
+        let conditional = ConditionalRequest {
+            if_modified_since: DateTime::new(1994, 10, 29, 19, 43, 31).map(IfModifiedSince::from),
+            if_none_match: Some(IfNoneMatch::from(vec![
+                String::from("\"xyzzy\""),
+                String::from("W/\"r2d2,xxxx\""),
+            ])),
+        };
+
+        let mut request = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+            .request_uri(crate::Url::parse("rtsp://example.com/media").unwrap())
+            .header(CSEQ, "1")
+            .empty();
+        conditional.insert_into(&mut request);
+
+        assert_eq!(
+            request.header(&IF_MODIFIED_SINCE).unwrap().as_str(),
+            "Sat, 29 Oct 1994 19:43:31 GMT"
+        );
+        assert_eq!(
+            request.header(&IF_NONE_MATCH).unwrap().as_str(),
+            "\"xyzzy\", W/\"r2d2,xxxx\""
+        );
+        assert_eq!(ConditionalRequest::from_headers(&request), Ok(conditional));
+
+        // Only one of both headers
+        let request = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+            .header(IF_NONE_MATCH, " * ")
+            .empty();
+        assert_eq!(
+            ConditionalRequest::from_headers(&request),
+            Ok(ConditionalRequest {
+                if_modified_since: None,
+                if_none_match: Some(IfNoneMatch::from(vec![String::from("*")])),
+            })
+        );
+
+        // Unset fields remove the headers
+        let mut request = request;
+        ConditionalRequest {
+            if_modified_since: DateTime::new(1994, 10, 29, 19, 43, 31).map(IfModifiedSince::from),
+            if_none_match: None,
+        }
+        .insert_into(&mut request);
+        assert!(request.header(&IF_NONE_MATCH).is_none());
+        ConditionalRequest::default().insert_into(&mut request);
+        assert!(ConditionalRequest::from_headers(&request)
+            .unwrap()
+            .is_empty());
+
+        for (name, value) in [(IF_NONE_MATCH, "xyzzy"), (IF_MODIFIED_SINCE, "29 Oct 1994")] {
+            let request = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+                .header(name.clone(), value)
+                .empty();
+            assert_eq!(
+                ConditionalRequest::from_headers(&request)
+                    .unwrap_err()
+                    .header_name(),
+                Some(&name)
+            );
+        }
+    }
+}
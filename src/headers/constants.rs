@@ -70,3 +70,119 @@ pub const UNSUPPORTED: HeaderName = HeaderName::from_static_str_unchecked("Unsup
 pub const USER_AGENT: HeaderName = HeaderName::from_static_str_unchecked("User-Agent");
 pub const VIA: HeaderName = HeaderName::from_static_str_unchecked("Via");
 pub const WWW_AUTHENTICATE: HeaderName = HeaderName::from_static_str_unchecked("WWW-Authenticate");
+
+static WELL_KNOWN_HEADERS: [HeaderName; 58] = [
+    ACCEPT,
+    ACCEPT_CREDENTIALS,
+    ACCEPT_ENCODING,
+    ACCEPT_LANGUAGE,
+    ACCEPT_RANGES,
+    ALLOW,
+    AUTHENTICATION_INFO,
+    AUTHORIZATION,
+    BANDWIDTH,
+    BLOCKSIZE,
+    CACHE_CONTROL,
+    CONNECTION,
+    CONNECTION_CREDENTIALS,
+    CONTENT_BASE,
+    CONTENT_ENCODING,
+    CONTENT_LANGUAGE,
+    CONTENT_LENGTH,
+    CONTENT_LOCATION,
+    CONTENT_TYPE,
+    CSEQ,
+    DATE,
+    EXPIRES,
+    FROM,
+    IF_MATCH,
+    IF_MODIFIED_SINCE,
+    IF_NONE_MATCH,
+    LAST_MODIFIED,
+    LOCATION,
+    MEDIA_PROPERTIES,
+    MEDIA_RANGE,
+    MTAG,
+    NOTIFY_REASON,
+    PIPELINED_REQUESTS,
+    PROXY_AUTHENTICATE,
+    PROXY_AUTHENTICATION_INFO,
+    PROXY_AUTHORIZATION,
+    PROXY_REQUIRE,
+    PROXY_SUPPORTED,
+    PUBLIC,
+    RANGE,
+    REFERRER,
+    REQUEST_STATUS,
+    REQUIRE,
+    RETRY_AFTER,
+    RTP_INFO,
+    SCALE,
+    SEEK_STYLE,
+    SERVER,
+    SESSION,
+    SPEED,
+    SUPPORTED,
+    TERMINATE_REASON,
+    TIMESTAMP,
+    TRANSPORT,
+    UNSUPPORTED,
+    USER_AGENT,
+    VIA,
+    WWW_AUTHENTICATE,
+];
+
+/// Returns all header names for which this crate provides a constant.
+pub fn well_known_headers() -> &'static [HeaderName] {
+    &WELL_KNOWN_HEADERS
+}
+
+/// Well-known headers whose value is a comma separated list as defined in
+/// [RFC 7826 section 5.2](https://tools.ietf.org/html/rfc7826#section-5.2).
+static LIST_HEADERS: [HeaderName; 24] = [
+    ACCEPT,
+    ACCEPT_ENCODING,
+    ACCEPT_LANGUAGE,
+    ACCEPT_RANGES,
+    ALLOW,
+    CACHE_CONTROL,
+    CONNECTION,
+    CONTENT_ENCODING,
+    CONTENT_LANGUAGE,
+    IF_MATCH,
+    IF_NONE_MATCH,
+    MEDIA_PROPERTIES,
+    MEDIA_RANGE,
+    PROXY_AUTHENTICATE,
+    PROXY_REQUIRE,
+    PROXY_SUPPORTED,
+    PUBLIC,
+    REQUIRE,
+    RTP_INFO,
+    SUPPORTED,
+    TRANSPORT,
+    UNSUPPORTED,
+    VIA,
+    WWW_AUTHENTICATE,
+];
+
+/// Checks if the header is a well-known header whose value is a comma separated list.
+pub(crate) fn is_list_header(name: &HeaderName) -> bool {
+    LIST_HEADERS.contains(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_well_known_headers() {
+        let headers = well_known_headers();
+
+        assert_eq!(headers.len(), 58);
+        assert!(headers.contains(&CSEQ));
+        assert!(headers.contains(&TRANSPORT));
+        assert!(headers.contains(&HeaderName::from_static_str("cseq").unwrap()));
+        assert!(!headers.contains(&HeaderName::from_static_str("X-Custom").unwrap()));
+    }
+}
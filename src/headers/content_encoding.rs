@@ -0,0 +1,144 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+/// `Content-Encoding` header ([RFC 7826 section 18.15](https://tools.ietf.org/html/rfc7826#section-18.15)).
+///
+/// Content codings are listed in the order they were applied.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContentEncoding(Vec<String>);
+
+impl std::ops::Deref for ContentEncoding {
+    type Target = Vec<String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for ContentEncoding {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<Vec<String>> for ContentEncoding {
+    fn as_ref(&self) -> &Vec<String> {
+        &self.0
+    }
+}
+
+impl AsMut<Vec<String>> for ContentEncoding {
+    fn as_mut(&mut self) -> &mut Vec<String> {
+        &mut self.0
+    }
+}
+
+impl From<Vec<String>> for ContentEncoding {
+    fn from(v: Vec<String>) -> Self {
+        ContentEncoding(v)
+    }
+}
+
+impl<'a> From<&'a [String]> for ContentEncoding {
+    fn from(v: &'a [String]) -> Self {
+        ContentEncoding(v.to_vec())
+    }
+}
+
+impl std::iter::FromIterator<String> for ContentEncoding {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        ContentEncoding(iter.into_iter().collect())
+    }
+}
+
+impl ContentEncoding {
+    /// Check if the content coding was applied.
+    pub fn contains(&self, coding: &str) -> bool {
+        self.0.iter().any(|c| c.eq_ignore_ascii_case(coding))
+    }
+}
+
+impl super::TypedHeader for ContentEncoding {
+    fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError> {
+        use super::parser_helpers::token;
+
+        let headers = headers.as_ref();
+
+        let header = match headers.get(&CONTENT_ENCODING) {
+            None => return Ok(None),
+            Some(header) => header,
+        };
+
+        let codings = header
+            .as_str()
+            .split(',')
+            .map(str::trim)
+            .filter(|coding| !coding.is_empty())
+            .map(|coding| match token(coding.as_bytes()) {
+                Ok(([], t)) if !t.is_empty() => Ok(String::from(coding)),
+                _ => Err(HeaderParseError::for_header(CONTENT_ENCODING)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Some(ContentEncoding(codings)))
+    }
+
+    fn insert_into(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+        headers.insert(CONTENT_ENCODING, self.0.join(", "));
+    }
+}
+
+impl super::TypedAppendableHeader for ContentEncoding {
+    fn append_to(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+        headers.append(CONTENT_ENCODING, self.0.join(", "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_encoding() {
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .header(CONTENT_ENCODING, "deflate,  gzip")
+            .empty();
+
+        let content_encoding = response.typed_header::<ContentEncoding>().unwrap().unwrap();
+        assert_eq!(
+            *content_encoding,
+            vec![String::from("deflate"), String::from("gzip")]
+        );
+        assert!(content_encoding.contains("GZIP"));
+        assert!(!content_encoding.contains("br"));
+
+        let response2 = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .typed_header(&content_encoding)
+            .empty();
+        assert_eq!(
+            response2.header(&CONTENT_ENCODING).unwrap().as_str(),
+            "deflate, gzip"
+        );
+
+        // Empty list elements are ignored
+        for (header, expected) in [("", vec![]), ("gzip,", vec![String::from("gzip")])] {
+            let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+                .header(CONTENT_ENCODING, header)
+                .empty();
+            let content_encoding = response.typed_header::<ContentEncoding>().unwrap().unwrap();
+            assert_eq!(*content_encoding, expected, "{}", header);
+        }
+
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .header(CONTENT_ENCODING, "gz ip")
+            .empty();
+        let err = response.typed_header::<ContentEncoding>().unwrap_err();
+        assert_eq!(err.header_name(), Some(&CONTENT_ENCODING));
+    }
+}
@@ -58,9 +58,10 @@ impl super::TypedHeader for ContentLength {
 
         let length = header
             .as_str()
+            .trim()
             .parse::<u64>()
             .map(ContentLength)
-            .map_err(|_| HeaderParseError)?;
+            .map_err(|_| HeaderParseError::for_header(CONTENT_LENGTH))?;
 
         Ok(Some(length))
     }
@@ -46,8 +46,11 @@ impl super::TypedHeader for ContentType {
             }
         };
 
-        let (media_type, media_subtype) = split_once(media_type, '/').ok_or(HeaderParseError)?;
-        let media_type = media_type.parse().map_err(|_| HeaderParseError)?;
+        let (media_type, media_subtype) = split_once(media_type, '/')
+            .ok_or_else(|| HeaderParseError::for_header(CONTENT_TYPE))?;
+        let media_type = media_type
+            .parse()
+            .map_err(|_| HeaderParseError::for_header(CONTENT_TYPE))?;
 
         Ok(Some(ContentType {
             media_type,
@@ -58,9 +58,10 @@ impl super::TypedHeader for CSeq {
 
         let cseq = header
             .as_str()
+            .trim()
             .parse::<u32>()
             .map(CSeq)
-            .map_err(|_| HeaderParseError)?;
+            .map_err(|_| HeaderParseError::for_header(CSEQ))?;
 
         Ok(Some(cseq))
     }
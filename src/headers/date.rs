@@ -0,0 +1,461 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+use std::fmt;
+
+const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const WEEKDAYS_LONG: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// HTTP date in UTC ([RFC 7231 section 7.1.1.1](https://tools.ietf.org/html/rfc7231#section-7.1.1.1)).
+///
+/// This can be parsed from the RFC 1123 (`Sun, 06 Nov 1994 08:49:37 GMT`), RFC 850
+/// (`Sunday, 06-Nov-94 08:49:37 GMT`) and asctime (`Sun Nov  6 08:49:37 1994`) formats and is
+/// always serialized in the RFC 1123 format. Two digit years of the RFC 850 format are
+/// interpreted as 1970 to 2069.
+///
+/// The weekday is not stored and not checked against the date when parsing. With the `serde`
+/// feature the date is (de)serialized as string in the same formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
+pub struct DateTime {
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+impl DateTime {
+    /// Creates a new date.
+    ///
+    /// Returns `None` if any of the fields is out of range, e.g. month 13 or February 30. A
+    /// second of 60 is allowed for leap seconds.
+    pub fn new(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> Option<Self> {
+        if !(1..=12).contains(&month)
+            || day == 0
+            || day > days_in_month(year, month)
+            || hour > 23
+            || minute > 59
+            || second > 60
+        {
+            return None;
+        }
+
+        Some(DateTime {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    /// Year.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Month, starting at 1 for January.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Day of the month, starting at 1.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Hour.
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Minute.
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Second.
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// Day of the week, starting at 0 for Sunday.
+    pub fn weekday(&self) -> u8 {
+        // Sakamoto's method for the Gregorian calendar
+        const OFFSETS: [u32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+
+        let mut year = u32::from(self.year);
+        if self.month < 3 {
+            year = year.wrapping_sub(1);
+        }
+        // Year 0 wraps around, shift by a multiple of 400 years to stay positive
+        let year = year.wrapping_add(400) % 400;
+
+        ((year + year / 4 - year / 100
+            + year / 400
+            + OFFSETS[usize::from(self.month - 1)]
+            + u32::from(self.day))
+            % 7) as u8
+    }
+}
+
+fn is_leap_year(year: u16) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}, {:02} {} {:04} {:02}:{:02}:{:02} GMT",
+            WEEKDAYS[usize::from(self.weekday())],
+            self.day,
+            MONTHS[usize::from(self.month - 1)],
+            self.year,
+            self.hour,
+            self.minute,
+            self.second
+        )
+    }
+}
+
+fn number<T: std::str::FromStr>(s: &str, min_len: usize, max_len: usize) -> Option<T> {
+    if s.len() < min_len || s.len() > max_len || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    s.parse::<T>().ok()
+}
+
+fn month(s: &str) -> Option<u8> {
+    MONTHS.iter().position(|m| *m == s).map(|idx| idx as u8 + 1)
+}
+
+fn time(s: &str) -> Option<(u8, u8, u8)> {
+    let mut parts = s.split(':');
+    let hour = number(parts.next()?, 2, 2)?;
+    let minute = number(parts.next()?, 2, 2)?;
+    let second = number(parts.next()?, 2, 2)?;
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some((hour, minute, second))
+}
+
+fn parse_date(s: &str) -> Option<DateTime> {
+    let parts = s.split_ascii_whitespace().collect::<Vec<_>>();
+
+    match parts.as_slice() {
+        // RFC 1123: `Sun, 06 Nov 1994 08:49:37 GMT`
+        [weekday, day, mon, year, t, "GMT"] if WEEKDAYS.contains(&weekday.strip_suffix(',')?) => {
+            let (hour, minute, second) = time(t)?;
+            DateTime::new(
+                number(year, 4, 4)?,
+                month(mon)?,
+                number(day, 2, 2)?,
+                hour,
+                minute,
+                second,
+            )
+        }
+        // RFC 850: `Sunday, 06-Nov-94 08:49:37 GMT`
+        [weekday, date, t, "GMT"] if WEEKDAYS_LONG.contains(&weekday.strip_suffix(',')?) => {
+            let mut date = date.split('-');
+            let day = number(date.next()?, 2, 2)?;
+            let mon = month(date.next()?)?;
+            let year = number::<u16>(date.next()?, 2, 2)?;
+            if date.next().is_some() {
+                return None;
+            }
+            let year = if year < 70 { 2000 + year } else { 1900 + year };
+
+            let (hour, minute, second) = time(t)?;
+            DateTime::new(year, mon, day, hour, minute, second)
+        }
+        // asctime: `Sun Nov  6 08:49:37 1994`
+        [weekday, mon, day, t, year] if WEEKDAYS.contains(weekday) => {
+            let (hour, minute, second) = time(t)?;
+            DateTime::new(
+                number(year, 4, 4)?,
+                month(mon)?,
+                number(day, 1, 2)?,
+                hour,
+                minute,
+                second,
+            )
+        }
+        _ => None,
+    }
+}
+
+impl std::str::FromStr for DateTime {
+    type Err = HeaderParseError;
+
+    fn from_str(s: &str) -> Result<Self, HeaderParseError> {
+        parse_date(s.trim()).ok_or_else(HeaderParseError::new)
+    }
+}
+
+impl std::convert::TryFrom<String> for DateTime {
+    type Error = HeaderParseError;
+
+    fn try_from(s: String) -> Result<Self, HeaderParseError> {
+        s.parse()
+    }
+}
+
+impl From<DateTime> for String {
+    fn from(v: DateTime) -> String {
+        v.to_string()
+    }
+}
+
+fn date_from_headers(
+    headers: &Headers,
+    name: HeaderName,
+) -> Result<Option<DateTime>, HeaderParseError> {
+    let header = match headers.get(&name) {
+        None => return Ok(None),
+        Some(header) => header,
+    };
+
+    parse_date(header.as_str().trim())
+        .map(Some)
+        .ok_or_else(|| HeaderParseError::for_header(name))
+}
+
+// Defines a newtype header around `DateTime`.
+macro_rules! date_header {
+    ($(#[$attr:meta])* $name:ident, $header:ident) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct $name(DateTime);
+
+        impl std::ops::Deref for $name {
+            type Target = DateTime;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl std::ops::DerefMut for $name {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl AsRef<DateTime> for $name {
+            fn as_ref(&self) -> &DateTime {
+                &self.0
+            }
+        }
+
+        impl AsMut<DateTime> for $name {
+            fn as_mut(&mut self) -> &mut DateTime {
+                &mut self.0
+            }
+        }
+
+        impl From<DateTime> for $name {
+            fn from(v: DateTime) -> $name {
+                $name(v)
+            }
+        }
+
+        impl From<$name> for DateTime {
+            fn from(v: $name) -> DateTime {
+                v.0
+            }
+        }
+
+        impl super::TypedHeader for $name {
+            fn from_headers(
+                headers: impl AsRef<Headers>,
+            ) -> Result<Option<Self>, HeaderParseError> {
+                date_from_headers(headers.as_ref(), $header).map(|date| date.map($name))
+            }
+
+            fn insert_into(&self, mut headers: impl AsMut<Headers>) {
+                let headers = headers.as_mut();
+
+                headers.insert($header, self.0.to_string());
+            }
+        }
+    };
+}
+
+date_header!(
+    /// `Date` header ([RFC 7826 section 18.21](https://tools.ietf.org/html/rfc7826#section-18.21)).
+    ///
+    /// ## Parsing and serializing
+    ///
+    /// ```rust
+    /// use rtsp_types::headers::Date;
+    ///
+    /// let response = rtsp_types::Response::builder(
+    ///         rtsp_types::Version::V1_0,
+    ///         rtsp_types::StatusCode::Ok
+    ///     )
+    ///     .header(rtsp_types::headers::DATE, "Sunday, 06-Nov-94 08:49:37 GMT")
+    ///     .empty();
+    ///
+    /// let date = response.typed_header::<Date>().unwrap().unwrap();
+    /// assert_eq!((date.year(), date.month(), date.day()), (1994, 11, 6));
+    /// assert_eq!(date.to_string(), "Sun, 06 Nov 1994 08:49:37 GMT");
+    /// ```
+    Date,
+    DATE
+);
+
+date_header!(
+    /// `Expires` header ([RFC 7826 section 18.22](https://tools.ietf.org/html/rfc7826#section-18.22)).
+    Expires,
+    EXPIRES
+);
+
+date_header!(
+    /// `Last-Modified` header ([RFC 7826 section 18.27](https://tools.ietf.org/html/rfc7826#section-18.27)).
+    LastModified,
+    LAST_MODIFIED
+);
+
+date_header!(
+    /// `If-Modified-Since` header ([RFC 7826 section 18.25](https://tools.ietf.org/html/rfc7826#section-18.25)).
+    IfModifiedSince,
+    IF_MODIFIED_SINCE
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_date_formats() {
+        let expected = DateTime::new(1994, 11, 6, 8, 49, 37).unwrap();
+
+        for header in [
+            "Sun, 06 Nov 1994 08:49:37 GMT",
+            "Sunday, 06-Nov-94 08:49:37 GMT",
+            "Sun Nov  6 08:49:37 1994",
+        ] {
+            let response = crate::Response::builder(crate::Version::V1_0, crate::StatusCode::Ok)
+                .header(DATE, header)
+                .header(EXPIRES, header)
+                .header(LAST_MODIFIED, header)
+                .empty();
+
+            let date = response.typed_header::<Date>().unwrap().unwrap();
+            assert_eq!(*date, expected, "{}", header);
+            let expires = response.typed_header::<Expires>().unwrap().unwrap();
+            assert_eq!(*expires, expected, "{}", header);
+            let last_modified = response.typed_header::<LastModified>().unwrap().unwrap();
+            assert_eq!(*last_modified, expected, "{}", header);
+
+            let response2 = crate::Response::builder(crate::Version::V1_0, crate::StatusCode::Ok)
+                .typed_header(&date)
+                .typed_header(&expires)
+                .typed_header(&last_modified)
+                .empty();
+            for name in [DATE, EXPIRES, LAST_MODIFIED] {
+                assert_eq!(
+                    response2.header(&name).unwrap().as_str(),
+                    "Sun, 06 Nov 1994 08:49:37 GMT"
+                );
+            }
+        }
+
+        assert_eq!(
+            "Thursday, 01-Jan-70 00:00:00 GMT"
+                .parse::<DateTime>()
+                .unwrap()
+                .to_string(),
+            "Thu, 01 Jan 1970 00:00:00 GMT"
+        );
+        assert_eq!(
+            "Tue, 29 Feb 2000 23:59:60 GMT"
+                .parse::<DateTime>()
+                .unwrap()
+                .to_string(),
+            "Tue, 29 Feb 2000 23:59:60 GMT"
+        );
+        assert_eq!(
+            "Wed Dec 31 12:00:00 2069"
+                .parse::<DateTime>()
+                .unwrap()
+                .to_string(),
+            "Tue, 31 Dec 2069 12:00:00 GMT"
+        );
+    }
+
+    #[test]
+    fn test_date_invalid() {
+        for header in [
+            "",
+            "Sun, 06 Nov 1994 08:49:37",
+            "Sun, 06 Nov 1994 08:49:37 UTC",
+            "Sun, 06 Foo 1994 08:49:37 GMT",
+            "Sun, 32 Dec 1994 08:49:37 GMT",
+            "Sun, 00 Dec 1994 08:49:37 GMT",
+            "Sun, 29 Feb 1900 08:49:37 GMT",
+            "Sun, 06 Nov 94 08:49:37 GMT",
+            "Sun, 06 Nov 1994 24:00:00 GMT",
+            "Sun, 06 Nov 1994 08:60:00 GMT",
+            "Sun, 06 Nov 1994 8:49:37 GMT",
+            "Sunday, 06-Nov-1994 08:49:37 GMT",
+            "Sun Nov 31 08:49:37 1994",
+            "Sun 13 6 08:49:37 1994",
+            "06 Nov 1994 08:49:37 GMT",
+        ] {
+            let response = crate::Response::builder(crate::Version::V1_0, crate::StatusCode::Ok)
+                .header(DATE, header)
+                .empty();
+            let err = response.typed_header::<Date>().unwrap_err();
+            assert_eq!(err.header_name(), Some(&DATE), "{}", header);
+        }
+
+        assert!(DateTime::new(1994, 13, 6, 8, 49, 37).is_none());
+        assert!(DateTime::new(1994, 11, 32, 8, 49, 37).is_none());
+        assert!(DateTime::new(1996, 2, 29, 8, 49, 37).is_some());
+        assert!(DateTime::new(1995, 2, 29, 8, 49, 37).is_none());
+
+        // Conversions used for serde
+        use std::convert::TryFrom;
+        let date = DateTime::new(1994, 11, 6, 8, 49, 37).unwrap();
+        assert_eq!(String::from(date), "Sun, 06 Nov 1994 08:49:37 GMT");
+        assert_eq!(
+            DateTime::try_from(String::from("Sun, 06 Nov 1994 08:49:37 GMT")),
+            Ok(date)
+        );
+        assert!(DateTime::try_from(String::from("Sun, 30 Feb 1994 08:49:37 GMT")).is_err());
+    }
+}
@@ -2,7 +2,129 @@
 //
 // Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
 
+use super::HeaderParseError;
+
+use std::fmt;
+
 pub const PLAY_BASIC: &str = "play.basic";
 pub const PLAY_SCALE: &str = "play.scale";
 pub const PLAY_SPEED: &str = "play.speed";
 pub const SETUP_RTP_RTCP_MUX: &str = "setup.rtp.rtcp.mux";
+
+/// Feature tags as used in the `Supported`, `Require`, `Proxy-Require` and `Unsupported`
+/// headers.
+///
+/// See [RFC 7826 section 4.5](https://tools.ietf.org/html/rfc7826#section-4.5).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Feature {
+    /// Minimal implementation for playback operations.
+    PlayBasic,
+    /// Support for the `Scale` header.
+    PlayScale,
+    /// Support for the `Speed` header.
+    PlaySpeed,
+    /// Support for RTP and RTCP multiplexing.
+    SetupRtpRtcpMux,
+    /// Extension feature tag.
+    Extension(String),
+}
+
+// Feature tags with a dedicated `Feature` variant.
+static KNOWN_FEATURES: [(Feature, &str); 4] = [
+    (Feature::PlayBasic, PLAY_BASIC),
+    (Feature::PlayScale, PLAY_SCALE),
+    (Feature::PlaySpeed, PLAY_SPEED),
+    (Feature::SetupRtpRtcpMux, SETUP_RTP_RTCP_MUX),
+];
+
+impl Feature {
+    /// Returns the feature tag as used in the headers, e.g. `play.basic`.
+    pub fn as_str(&self) -> &str {
+        if let Feature::Extension(ref s) = self {
+            return s.as_str();
+        }
+
+        KNOWN_FEATURES
+            .iter()
+            .find(|(feature, _)| feature == self)
+            .map(|(_, s)| *s)
+            .expect("all non-extension features are known")
+    }
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for Feature {
+    type Err = HeaderParseError;
+
+    fn from_str(s: &str) -> Result<Self, HeaderParseError> {
+        Ok(KNOWN_FEATURES
+            .iter()
+            .find(|(_, tag)| *tag == s)
+            .map(|(feature, _)| feature.clone())
+            .unwrap_or_else(|| Feature::Extension(String::from(s))))
+    }
+}
+
+// Converts the feature tags of the `Supported`, `Require` and `Unsupported` headers.
+pub(super) fn features_from_tags(tags: &[String]) -> Vec<Feature> {
+    tags.iter()
+        .map(|tag| {
+            tag.parse()
+                .unwrap_or_else(|_| Feature::Extension(tag.clone()))
+        })
+        .collect()
+}
+
+impl AsRef<str> for Feature {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<'a> From<&'a Feature> for &'a str {
+    fn from(v: &'a Feature) -> Self {
+        v.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feature() {
+        let features = [
+            ("play.basic", Feature::PlayBasic),
+            ("play.scale", Feature::PlayScale),
+            ("play.speed", Feature::PlaySpeed),
+            ("setup.rtp.rtcp.mux", Feature::SetupRtpRtcpMux),
+            (
+                "com.example.foo",
+                Feature::Extension(String::from("com.example.foo")),
+            ),
+        ];
+
+        for (s, feature) in &features {
+            assert_eq!(s.parse::<Feature>().as_ref(), Ok(feature));
+            assert_eq!(feature.to_string(), *s);
+        }
+
+        let supported = crate::headers::Supported::builder()
+            .play_basic()
+            .feature("com.example.foo")
+            .build();
+        assert_eq!(
+            supported.features(),
+            vec![
+                Feature::PlayBasic,
+                Feature::Extension(String::from("com.example.foo"))
+            ]
+        );
+    }
+}
@@ -0,0 +1,162 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+/// `If-None-Match` header ([RFC 7826 section 18.26](https://tools.ietf.org/html/rfc7826#section-18.26)).
+///
+/// Entity tags include the quotes and the weak prefix, e.g. `"xyzzy"` or `W/"xyzzy"`, or are
+/// only `*`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IfNoneMatch(Vec<String>);
+
+impl std::ops::Deref for IfNoneMatch {
+    type Target = Vec<String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for IfNoneMatch {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<Vec<String>> for IfNoneMatch {
+    fn as_ref(&self) -> &Vec<String> {
+        &self.0
+    }
+}
+
+impl AsMut<Vec<String>> for IfNoneMatch {
+    fn as_mut(&mut self) -> &mut Vec<String> {
+        &mut self.0
+    }
+}
+
+impl From<Vec<String>> for IfNoneMatch {
+    fn from(v: Vec<String>) -> Self {
+        IfNoneMatch(v)
+    }
+}
+
+impl<'a> From<&'a [String]> for IfNoneMatch {
+    fn from(v: &'a [String]) -> Self {
+        IfNoneMatch(v.to_vec())
+    }
+}
+
+impl std::iter::FromIterator<String> for IfNoneMatch {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        IfNoneMatch(iter.into_iter().collect())
+    }
+}
+
+mod parser {
+    use super::super::parser_helpers::trim;
+    use nom::branch::alt;
+    use nom::bytes::complete::{tag, take_while};
+    use nom::combinator::{all_consuming, map_res, opt, recognize};
+    use nom::multi::separated_list1;
+    use nom::sequence::{delimited, tuple};
+    use nom::IResult;
+    use std::str;
+
+    fn entity_tag(input: &[u8]) -> IResult<&[u8], &str> {
+        fn is_etagc(i: u8) -> bool {
+            i == 0x21 || (0x23..=0x7e).contains(&i) || i >= 0x80
+        }
+
+        map_res(
+            recognize(tuple((
+                opt(tag(b"W/")),
+                delimited(tag(b"\""), take_while(is_etagc), tag(b"\"")),
+            ))),
+            str::from_utf8,
+        )(input)
+    }
+
+    pub(super) fn entity_tags(input: &[u8]) -> IResult<&[u8], Vec<&str>> {
+        all_consuming(alt((
+            map_res(trim(tag(b"*")), |star| {
+                str::from_utf8(star).map(|s| vec![s])
+            }),
+            separated_list1(tag(b","), trim(entity_tag)),
+        )))(input)
+    }
+}
+
+impl super::TypedHeader for IfNoneMatch {
+    fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError> {
+        let headers = headers.as_ref();
+
+        let header = match headers.get(&IF_NONE_MATCH) {
+            None => return Ok(None),
+            Some(header) => header,
+        };
+
+        let (_rem, tags) = parser::entity_tags(header.as_str().as_bytes())
+            .map_err(|_| HeaderParseError::for_header(IF_NONE_MATCH))?;
+
+        Ok(Some(IfNoneMatch(
+            tags.into_iter().map(String::from).collect(),
+        )))
+    }
+
+    fn insert_into(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+        headers.insert(IF_NONE_MATCH, self.0.join(", "));
+    }
+}
+
+impl super::TypedAppendableHeader for IfNoneMatch {
+    fn append_to(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+        headers.append(IF_NONE_MATCH, self.0.join(", "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_if_none_match() {
+        let request = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+            .header(IF_NONE_MATCH, "\"xyzzy\",  W/\"r2d2,xxxx\"")
+            .empty();
+
+        let if_none_match = request.typed_header::<IfNoneMatch>().unwrap().unwrap();
+        assert_eq!(
+            *if_none_match,
+            vec![String::from("\"xyzzy\""), String::from("W/\"r2d2,xxxx\"")]
+        );
+
+        let request2 = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+            .typed_header(&if_none_match)
+            .empty();
+        assert_eq!(
+            request2.header(&IF_NONE_MATCH).unwrap().as_str(),
+            "\"xyzzy\", W/\"r2d2,xxxx\""
+        );
+
+        let request = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+            .header(IF_NONE_MATCH, " * ")
+            .empty();
+        assert_eq!(
+            request.typed_header::<IfNoneMatch>(),
+            Ok(Some(IfNoneMatch::from(vec![String::from("*")])))
+        );
+
+        for value in ["xyzzy", "\"xyzzy\", *", "\"xy\"zzy\"", "w/\"xyzzy\"", ""] {
+            let request = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+                .header(IF_NONE_MATCH, value)
+                .empty();
+            assert!(request.typed_header::<IfNoneMatch>().is_err(), "{}", value);
+        }
+    }
+}
@@ -7,7 +7,10 @@ use super::*;
 use std::fmt;
 
 /// `Media-Properties` header ([RFC 7826 section 18.29](https://tools.ietf.org/html/rfc7826#section-18.29)).
-#[derive(Debug, Clone)]
+///
+/// This only implements `PartialEq` and not `Eq` or `Hash` as some properties contain
+/// floating point values.
+#[derive(Debug, Clone, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MediaProperties(Vec<MediaProperty>);
 
@@ -185,11 +188,10 @@ pub(super) mod parser {
 
     fn media_property(input: &[u8]) -> IResult<&[u8], MediaProperty> {
         map_res(param, |p| -> Result<_, HeaderParseError> {
-            dbg!(&p);
             match p {
                 ("Random-Access", None) => Ok(MediaProperty::RandomAccess(None)),
                 ("Random-Access", Some(dur)) => {
-                    let dur = dur.parse().map_err(|_| HeaderParseError)?;
+                    let dur = dur.parse().map_err(|_| HeaderParseError::new())?;
                     Ok(MediaProperty::RandomAccess(Some(dur)))
                 }
                 ("Beginning-Only", None) => Ok(MediaProperty::BeginningOnly),
@@ -199,27 +201,28 @@ pub(super) mod parser {
                 ("Time-Progressing", None) => Ok(MediaProperty::TimeProgressing),
                 ("Unlimited", None) => Ok(MediaProperty::Unlimited),
                 ("Time-Limited", Some(time)) => {
-                    let time = time.parse().map_err(|_| HeaderParseError)?;
+                    let time = time.parse().map_err(|_| HeaderParseError::new())?;
                     Ok(MediaProperty::TimeLimited(time))
                 }
                 ("Time-Duration", Some(dur)) => {
-                    let dur = dur.parse().map_err(|_| HeaderParseError)?;
+                    let dur = dur.parse().map_err(|_| HeaderParseError::new())?;
                     Ok(MediaProperty::TimeDuration(dur))
                 }
                 ("Scales", Some(scales)) => {
-                    if !scales.starts_with('"') || !scales.ends_with('"') {
-                        return Err(HeaderParseError);
-                    }
+                    let scales = scales
+                        .strip_prefix('"')
+                        .and_then(|s| s.strip_suffix('"'))
+                        .ok_or_else(HeaderParseError::new)?;
 
                     let mut s = Vec::new();
-                    for scale in scales[1..(scales.len() - 1)].split(',') {
+                    for scale in scales.split(',') {
                         let scale = scale.trim();
                         if let Some((a, b)) = split_once(scale, ':') {
-                            let a = a.parse().map_err(|_| HeaderParseError)?;
-                            let b = b.parse().map_err(|_| HeaderParseError)?;
+                            let a = a.parse().map_err(|_| HeaderParseError::new())?;
+                            let b = b.parse().map_err(|_| HeaderParseError::new())?;
                             s.push(ScaleRange::Range(a, b));
                         } else {
-                            let a = scale.parse().map_err(|_| HeaderParseError)?;
+                            let a = scale.parse().map_err(|_| HeaderParseError::new())?;
                             s.push(ScaleRange::Scale(a));
                         }
                     }
@@ -248,8 +251,8 @@ impl super::TypedHeader for MediaProperties {
             Some(header) => header,
         };
 
-        let (_rem, properties) =
-            parser::media_properties(header.as_str().as_bytes()).map_err(|_| HeaderParseError)?;
+        let (_rem, properties) = parser::media_properties(header.as_str().as_bytes())
+            .map_err(|_| HeaderParseError::for_header(MEDIA_PROPERTIES))?;
 
         Ok(Some(properties.into()))
     }
@@ -328,4 +331,19 @@ mod tests {
             .empty();
         assert_eq!(response, response2);
     }
+
+    #[test]
+    fn test_media_properties_malformed_scales() {
+        for header in ["Scales=\"", "Immutable, Scales=\"", "Scales=\"1, 2"] {
+            let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+                .header(crate::headers::MEDIA_PROPERTIES, header)
+                .empty();
+
+            assert!(
+                response.typed_header::<super::MediaProperties>().is_err(),
+                "{}",
+                header
+            );
+        }
+    }
 }
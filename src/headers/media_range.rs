@@ -6,7 +6,7 @@ use super::Range;
 use super::*;
 
 /// `Media-Range` header ([RFC 7826 section 18.30](https://tools.ietf.org/html/rfc7826#section-18.30)).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MediaRange(Vec<Range>);
 
@@ -48,6 +48,12 @@ impl<'a> From<&'a [Range]> for MediaRange {
     }
 }
 
+impl std::iter::FromIterator<Range> for MediaRange {
+    fn from_iter<I: IntoIterator<Item = Range>>(iter: I) -> Self {
+        MediaRange(iter.into_iter().collect())
+    }
+}
+
 impl MediaRange {
     /// Creates a new `Media-Range` header builder.
     pub fn builder() -> MediaRangeBuilder {
@@ -66,12 +72,61 @@ impl MediaRangeBuilder {
         self
     }
 
+    /// Add the provided NPT range to the `Media-Range` header.
+    pub fn npt(self, range: NptRange) -> Self {
+        self.range(Range::Npt(range))
+    }
+
+    /// Add the provided SMPTE range to the `Media-Range` header.
+    pub fn smpte(self, range: SmpteRange) -> Self {
+        self.range(Range::Smpte(range))
+    }
+
+    /// Add the provided absolute time range to the `Media-Range` header.
+    pub fn clock(self, range: UtcRange) -> Self {
+        self.range(Range::Utc(range))
+    }
+
     /// Build the `Media-Range` header.
     pub fn build(self) -> MediaRange {
         MediaRange(self.0)
     }
 }
 
+impl MediaRange {
+    /// Gets the `Media-Range` header from `headers`, parsing each range with
+    /// [`Range::parse_lenient`](../range/enum.Range.html#method.parse_lenient).
+    ///
+    /// The [`TypedHeader`] implementation only accepts ranges that strictly follow the RFC grammar.
+    /// This still fails if any range is invalid even with the relaxed rules, e.g. `npt=abc`.
+    pub fn from_headers_lenient(
+        headers: impl AsRef<Headers>,
+    ) -> Result<Option<Self>, HeaderParseError> {
+        let headers = headers.as_ref();
+
+        headers
+            .get(&MEDIA_RANGE)
+            .map(|header| parse_ranges(header.as_str(), true))
+            .transpose()
+    }
+}
+
+fn parse_ranges(value: &str, lenient: bool) -> Result<MediaRange, HeaderParseError> {
+    let mut ranges = Vec::new();
+    for range in value.split(',') {
+        let range = range.trim();
+
+        let range = if lenient {
+            Range::parse_lenient(range)
+        } else {
+            range.parse()
+        };
+        ranges.push(range.map_err(|_| HeaderParseError::for_header(MEDIA_RANGE))?);
+    }
+
+    Ok(MediaRange(ranges))
+}
+
 impl super::TypedHeader for MediaRange {
     fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError> {
         let headers = headers.as_ref();
@@ -81,14 +136,7 @@ impl super::TypedHeader for MediaRange {
             Some(header) => header,
         };
 
-        let mut ranges = Vec::new();
-        for range in header.as_str().split(',') {
-            let range = range.trim();
-
-            ranges.push(range.parse()?);
-        }
-
-        Ok(Some(MediaRange(ranges)))
+        Ok(Some(parse_ranges(header.as_str(), false)?))
     }
 
     fn insert_into(&self, mut headers: impl AsMut<Headers>) {
@@ -123,3 +171,53 @@ impl super::TypedAppendableHeader for MediaRange {
         headers.append(MEDIA_RANGE, ranges);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_media_range_lenient() {
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .header(MEDIA_RANGE, "npt=0 - 10, npt=20")
+            .empty();
+
+        assert_eq!(
+            response.typed_header::<MediaRange>(),
+            Err(HeaderParseError::for_header(MEDIA_RANGE))
+        );
+
+        let media_range = MediaRange::from_headers_lenient(&response)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            *media_range,
+            vec![
+                Range::Npt(NptRange::FromTo(
+                    NptTime::Seconds(0, None),
+                    NptTime::Seconds(10, None),
+                )),
+                Range::Npt(NptRange::From(NptTime::Seconds(20, None))),
+            ]
+        );
+
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .header(MEDIA_RANGE, "npt=0-10")
+            .empty();
+        assert_eq!(
+            MediaRange::from_headers_lenient(&response).unwrap(),
+            response.typed_header::<MediaRange>().unwrap()
+        );
+
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .header(MEDIA_RANGE, "npt=0 - 10, npt=abc")
+            .empty();
+        assert_eq!(
+            MediaRange::from_headers_lenient(&response),
+            Err(HeaderParseError::for_header(MEDIA_RANGE))
+        );
+
+        let request = crate::Request::builder(crate::Method::Play, crate::Version::V2_0).empty();
+        assert_eq!(MediaRange::from_headers_lenient(&request), Ok(None));
+    }
+}
@@ -15,13 +15,46 @@ pub use constants::*;
 
 mod parser_helpers;
 
+// Implements fixed precision formatting for headers that are newtypes around an `f64`.
+macro_rules! impl_with_precision {
+    ($name:ident, $header:ident) => {
+        impl $name {
+            /// Formats the value with exactly `precision` decimal places, e.g. `2.000`.
+            pub fn to_string_with_precision(&self, precision: usize) -> String {
+                format!("{:.*}", precision, self.0)
+            }
+
+            /// Inserts the header into headers with exactly `precision` decimal places.
+            ///
+            /// See [`to_string_with_precision`](#method.to_string_with_precision).
+            pub fn insert_with_precision(
+                &self,
+                mut headers: impl AsMut<Headers>,
+                precision: usize,
+            ) {
+                let headers = headers.as_mut();
+
+                headers.insert($header, self.to_string_with_precision(precision));
+            }
+        }
+    };
+}
+
 pub mod accept;
+pub mod accept_encoding;
 pub mod accept_ranges;
 pub mod allow;
+pub mod bandwidth;
+pub mod blocksize;
+pub mod cache_control;
+pub mod conditional;
+pub mod content_encoding;
 pub mod content_length;
 pub mod content_type;
 pub mod cseq;
+pub mod date;
 pub mod features;
+pub mod if_none_match;
 pub mod media_properties;
 pub mod media_range;
 pub mod notify_reason;
@@ -35,18 +68,31 @@ pub mod seek_style;
 pub mod session;
 pub mod speed;
 pub mod supported;
+pub mod timestamp;
 pub mod transport;
 pub mod unsupported;
+pub mod uri;
+pub mod via;
+pub mod www_authenticate;
 
 pub use accept::{Accept, MediaType, MediaTypeRange};
+pub use accept_encoding::AcceptEncoding;
 pub use accept_ranges::{AcceptRanges, RangeUnit};
 pub use allow::Allow;
+pub use bandwidth::Bandwidth;
+pub use blocksize::Blocksize;
+pub use cache_control::{CacheControl, CacheDirective};
+pub use conditional::ConditionalRequest;
+pub use content_encoding::ContentEncoding;
 pub use content_length::ContentLength;
 pub use content_type::ContentType;
 pub use cseq::CSeq;
+pub use date::{Date, DateTime, Expires, IfModifiedSince, LastModified};
+pub use features::Feature;
+pub use if_none_match::IfNoneMatch;
 pub use media_properties::{MediaProperties, MediaProperty};
 pub use media_range::MediaRange;
-pub use notify_reason::NotifyReason;
+pub use notify_reason::{NotifyReason, NotifyReasonParams};
 pub use pipelined_requests::PipelinedRequests;
 pub use public::Public;
 pub use range::{NptRange, NptTime, Range, SmpteRange, SmpteTime, SmpteType, UtcRange, UtcTime};
@@ -57,8 +103,335 @@ pub use seek_style::SeekStyle;
 pub use session::Session;
 pub use speed::Speed;
 pub use supported::Supported;
+pub use timestamp::Timestamp;
 pub use transport::{
-    OtherTransport, RtpLowerTransport, RtpProfile, RtpTransport, RtpTransportParameters, Transport,
-    TransportMode, TransportParameters, Transports,
+    OtherTransport, PortKind, RtpLowerTransport, RtpProfile, RtpTransport, RtpTransportBuilder,
+    RtpTransportParameters, Transport, TransportDelivery, TransportMode, TransportParameters,
+    TransportSetup, Transports,
 };
 pub use unsupported::Unsupported;
+pub use uri::{ContentBase, ContentLocation, Location, Referrer, UriReference};
+pub use via::{Via, ViaEntry};
+pub use www_authenticate::{Challenge, WwwAuthenticate};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_typed_headers() {
+        fn check<H: TypedHeader>() {
+            let headers = Headers::new();
+            assert!(
+                matches!(H::from_headers(&headers), Ok(None)),
+                "{}",
+                std::any::type_name::<H>()
+            );
+        }
+
+        check::<Accept>();
+        check::<AcceptEncoding>();
+        check::<AcceptRanges>();
+        check::<Allow>();
+        check::<Bandwidth>();
+        check::<Blocksize>();
+        check::<CacheControl>();
+        check::<ContentBase>();
+        check::<ContentEncoding>();
+        check::<ContentLength>();
+        check::<ContentLocation>();
+        check::<ContentType>();
+        check::<CSeq>();
+        check::<Date>();
+        check::<Expires>();
+        check::<IfModifiedSince>();
+        check::<IfNoneMatch>();
+        check::<LastModified>();
+        check::<Location>();
+        check::<MediaProperties>();
+        check::<MediaRange>();
+        check::<NotifyReason>();
+        check::<NotifyReasonParams>();
+        check::<PipelinedRequests>();
+        check::<Public>();
+        check::<Range>();
+        check::<Referrer>();
+        check::<Require>();
+        check::<RtpInfos>();
+        check::<Scale>();
+        check::<SeekStyle>();
+        check::<Session>();
+        check::<Speed>();
+        check::<Supported>();
+        check::<Timestamp>();
+        check::<Transports>();
+        check::<Unsupported>();
+        check::<Via>();
+        check::<WwwAuthenticate>();
+    }
+
+    #[test]
+    fn test_scalar_typed_headers() {
+        fn check<H: TypedHeader + Clone + PartialEq + std::fmt::Debug>(
+            name: HeaderName,
+            valid: &[(&str, H)],
+            invalid: &[&str],
+        ) {
+            let mut headers = Headers::new();
+            assert_eq!(H::from_headers(&headers), Ok(None), "{}", name);
+
+            for (value, expected) in valid {
+                for value in [
+                    String::from(*value),
+                    format!(" {}", value),
+                    format!("{} ", value),
+                    format!("\t{} \t", value),
+                ] {
+                    headers.insert(name.clone(), value.as_str());
+                    assert_eq!(
+                        H::from_headers(&headers),
+                        Ok(Some(expected.clone())),
+                        "{}: {:?}",
+                        name,
+                        value
+                    );
+                }
+            }
+
+            for value in invalid {
+                headers.insert(name.clone(), *value);
+                assert_eq!(
+                    H::from_headers(&headers),
+                    Err(HeaderParseError::for_header(name.clone())),
+                    "{}: {:?}",
+                    name,
+                    value
+                );
+            }
+        }
+
+        let garbage = ["", " ", "abc", "1 2", "1,2", "0x10"];
+
+        check(
+            BANDWIDTH,
+            &[("0", Bandwidth::from(0)), ("64000", Bandwidth::from(64000))],
+            &[&garbage[..], &["-1", "18446744073709551616"]].concat(),
+        );
+        check(
+            BLOCKSIZE,
+            &[("1400", Blocksize::from(1400))],
+            &[&garbage[..], &["-1", "4294967296"]].concat(),
+        );
+        check(
+            CONTENT_LENGTH,
+            &[
+                ("0", ContentLength::from(0)),
+                ("18", ContentLength::from(18)),
+            ],
+            &[&garbage[..], &["-1", "18446744073709551616"]].concat(),
+        );
+        check(
+            CSEQ,
+            &[("1", CSeq::from(1)), ("4294967295", CSeq::from(u32::MAX))],
+            &[&garbage[..], &["-1", "4294967296"]].concat(),
+        );
+        check(
+            PIPELINED_REQUESTS,
+            &[("7", PipelinedRequests::from(7))],
+            &[&garbage[..], &["-1", "4294967296"]].concat(),
+        );
+        check(
+            SCALE,
+            &[
+                ("1", Scale::from(1.0)),
+                ("-2.5", Scale::from(-2.5)),
+                ("+0.5", Scale::from(0.5)),
+            ],
+            &[&garbage[..], &["1e400", "inf", "NaN"]].concat(),
+        );
+        check(
+            SPEED,
+            &[("1", Speed::from(1.0)), ("2.5", Speed::from(2.5))],
+            &[&garbage[..], &["1e400", "-inf", "NaN"]].concat(),
+        );
+    }
+
+    #[test]
+    fn test_single_valued_headers_not_appendable() {
+        // Resolving `check` is ambiguous, and fails to compile, for any type that implements
+        // `TypedAppendableHeader`.
+        trait NotAppendable<A> {
+            fn check() {}
+        }
+
+        impl<T: TypedHeader> NotAppendable<()> for T {}
+
+        struct Appendable;
+        impl<T: TypedAppendableHeader> NotAppendable<Appendable> for T {}
+
+        <Bandwidth as NotAppendable<_>>::check();
+        <Blocksize as NotAppendable<_>>::check();
+        <ContentBase as NotAppendable<_>>::check();
+        <ContentLength as NotAppendable<_>>::check();
+        <ContentLocation as NotAppendable<_>>::check();
+        <ContentType as NotAppendable<_>>::check();
+        <CSeq as NotAppendable<_>>::check();
+        <Date as NotAppendable<_>>::check();
+        <Expires as NotAppendable<_>>::check();
+        <IfModifiedSince as NotAppendable<_>>::check();
+        <LastModified as NotAppendable<_>>::check();
+        <Location as NotAppendable<_>>::check();
+        <NotifyReason as NotAppendable<_>>::check();
+        <NotifyReasonParams as NotAppendable<_>>::check();
+        <PipelinedRequests as NotAppendable<_>>::check();
+        <Range as NotAppendable<_>>::check();
+        <Referrer as NotAppendable<_>>::check();
+        <Scale as NotAppendable<_>>::check();
+        <SeekStyle as NotAppendable<_>>::check();
+        <Session as NotAppendable<_>>::check();
+        <Speed as NotAppendable<_>>::check();
+        <Timestamp as NotAppendable<_>>::check();
+    }
+
+    #[test]
+    fn test_list_header_equality() {
+        use std::collections::HashSet;
+
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .header(ACCEPT, "application/sdp")
+            .header(ACCEPT_RANGES, "npt, clock")
+            .header(MEDIA_RANGE, "npt=0-10")
+            .header(MEDIA_PROPERTIES, "Random-Access=2.5, Immutable")
+            .header(REQUIRE, "play.basic")
+            .header(SUPPORTED, "play.basic, play.scale")
+            .header(UNSUPPORTED, "play.speed")
+            .empty();
+
+        let accept = Accept::builder()
+            .media_type(MediaTypeRange {
+                type_: Some(MediaType::Application),
+                subtype: Some("sdp".into()),
+                params: Vec::new(),
+            })
+            .build();
+        assert_eq!(
+            response.typed_header::<Accept>().unwrap(),
+            Some(accept.clone())
+        );
+        assert_ne!(Accept::default(), accept);
+
+        let accept_ranges = AcceptRanges::builder().npt().clock().build();
+        assert_eq!(
+            response.typed_header::<AcceptRanges>().unwrap(),
+            Some(accept_ranges.clone())
+        );
+        assert_ne!(accept_ranges, AcceptRanges::builder().npt().build());
+
+        let media_range = MediaRange::builder()
+            .npt(NptRange::FromTo(
+                NptTime::Seconds(0, None),
+                NptTime::Seconds(10, None),
+            ))
+            .build();
+        assert_eq!(
+            response.typed_header::<MediaRange>().unwrap(),
+            Some(media_range.clone())
+        );
+        assert_ne!(media_range, MediaRange::default());
+
+        let media_properties = MediaProperties::builder()
+            .property(MediaProperty::RandomAccess(Some(2.5)))
+            .property(MediaProperty::Immutable)
+            .build();
+        assert_eq!(
+            response.typed_header::<MediaProperties>().unwrap(),
+            Some(media_properties)
+        );
+        // Floating point values are only partially comparable
+        let nan = MediaProperties::from(vec![MediaProperty::TimeDuration(f64::NAN)]);
+        assert_ne!(nan, nan.clone());
+
+        let require = Require::builder().play_basic().build();
+        assert_eq!(response.typed_header::<Require>().unwrap(), Some(require));
+
+        let supported = Supported::builder().play_basic().play_scale().build();
+        assert_eq!(
+            response.typed_header::<Supported>().unwrap(),
+            Some(supported.clone())
+        );
+        assert_ne!(
+            supported,
+            Supported::builder().play_scale().play_basic().build()
+        );
+
+        let unsupported = Unsupported::builder().play_speed().build();
+        assert_eq!(
+            response.typed_header::<Unsupported>().unwrap(),
+            Some(unsupported)
+        );
+
+        let set: HashSet<_> = [
+            Supported::builder().play_basic().build(),
+            Supported::builder().play_basic().build(),
+            Supported::builder().play_speed().build(),
+        ]
+        .iter()
+        .cloned()
+        .collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_append_typed_headers() {
+        fn check<H, T>(name: HeaderName, a: &str, b: &str)
+        where
+            H: TypedAppendableHeader + std::ops::Deref<Target = Vec<T>> + From<Vec<T>>,
+            T: Clone + PartialEq + std::fmt::Debug,
+        {
+            let mut headers = Headers::new();
+            headers.insert(name.clone(), a);
+            let a_header = headers.get_typed::<H>().unwrap().unwrap();
+            headers.insert(name.clone(), b);
+            let b_header = headers.get_typed::<H>().unwrap().unwrap();
+
+            // Appending to absent headers is the same as inserting
+            let mut headers = Headers::new();
+            headers.append_typed(&a_header);
+            assert_eq!(headers.get(&name).unwrap().as_str(), a, "{}", name);
+
+            // Appending to existing headers joins both lists
+            headers.append_typed(&b_header);
+            assert_eq!(
+                headers.get(&name).unwrap().as_str(),
+                format!("{}, {}", a, b),
+                "{}",
+                name
+            );
+            let combined = headers.get_typed::<H>().unwrap().unwrap();
+            let mut expected = a_header.to_vec();
+            expected.extend_from_slice(&b_header);
+            assert_eq!(*combined, expected, "{}", name);
+
+            // Appending an empty list keeps existing headers unchanged
+            headers.append_typed(&H::from(Vec::new()));
+            assert_eq!(
+                headers.get(&name).unwrap().as_str(),
+                format!("{}, {}", a, b),
+                "{}",
+                name
+            );
+        }
+
+        check::<Supported, _>(SUPPORTED, "play.basic", "play.scale, setup.rtp.rtcp.mux");
+        check::<Require, _>(REQUIRE, "play.basic", "play.speed");
+        check::<Public, _>(PUBLIC, "OPTIONS, DESCRIBE", "SETUP, PLAY, TEARDOWN");
+        check::<Accept, _>(ACCEPT, "application/sdp", "text/parameters;q=0.5");
+        check::<IfNoneMatch, _>(IF_NONE_MATCH, "\"xyzzy\"", "W/\"r2d2\", \"abc\"");
+        check::<MediaRange, _>(MEDIA_RANGE, "npt=0-", "clock=19961108T142300Z-");
+        check::<MediaProperties, _>(
+            MEDIA_PROPERTIES,
+            "Random-Access=2.5",
+            "Unlimited, Immutable",
+        );
+    }
+}
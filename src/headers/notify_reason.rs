@@ -7,16 +7,24 @@ use super::*;
 use std::fmt;
 
 /// `Notify-Reason` header ([RFC 7826 section 18.32](https://tools.ietf.org/html/rfc7826#section-18.32)).
+///
+/// Parameters after the reason are ignored, use
+/// [`NotifyReasonParams`](struct.NotifyReasonParams.html) to preserve them.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NotifyReason {
+    /// `end-of-stream`: the end of the stream has been reached.
     EndOfStream,
+    /// `media-properties-update`: the media properties have changed.
     MediaPropertiesUpdate,
+    /// `scale-change`: the scale of the stream has changed.
     ScaleChange,
+    /// Extension reason.
     Extension(String),
 }
 
 impl NotifyReason {
+    /// Returns the reason as used in the header.
     pub fn as_str(&self) -> &str {
         match self {
             NotifyReason::EndOfStream => "end-of-stream",
@@ -47,6 +55,100 @@ impl std::str::FromStr for NotifyReason {
 }
 
 impl super::TypedHeader for NotifyReason {
+    fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError> {
+        NotifyReasonParams::from_headers(headers).map(|params| params.map(|params| params.reason))
+    }
+
+    fn insert_into(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+
+        headers.insert(NOTIFY_REASON, self.to_string());
+    }
+}
+
+/// `Notify-Reason` header with parameters.
+///
+/// Some servers add parameters after the reason, e.g. `end-of-stream;rtptime=1234`, which are
+/// preserved.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NotifyReasonParams {
+    /// Reason of the notification.
+    pub reason: NotifyReason,
+    /// Parameters in the order they appear in the header.
+    pub params: Vec<(String, Option<String>)>,
+}
+
+impl NotifyReasonParams {
+    /// Creates a new notify reason without parameters.
+    pub fn new(reason: NotifyReason) -> Self {
+        NotifyReasonParams {
+            reason,
+            params: Vec::new(),
+        }
+    }
+
+    /// Gets the value of the first parameter with the given name.
+    ///
+    /// Returns `Some(None)` if the parameter has no value.
+    pub fn param(&self, name: &str) -> Option<Option<&str>> {
+        self.params
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_deref())
+    }
+}
+
+impl From<NotifyReason> for NotifyReasonParams {
+    fn from(reason: NotifyReason) -> Self {
+        NotifyReasonParams::new(reason)
+    }
+}
+
+impl fmt::Display for NotifyReasonParams {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.reason.as_str())?;
+        for (name, value) in &self.params {
+            write!(f, ";{}", name)?;
+            if let Some(value) = value {
+                write!(f, "={}", value)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for NotifyReasonParams {
+    type Err = HeaderParseError;
+
+    fn from_str(s: &str) -> Result<Self, HeaderParseError> {
+        use super::parser_helpers::{split_once, split_unquoted};
+
+        let mut iter = split_unquoted(s, ';').into_iter().map(str::trim);
+
+        let reason = iter
+            .next()
+            .filter(|reason| !reason.is_empty())
+            .ok_or_else(HeaderParseError::new)?
+            .parse::<NotifyReason>()?;
+
+        let mut params = Vec::new();
+        for param in iter.filter(|param| !param.is_empty()) {
+            match split_once(param, '=') {
+                Some((name, value)) if !name.trim().is_empty() => {
+                    params.push((String::from(name.trim()), Some(String::from(value.trim()))))
+                }
+                Some(_) => return Err(HeaderParseError::new()),
+                None => params.push((String::from(param), None)),
+            }
+        }
+
+        Ok(NotifyReasonParams { reason, params })
+    }
+}
+
+impl super::TypedHeader for NotifyReasonParams {
     fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError> {
         let headers = headers.as_ref();
 
@@ -55,7 +157,10 @@ impl super::TypedHeader for NotifyReason {
             Some(header) => header,
         };
 
-        let notify_reason = header.as_str().parse().map_err(|_| HeaderParseError)?;
+        let notify_reason = header
+            .as_str()
+            .parse()
+            .map_err(|_| HeaderParseError::for_header(NOTIFY_REASON))?;
 
         Ok(Some(notify_reason))
     }
@@ -66,3 +171,94 @@ impl super::TypedHeader for NotifyReason {
         headers.insert(NOTIFY_REASON, self.to_string());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notify_reason() {
+        let request = crate::Request::builder(crate::Method::PlayNotify, crate::Version::V2_0)
+            .header(NOTIFY_REASON, "scale-change")
+            .empty();
+        let notify_reason = request.typed_header::<NotifyReason>().unwrap().unwrap();
+        assert_eq!(notify_reason, NotifyReason::ScaleChange);
+
+        let request2 = crate::Request::builder(crate::Method::PlayNotify, crate::Version::V2_0)
+            .typed_header(&notify_reason)
+            .empty();
+        assert_eq!(request, request2);
+
+        // Parameters are ignored
+        let request = crate::Request::builder(crate::Method::PlayNotify, crate::Version::V2_0)
+            .header(NOTIFY_REASON, "end-of-stream;rtptime=1234567")
+            .empty();
+        assert_eq!(
+            request.typed_header::<NotifyReason>().unwrap(),
+            Some(NotifyReason::EndOfStream)
+        );
+    }
+
+    #[test]
+    fn test_notify_reason_params() {
+        for (header, expected) in [
+            (
+                "end-of-stream",
+                NotifyReasonParams::new(NotifyReason::EndOfStream),
+            ),
+            (
+                "end-of-stream;rtptime=1234567;seq=42",
+                NotifyReasonParams {
+                    reason: NotifyReason::EndOfStream,
+                    params: vec![
+                        (String::from("rtptime"), Some(String::from("1234567"))),
+                        (String::from("seq"), Some(String::from("42"))),
+                    ],
+                },
+            ),
+            (
+                "x-custom-reason;flag;info=\"a;b\"",
+                NotifyReasonParams {
+                    reason: NotifyReason::Extension(String::from("x-custom-reason")),
+                    params: vec![
+                        (String::from("flag"), None),
+                        (String::from("info"), Some(String::from("\"a;b\""))),
+                    ],
+                },
+            ),
+        ] {
+            let request = crate::Request::builder(crate::Method::PlayNotify, crate::Version::V2_0)
+                .header(NOTIFY_REASON, header)
+                .empty();
+
+            let notify_reason = request
+                .typed_header::<NotifyReasonParams>()
+                .unwrap()
+                .unwrap();
+            assert_eq!(notify_reason, expected, "{}", header);
+
+            let request2 = crate::Request::builder(crate::Method::PlayNotify, crate::Version::V2_0)
+                .typed_header(&notify_reason)
+                .empty();
+            assert_eq!(request, request2, "{}", header);
+        }
+
+        let notify_reason = "end-of-stream ; RTPTime=1234"
+            .parse::<NotifyReasonParams>()
+            .unwrap();
+        assert_eq!(notify_reason.param("rtptime"), Some(Some("1234")));
+        assert_eq!(notify_reason.param("seq"), None);
+        assert_eq!(notify_reason.to_string(), "end-of-stream;RTPTime=1234");
+
+        for header in ["", ";rtptime=1234", "end-of-stream;=1234"] {
+            let request = crate::Request::builder(crate::Method::PlayNotify, crate::Version::V2_0)
+                .header(NOTIFY_REASON, header)
+                .empty();
+            assert!(
+                request.typed_header::<NotifyReasonParams>().is_err(),
+                "{}",
+                header
+            );
+        }
+    }
+}
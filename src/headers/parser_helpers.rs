@@ -106,6 +106,30 @@ pub(super) fn quoted_string(input: &[u8]) -> IResult<&[u8], &[u8]> {
     Ok((snd, fst))
 }
 
+/// Splits `s` at every `d` that is not inside a quoted string.
+pub(super) fn split_unquoted(s: &str, d: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut in_quotes = false;
+    let mut escaped = false;
+
+    for (idx, c) in s.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if in_quotes && c == '\\' {
+            escaped = true;
+        } else if c == '"' {
+            in_quotes = !in_quotes;
+        } else if !in_quotes && c == d {
+            parts.push(&s[start..idx]);
+            start = idx + c.len_utf8();
+        }
+    }
+    parts.push(&s[start..]);
+
+    parts
+}
+
 // FIXME: Remove once str::split_once is stabilized
 pub(super) fn split_once(s: &str, d: char) -> Option<(&str, &str)> {
     let idx = s.find(d)?;
@@ -115,3 +139,24 @@ pub(super) fn split_once(s: &str, d: char) -> Option<(&str, &str)> {
 
     Some((fst, snd))
 }
+
+/// Parses a quality value as defined in
+/// [RFC 7231 section 5.3.1](https://tools.ietf.org/html/rfc7231#section-5.3.1).
+///
+/// This is a value between 0 and 1 with at most three decimal places.
+pub(super) fn parse_quality(s: &str) -> Option<f32> {
+    let (int, frac) = match split_once(s, '.') {
+        Some((int, frac)) => (int, frac),
+        None => (s, ""),
+    };
+
+    if frac.len() > 3 || !frac.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    match int {
+        "0" => s.parse().ok(),
+        "1" if frac.bytes().all(|b| b == b'0') => Some(1.0),
+        _ => None,
+    }
+}
@@ -58,9 +58,10 @@ impl super::TypedHeader for PipelinedRequests {
 
         let cseq = header
             .as_str()
+            .trim()
             .parse::<u32>()
             .map(PipelinedRequests)
-            .map_err(|_| HeaderParseError)?;
+            .map_err(|_| HeaderParseError::for_header(PIPELINED_REQUESTS))?;
 
         Ok(Some(cseq))
     }
@@ -6,7 +6,7 @@ use super::*;
 use crate::Method;
 
 /// `Public` header ([RFC 7826 section 18.39](https://tools.ietf.org/html/rfc7826#section-18.39)).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Public(Vec<Method>);
 
@@ -53,6 +53,30 @@ impl Public {
     pub fn builder() -> PublicBuilder {
         PublicBuilder(Vec::new())
     }
+
+    /// Check if the method is supported.
+    pub fn contains(&self, method: &Method) -> bool {
+        self.0.iter().any(|m| m == method)
+    }
+
+    /// Check if all the methods are supported.
+    pub fn allows_all(&self, methods: &[Method]) -> bool {
+        methods.iter().all(|method| self.contains(method))
+    }
+
+    /// Gets the `Public` header from `headers`, ignoring the case of known methods.
+    ///
+    /// This maps e.g. `Get_Parameter` to [`Method::GetParameter`] instead of an extension method.
+    ///
+    /// This can't fail as unknown methods are kept as extension methods, so `None` is only returned
+    /// if the header is missing.
+    pub fn from_headers_lenient(headers: impl AsRef<Headers>) -> Option<Self> {
+        let headers = headers.as_ref();
+
+        headers
+            .get(&PUBLIC)
+            .map(|header| Public(split_methods(header.as_str(), true)))
+    }
 }
 
 /// Builder for the `Public` header.
@@ -81,45 +105,56 @@ impl super::TypedHeader for Public {
             Some(header) => header,
         };
 
-        let mut public = Vec::new();
-        for method in header.as_str().split(',') {
-            let method = method.trim();
-
-            public.push(method.into());
-        }
-
-        Ok(Some(Public(public)))
+        Ok(Some(Public(split_methods(header.as_str(), false))))
     }
 
     fn insert_into(&self, mut headers: impl AsMut<Headers>) {
         let headers = headers.as_mut();
-
-        let mut public = String::new();
-        for method in &self.0 {
-            if !public.is_empty() {
-                public.push_str(", ");
-            }
-
-            public.push_str(method.into());
-        }
-
-        headers.insert(PUBLIC, public);
+        headers.insert(PUBLIC, join_methods(&self.0));
     }
 }
 
 impl super::TypedAppendableHeader for Public {
     fn append_to(&self, mut headers: impl AsMut<Headers>) {
         let headers = headers.as_mut();
+        headers.append(PUBLIC, join_methods(&self.0));
+    }
+}
 
-        let mut public = String::new();
-        for method in &self.0 {
-            if !public.is_empty() {
-                public.push_str(", ");
-            }
-
-            public.push_str(method.into());
-        }
-
-        headers.append(PUBLIC, public);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_public() {
+        let response = crate::Response::builder(crate::Version::V1_0, crate::StatusCode::Ok)
+            .header(PUBLIC, "OPTIONS, DESCRIBE, SETUP, PLAY, TEARDOWN,")
+            .empty();
+
+        let public = response.typed_header::<Public>().unwrap().unwrap();
+        assert_eq!(
+            *public,
+            vec![
+                Method::Options,
+                Method::Describe,
+                Method::Setup,
+                Method::Play,
+                Method::Teardown
+            ]
+        );
+        assert!(public.contains(&Method::Play));
+        assert!(!public.contains(&Method::Pause));
+        assert!(public.allows_all(&[Method::Describe, Method::Setup, Method::Play]));
+        assert!(!public.allows_all(&[Method::Play, Method::Pause]));
+        assert!(public.allows_all(&[]));
+
+        let response = crate::Response::builder(crate::Version::V1_0, crate::StatusCode::Ok)
+            .header(PUBLIC, " , OPTIONS,, PLAY , ")
+            .empty();
+        let public = response.typed_header::<Public>().unwrap().unwrap();
+        assert_eq!(*public, vec![Method::Options, Method::Play]);
+
+        let public = Public::from_headers_lenient(&response).unwrap();
+        assert_eq!(*public, vec![Method::Options, Method::Play]);
     }
 }
@@ -32,22 +32,59 @@ impl fmt::Display for Range {
     }
 }
 
-impl std::str::FromStr for Range {
-    type Err = HeaderParseError;
+impl Range {
+    /// Parses a range while allowing whitespace around the separators, e.g. `npt=0 - 10`.
+    ///
+    /// A start time without any `-` separator, e.g. `npt=10`, is accepted as an open-ended range
+    /// starting at that time.
+    ///
+    /// The `FromStr` implementation only accepts ranges that strictly follow the RFC grammar.
+    pub fn parse_lenient(s: &str) -> Result<Self, HeaderParseError> {
+        Self::parse(s.trim(), true)
+    }
 
-    fn from_str(s: &str) -> Result<Self, HeaderParseError> {
+    /// Gets the `Range` header from `headers`, parsing it with
+    /// [`parse_lenient`](#method.parse_lenient).
+    ///
+    /// The [`TypedHeader`] implementation only accepts ranges that strictly follow the RFC grammar.
+    /// This still fails for ranges that are invalid even with the relaxed rules, e.g. `npt=abc`.
+    pub fn from_headers_lenient(
+        headers: impl AsRef<Headers>,
+    ) -> Result<Option<Self>, HeaderParseError> {
+        let headers = headers.as_ref();
+
+        let header = match headers.get(&RANGE) {
+            None => return Ok(None),
+            Some(header) => header,
+        };
+
+        Ok(Some(
+            Range::parse_lenient(header.as_str())
+                .map_err(|_| HeaderParseError::for_header(RANGE))?,
+        ))
+    }
+
+    fn parse(s: &str, lenient: bool) -> Result<Self, HeaderParseError> {
         if s.starts_with("npt") {
-            Ok(Range::Npt(s.parse()?))
+            Ok(Range::Npt(NptRange::parse(s, lenient)?))
         } else if s.starts_with("clock") {
-            Ok(Range::Utc(s.parse()?))
+            Ok(Range::Utc(UtcRange::parse(s, lenient)?))
         } else if s.starts_with("smpte") {
-            Ok(Range::Smpte(s.parse()?))
+            Ok(Range::Smpte(SmpteRange::parse(s, lenient)?))
         } else {
             Ok(Range::Other(s.into()))
         }
     }
 }
 
+impl std::str::FromStr for Range {
+    type Err = HeaderParseError;
+
+    fn from_str(s: &str) -> Result<Self, HeaderParseError> {
+        Self::parse(s, false)
+    }
+}
+
 /// Normal Play Time Range ([RFC 7826 section 4.4.2](https://tools.ietf.org/html/rfc7826#section-4.4.2)).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -73,38 +110,90 @@ impl fmt::Display for NptRange {
     }
 }
 
-impl std::str::FromStr for NptRange {
-    type Err = HeaderParseError;
+impl NptRange {
+    /// Creates a range from `from` to `to`.
+    ///
+    /// Returns `None` if `from` is after `to`. Ranges involving `now` are not checked.
+    pub fn from_to(from: NptTime, to: NptTime) -> Option<Self> {
+        match (from.nanoseconds(), to.nanoseconds()) {
+            (Some(f), Some(t)) if f > t => None,
+            _ => Some(NptRange::FromTo(from, to)),
+        }
+    }
 
-    fn from_str(s: &str) -> Result<Self, HeaderParseError> {
-        let s = s.strip_prefix("npt").ok_or(HeaderParseError)?;
+    /// Checks if `t` is inside this range, including the start and end.
+    ///
+    /// This is always `false` for empty ranges and if `t` or any bound of the range is `now`.
+    pub fn contains(&self, t: NptTime) -> bool {
+        let t = match t.nanoseconds() {
+            Some(t) => t,
+            None => return false,
+        };
+
+        match self {
+            NptRange::Empty => false,
+            NptRange::From(f) => f.nanoseconds().map_or(false, |f| f <= t),
+            NptRange::FromTo(f, to) => match (f.nanoseconds(), to.nanoseconds()) {
+                (Some(f), Some(to)) => f <= t && t <= to,
+                _ => false,
+            },
+            NptRange::To(to) => to.nanoseconds().map_or(false, |to| t <= to),
+        }
+    }
+
+    /// Parses an NPT range while allowing whitespace around the `-` separator,
+    /// and a start time without any `-` separator.
+    pub fn parse_lenient(s: &str) -> Result<Self, HeaderParseError> {
+        Self::parse(s.trim(), true)
+    }
+
+    fn parse(s: &str, lenient: bool) -> Result<Self, HeaderParseError> {
+        let s = s.strip_prefix("npt").ok_or_else(HeaderParseError::new)?;
 
         if s.is_empty() {
             return Ok(NptRange::Empty);
         }
 
-        let s = s.strip_prefix('=').ok_or(HeaderParseError)?;
+        let s = s.strip_prefix('=').ok_or_else(HeaderParseError::new)?;
 
-        let (from, to) = split_once(s, '-').ok_or(HeaderParseError)?;
+        let (from, to) = match split_once(s, '-') {
+            Some(v) => v,
+            // Some servers only send a start time without the trailing `-`
+            None if lenient => (s, ""),
+            None => return Err(HeaderParseError::new()),
+        };
+        let (from, to) = if lenient {
+            (from.trim(), to.trim())
+        } else {
+            (from, to)
+        };
         let from = if from.is_empty() { None } else { Some(from) };
         let to = if to.is_empty() { None } else { Some(to) };
 
         let from = from
-            .map(|s| s.parse::<NptTime>().map_err(|_| HeaderParseError))
+            .map(|s| s.parse::<NptTime>().map_err(|_| HeaderParseError::new()))
             .transpose()?;
         let to = to
-            .map(|s| s.parse::<NptTime>().map_err(|_| HeaderParseError))
+            .map(|s| s.parse::<NptTime>().map_err(|_| HeaderParseError::new()))
             .transpose()?;
 
         match (from, to) {
             (Some(from), Some(to)) => Ok(NptRange::FromTo(from, to)),
             (None, Some(to)) => Ok(NptRange::To(to)),
             (Some(from), None) => Ok(NptRange::From(from)),
-            (None, None) => Err(HeaderParseError),
+            (None, None) => Err(HeaderParseError::new()),
         }
     }
 }
 
+impl std::str::FromStr for NptRange {
+    type Err = HeaderParseError;
+
+    fn from_str(s: &str) -> Result<Self, HeaderParseError> {
+        Self::parse(s, false)
+    }
+}
+
 /// Normal Play Time ([RFC 7826 section 4.4.2](https://tools.ietf.org/html/rfc7826#section-4.4.2)).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -135,6 +224,57 @@ impl fmt::Display for NptTime {
     }
 }
 
+impl NptTime {
+    /// Creates an NPT time from whole seconds.
+    pub fn seconds(seconds: u64) -> Self {
+        NptTime::Seconds(seconds, None)
+    }
+
+    /// Creates an NPT time from hours, minutes and seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `minutes` or `seconds` is not smaller than 60.
+    pub fn hms(hours: u64, minutes: u8, seconds: u8) -> Self {
+        assert!(minutes < 60 && seconds < 60, "invalid minutes or seconds");
+
+        NptTime::Hms(hours, minutes, seconds, None)
+    }
+
+    /// Creates an NPT time from fractional seconds.
+    ///
+    /// The nanoseconds are always set, e.g. `90.5` is serialized as `90.500000000`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `seconds` is negative, not finite or overflows.
+    pub fn from_secs_f64(seconds: f64) -> Self {
+        let duration = std::time::Duration::from_secs_f64(seconds);
+
+        NptTime::Seconds(duration.as_secs(), Some(duration.subsec_nanos()))
+    }
+
+    /// Fractional seconds, or `None` for `now`.
+    pub fn as_secs_f64(&self) -> Option<f64> {
+        self.nanoseconds()
+            .map(|nanoseconds| nanoseconds as f64 / 1_000_000_000.0)
+    }
+
+    /// Total number of nanoseconds, or `None` for `now`.
+    fn nanoseconds(&self) -> Option<u128> {
+        match *self {
+            NptTime::Now => None,
+            NptTime::Seconds(seconds, nanoseconds) => {
+                Some(seconds as u128 * 1_000_000_000 + nanoseconds.unwrap_or(0) as u128)
+            }
+            NptTime::Hms(hours, minutes, seconds, nanoseconds) => Some(
+                (hours as u128 * 3600 + minutes as u128 * 60 + seconds as u128) * 1_000_000_000
+                    + nanoseconds.unwrap_or(0) as u128,
+            ),
+        }
+    }
+}
+
 impl std::str::FromStr for NptTime {
     type Err = HeaderParseError;
 
@@ -146,16 +286,20 @@ impl std::str::FromStr for NptTime {
         match split_once(s, ':') {
             None => match split_once(s, '.') {
                 None => {
-                    let seconds = s.parse::<u64>().map_err(|_| HeaderParseError)?;
+                    let seconds = s.parse::<u64>().map_err(|_| HeaderParseError::new())?;
                     Ok(NptTime::Seconds(seconds, None))
                 }
                 Some((seconds, subseconds)) => {
-                    let seconds = seconds.parse::<u64>().map_err(|_| HeaderParseError)?;
+                    let seconds = seconds
+                        .parse::<u64>()
+                        .map_err(|_| HeaderParseError::new())?;
                     let digits = subseconds.len();
                     if digits > 9 || digits == 0 {
-                        return Err(HeaderParseError);
+                        return Err(HeaderParseError::new());
                     }
-                    let subseconds = subseconds.parse::<u32>().map_err(|_| HeaderParseError)?;
+                    let subseconds = subseconds
+                        .parse::<u32>()
+                        .map_err(|_| HeaderParseError::new())?;
 
                     let nanoseconds = subseconds * u32::pow(10, 9 - digits as u32);
 
@@ -163,27 +307,29 @@ impl std::str::FromStr for NptTime {
                 }
             },
             Some((hours, s)) => {
-                let hours = hours.parse::<u64>().map_err(|_| HeaderParseError)?;
+                let hours = hours.parse::<u64>().map_err(|_| HeaderParseError::new())?;
                 let mut it = s.split(':');
                 let minutes = it
                     .next()
                     .and_then(|s| s.parse::<u8>().ok())
-                    .ok_or(HeaderParseError)?;
-                let seconds = it.next().ok_or(HeaderParseError)?;
+                    .ok_or_else(HeaderParseError::new)?;
+                let seconds = it.next().ok_or_else(HeaderParseError::new)?;
 
                 if let Some((seconds, subseconds)) = split_once(seconds, '.') {
-                    let seconds = seconds.parse::<u8>().map_err(|_| HeaderParseError)?;
+                    let seconds = seconds.parse::<u8>().map_err(|_| HeaderParseError::new())?;
                     let digits = subseconds.len();
                     if digits > 9 || digits == 0 {
-                        return Err(HeaderParseError);
+                        return Err(HeaderParseError::new());
                     }
-                    let subseconds = subseconds.parse::<u32>().map_err(|_| HeaderParseError)?;
+                    let subseconds = subseconds
+                        .parse::<u32>()
+                        .map_err(|_| HeaderParseError::new())?;
 
                     let nanoseconds = subseconds * u32::pow(10, 9 - digits as u32);
 
                     Ok(NptTime::Hms(hours, minutes, seconds, Some(nanoseconds)))
                 } else {
-                    let seconds = seconds.parse::<u8>().map_err(|_| HeaderParseError)?;
+                    let seconds = seconds.parse::<u8>().map_err(|_| HeaderParseError::new())?;
 
                     Ok(NptTime::Hms(hours, minutes, seconds, None))
                 }
@@ -217,10 +363,39 @@ impl fmt::Display for SmpteRange {
     }
 }
 
-impl std::str::FromStr for SmpteRange {
-    type Err = HeaderParseError;
+impl SmpteRange {
+    /// Creates a range from `from` to `to`.
+    ///
+    /// Returns `None` if `from` is after `to`.
+    pub fn from_to(ty: SmpteType, from: SmpteTime, to: SmpteTime) -> Option<Self> {
+        if from.key() > to.key() {
+            None
+        } else {
+            Some(SmpteRange::FromTo(ty, from, to))
+        }
+    }
 
-    fn from_str(s: &str) -> Result<Self, HeaderParseError> {
+    /// Checks if `t` is inside this range, including the start and end.
+    ///
+    /// The timecode type is not considered. This is always `false` for empty ranges.
+    pub fn contains(&self, t: SmpteTime) -> bool {
+        let t = t.key();
+
+        match self {
+            SmpteRange::Empty(_) => false,
+            SmpteRange::From(_, f) => f.key() <= t,
+            SmpteRange::FromTo(_, f, to) => f.key() <= t && t <= to.key(),
+            SmpteRange::To(_, to) => t <= to.key(),
+        }
+    }
+
+    /// Parses an SMPTE range while allowing whitespace around the `-` separator,
+    /// and a start time without any `-` separator.
+    pub fn parse_lenient(s: &str) -> Result<Self, HeaderParseError> {
+        Self::parse(s.trim(), true)
+    }
+
+    fn parse(s: &str, lenient: bool) -> Result<Self, HeaderParseError> {
         if let Some((ty, range)) = split_once(s, '=') {
             let ty = ty.parse()?;
 
@@ -228,24 +403,34 @@ impl std::str::FromStr for SmpteRange {
                 return Ok(SmpteRange::Empty(ty));
             }
 
-            let range = range.strip_prefix('=').ok_or(HeaderParseError)?;
+            let range = range.strip_prefix('=').ok_or_else(HeaderParseError::new)?;
 
-            let (from, to) = split_once(range, '-').ok_or(HeaderParseError)?;
+            let (from, to) = match split_once(range, '-') {
+                Some(v) => v,
+                // Some servers only send a start time without the trailing `-`
+                None if lenient => (range, ""),
+                None => return Err(HeaderParseError::new()),
+            };
+            let (from, to) = if lenient {
+                (from.trim(), to.trim())
+            } else {
+                (from, to)
+            };
             let from = if from.is_empty() { None } else { Some(from) };
             let to = if to.is_empty() { None } else { Some(to) };
 
             let from = from
-                .map(|s| s.parse::<SmpteTime>().map_err(|_| HeaderParseError))
+                .map(|s| s.parse::<SmpteTime>().map_err(|_| HeaderParseError::new()))
                 .transpose()?;
             let to = to
-                .map(|s| s.parse::<SmpteTime>().map_err(|_| HeaderParseError))
+                .map(|s| s.parse::<SmpteTime>().map_err(|_| HeaderParseError::new()))
                 .transpose()?;
 
             match (from, to) {
                 (Some(from), Some(to)) => Ok(SmpteRange::FromTo(ty, from, to)),
                 (None, Some(to)) => Ok(SmpteRange::To(ty, to)),
                 (Some(from), None) => Ok(SmpteRange::From(ty, from)),
-                (None, None) => Err(HeaderParseError),
+                (None, None) => Err(HeaderParseError::new()),
             }
         } else {
             Ok(SmpteRange::Empty(s.parse()?))
@@ -253,6 +438,14 @@ impl std::str::FromStr for SmpteRange {
     }
 }
 
+impl std::str::FromStr for SmpteRange {
+    type Err = HeaderParseError;
+
+    fn from_str(s: &str) -> Result<Self, HeaderParseError> {
+        Self::parse(s, false)
+    }
+}
+
 /// SMPTE-Relative Timecode Type ([RFC 7826 section 4.4.1](https://tools.ietf.org/html/rfc7826#section-4.4.1)).
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -282,7 +475,7 @@ impl std::str::FromStr for SmpteType {
     type Err = HeaderParseError;
 
     fn from_str(s: &str) -> Result<Self, HeaderParseError> {
-        let stripped = s.strip_prefix("smpte").ok_or(HeaderParseError)?;
+        let stripped = s.strip_prefix("smpte").ok_or_else(HeaderParseError::new)?;
         match stripped {
             "" => Ok(SmpteType::Smpte),
             "-30-drop" => Ok(SmpteType::Smpte30Drop),
@@ -328,6 +521,20 @@ impl fmt::Display for SmpteTime {
     }
 }
 
+impl SmpteTime {
+    /// Key for comparing timecodes, considering missing frames as zero.
+    fn key(&self) -> (u8, u8, u8, u8, u8) {
+        let (frames, subframes) = self.frames.unwrap_or((0, None));
+        (
+            self.hours,
+            self.minutes,
+            self.seconds,
+            frames,
+            subframes.unwrap_or(0),
+        )
+    }
+}
+
 impl std::str::FromStr for SmpteTime {
     type Err = HeaderParseError;
 
@@ -337,15 +544,15 @@ impl std::str::FromStr for SmpteTime {
         let hours = s
             .next()
             .and_then(|s| s.parse::<u8>().ok())
-            .ok_or(HeaderParseError)?;
+            .ok_or_else(HeaderParseError::new)?;
         let minutes = s
             .next()
             .and_then(|s| s.parse::<u8>().ok())
-            .ok_or(HeaderParseError)?;
+            .ok_or_else(HeaderParseError::new)?;
         let seconds = s
             .next()
             .and_then(|s| s.parse::<u8>().ok())
-            .ok_or(HeaderParseError)?;
+            .ok_or_else(HeaderParseError::new)?;
 
         let frames = match s.next() {
             Some(frames) => frames,
@@ -360,20 +567,23 @@ impl std::str::FromStr for SmpteTime {
         };
 
         if s.next().is_some() {
-            return Err(HeaderParseError);
+            return Err(HeaderParseError::new());
         }
 
         if let Some((frames, subframes)) = split_once(frames, '.') {
-            let frames = frames.parse::<u8>().map_err(|_| HeaderParseError)?;
+            let frames = frames.parse::<u8>().map_err(|_| HeaderParseError::new())?;
             let digits = subframes.len();
 
             let factor = match digits {
                 1 => 10,
                 2 => 1,
-                _ => return Err(HeaderParseError),
+                _ => return Err(HeaderParseError::new()),
             };
 
-            let subframes = subframes.parse::<u8>().map_err(|_| HeaderParseError)? * factor;
+            let subframes = subframes
+                .parse::<u8>()
+                .map_err(|_| HeaderParseError::new())?
+                * factor;
 
             Ok(SmpteTime {
                 hours,
@@ -382,7 +592,7 @@ impl std::str::FromStr for SmpteTime {
                 frames: Some((frames, Some(subframes))),
             })
         } else {
-            let frames = frames.parse::<u8>().map_err(|_| HeaderParseError)?;
+            let frames = frames.parse::<u8>().map_err(|_| HeaderParseError::new())?;
 
             Ok(SmpteTime {
                 hours,
@@ -419,38 +629,85 @@ impl fmt::Display for UtcRange {
     }
 }
 
-impl std::str::FromStr for UtcRange {
-    type Err = HeaderParseError;
+impl UtcRange {
+    /// Creates a range from `from` to `to`.
+    ///
+    /// Returns `None` if `from` is after `to`.
+    pub fn from_to(from: UtcTime, to: UtcTime) -> Option<Self> {
+        if from.key() > to.key() {
+            None
+        } else {
+            Some(UtcRange::FromTo(from, to))
+        }
+    }
 
-    fn from_str(s: &str) -> Result<Self, HeaderParseError> {
-        let s = s.strip_prefix("clock").ok_or(HeaderParseError)?;
+    /// Checks if `t` is inside this range, including the start and end.
+    ///
+    /// This is always `false` for empty ranges.
+    pub fn contains(&self, t: UtcTime) -> bool {
+        let t = t.key();
+
+        match self {
+            UtcRange::Empty => false,
+            UtcRange::From(f) => f.key() <= t,
+            UtcRange::FromTo(f, to) => f.key() <= t && t <= to.key(),
+            UtcRange::To(to) => t <= to.key(),
+        }
+    }
+
+    /// Parses a UTC range while allowing whitespace around the `-` separator,
+    /// and a start time without any `-` separator.
+    pub fn parse_lenient(s: &str) -> Result<Self, HeaderParseError> {
+        Self::parse(s.trim(), true)
+    }
+
+    fn parse(s: &str, lenient: bool) -> Result<Self, HeaderParseError> {
+        let s = s.strip_prefix("clock").ok_or_else(HeaderParseError::new)?;
 
         if s.is_empty() {
             return Ok(UtcRange::Empty);
         }
 
-        let s = s.strip_prefix('=').ok_or(HeaderParseError)?;
+        let s = s.strip_prefix('=').ok_or_else(HeaderParseError::new)?;
 
-        let (from, to) = split_once(s, '-').ok_or(HeaderParseError)?;
+        let (from, to) = match split_once(s, '-') {
+            Some(v) => v,
+            // Some servers only send a start time without the trailing `-`
+            None if lenient => (s, ""),
+            None => return Err(HeaderParseError::new()),
+        };
+        let (from, to) = if lenient {
+            (from.trim(), to.trim())
+        } else {
+            (from, to)
+        };
         let from = if from.is_empty() { None } else { Some(from) };
         let to = if to.is_empty() { None } else { Some(to) };
 
         let from = from
-            .map(|s| s.parse::<UtcTime>().map_err(|_| HeaderParseError))
+            .map(|s| s.parse::<UtcTime>().map_err(|_| HeaderParseError::new()))
             .transpose()?;
         let to = to
-            .map(|s| s.parse::<UtcTime>().map_err(|_| HeaderParseError))
+            .map(|s| s.parse::<UtcTime>().map_err(|_| HeaderParseError::new()))
             .transpose()?;
 
         match (from, to) {
             (Some(from), Some(to)) => Ok(UtcRange::FromTo(from, to)),
             (None, Some(to)) => Ok(UtcRange::To(to)),
             (Some(from), None) => Ok(UtcRange::From(from)),
-            (None, None) => Err(HeaderParseError),
+            (None, None) => Err(HeaderParseError::new()),
         }
     }
 }
 
+impl std::str::FromStr for UtcRange {
+    type Err = HeaderParseError;
+
+    fn from_str(s: &str) -> Result<Self, HeaderParseError> {
+        Self::parse(s, false)
+    }
+}
+
 /// Absolute Time (UTC) Time ([RFC 7826 section 4.4.3](https://tools.ietf.org/html/rfc7826#section-4.4.3)).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -473,27 +730,36 @@ impl fmt::Display for UtcTime {
     }
 }
 
+impl UtcTime {
+    /// Key for comparing times, considering missing nanoseconds as zero.
+    fn key(&self) -> (u32, u32, u32) {
+        (self.date, self.time, self.nanoseconds.unwrap_or(0))
+    }
+}
+
 impl std::str::FromStr for UtcTime {
     type Err = HeaderParseError;
 
     fn from_str(s: &str) -> Result<Self, HeaderParseError> {
-        let (date, time) = split_once(s, 'T').ok_or(HeaderParseError)?;
-        let time = time.strip_suffix('Z').ok_or(HeaderParseError)?;
+        let (date, time) = split_once(s, 'T').ok_or_else(HeaderParseError::new)?;
+        let time = time.strip_suffix('Z').ok_or_else(HeaderParseError::new)?;
 
-        let date = date.parse::<u32>().map_err(|_| HeaderParseError)?;
+        let date = date.parse::<u32>().map_err(|_| HeaderParseError::new())?;
         let (time, nanoseconds) = if let Some((time, subseconds)) = split_once(time, '.') {
-            let time = time.parse::<u32>().map_err(|_| HeaderParseError)?;
+            let time = time.parse::<u32>().map_err(|_| HeaderParseError::new())?;
             let digits = subseconds.len();
             if digits > 9 || digits == 0 {
-                return Err(HeaderParseError);
+                return Err(HeaderParseError::new());
             }
-            let subseconds = subseconds.parse::<u32>().map_err(|_| HeaderParseError)?;
+            let subseconds = subseconds
+                .parse::<u32>()
+                .map_err(|_| HeaderParseError::new())?;
 
             let nanoseconds = subseconds * u32::pow(10, 9 - digits as u32);
 
             (time, Some(nanoseconds))
         } else {
-            let time = time.parse::<u32>().map_err(|_| HeaderParseError)?;
+            let time = time.parse::<u32>().map_err(|_| HeaderParseError::new())?;
 
             (time, None)
         };
@@ -515,7 +781,12 @@ impl super::TypedHeader for Range {
             Some(header) => header,
         };
 
-        Ok(Some(header.as_str().parse()?))
+        Ok(Some(
+            header
+                .as_str()
+                .parse()
+                .map_err(|_| HeaderParseError::for_header(RANGE))?,
+        ))
     }
 
     fn insert_into(&self, mut headers: impl AsMut<Headers>) {
@@ -589,5 +860,198 @@ mod tests {
 
             assert_eq!(range, serialized.unwrap_or(header), "{header}");
         }
+
+        // Only accepted by lenient parsing
+        for header in [
+            "npt=10",
+            "npt=now",
+            "npt=0 - 10",
+            "clock=19961108T143720.25Z",
+        ] {
+            let request = crate::Request::builder(crate::Method::Play, crate::Version::V2_0)
+                .header(crate::headers::RANGE, header)
+                .empty();
+
+            assert_eq!(
+                request.typed_header::<super::Range>(),
+                Err(HeaderParseError::for_header(RANGE)),
+                "{header}"
+            );
+            assert!(Range::from_headers_lenient(&request).unwrap().is_some());
+        }
+    }
+
+    #[test]
+    fn test_range_lenient() {
+        let headers = [
+            (
+                "npt=0 - 10",
+                Range::Npt(NptRange::FromTo(
+                    NptTime::Seconds(0, None),
+                    NptTime::Seconds(10, None),
+                )),
+            ),
+            ("npt=now -", Range::Npt(NptRange::From(NptTime::Now))),
+            (
+                "npt= - 456",
+                Range::Npt(NptRange::To(NptTime::Seconds(456, None))),
+            ),
+        ];
+
+        for (header, expected) in &headers {
+            assert!(header.parse::<Range>().is_err(), "{}", header);
+            assert_eq!(
+                Range::parse_lenient(header).as_ref(),
+                Ok(expected),
+                "{}",
+                header
+            );
+
+            let request = crate::Request::builder(crate::Method::Play, crate::Version::V2_0)
+                .header(crate::headers::RANGE, *header)
+                .empty();
+
+            assert_eq!(
+                request.typed_header::<super::Range>(),
+                Err(HeaderParseError::for_header(RANGE)),
+                "{}",
+                header
+            );
+            let range = Range::from_headers_lenient(&request).unwrap().unwrap();
+            assert_eq!(range, *expected, "{}", header);
+        }
+    }
+
+    #[test]
+    fn test_range_lenient_bare_start() {
+        let headers = [
+            (
+                "npt=10",
+                Range::Npt(NptRange::From(NptTime::Seconds(10, None))),
+            ),
+            ("npt=now", Range::Npt(NptRange::From(NptTime::Now))),
+            (
+                "clock=19961108T143720.25Z",
+                Range::Utc(UtcRange::From(UtcTime {
+                    date: 19961108,
+                    time: 143720,
+                    nanoseconds: Some(250_000_000),
+                })),
+            ),
+        ];
+
+        for (header, expected) in &headers {
+            assert!(header.parse::<Range>().is_err(), "{}", header);
+            assert_eq!(
+                Range::parse_lenient(header).as_ref(),
+                Ok(expected),
+                "{}",
+                header
+            );
+
+            let request = crate::Request::builder(crate::Method::Play, crate::Version::V2_0)
+                .header(crate::headers::RANGE, *header)
+                .empty();
+
+            assert_eq!(
+                request.typed_header::<super::Range>(),
+                Err(HeaderParseError::for_header(RANGE)),
+                "{}",
+                header
+            );
+            let range = Range::from_headers_lenient(&request).unwrap().unwrap();
+            assert_eq!(range, *expected, "{}", header);
+        }
+
+        let request = crate::Request::builder(crate::Method::Play, crate::Version::V2_0)
+            .header(RANGE, "npt=abc")
+            .empty();
+        assert_eq!(
+            Range::from_headers_lenient(&request),
+            Err(HeaderParseError::for_header(RANGE))
+        );
+    }
+
+    #[test]
+    fn test_range_contains() {
+        let range =
+            NptRange::from_to(NptTime::Seconds(10, None), NptTime::Hms(0, 1, 0, None)).unwrap();
+        assert!(range.contains(NptTime::Seconds(10, None)));
+        assert!(range.contains(NptTime::Seconds(30, Some(500_000_000))));
+        assert!(range.contains(NptTime::Seconds(60, None)));
+        assert!(!range.contains(NptTime::Seconds(60, Some(1))));
+        assert!(!range.contains(NptTime::Seconds(9, None)));
+        assert!(!range.contains(NptTime::Now));
+
+        assert!(NptRange::From(NptTime::Hms(1, 0, 0, None)).contains(NptTime::Seconds(3600, None)));
+        assert!(!NptRange::From(NptTime::Now).contains(NptTime::Seconds(3600, None)));
+        assert!(NptRange::To(NptTime::Seconds(5, None)).contains(NptTime::Seconds(0, None)));
+        assert!(!NptRange::Empty.contains(NptTime::Seconds(0, None)));
+
+        assert_eq!(
+            NptRange::from_to(NptTime::Hms(0, 1, 0, None), NptTime::Seconds(59, None)),
+            None
+        );
+        assert!(NptRange::from_to(NptTime::Now, NptTime::Seconds(59, None)).is_some());
+
+        let time = |s: &str| s.parse::<SmpteTime>().unwrap();
+        let range = SmpteRange::from_to(SmpteType::Smpte, time("10:07:00"), time("10:07:33:05.01"))
+            .unwrap();
+        assert!(range.contains(time("10:07:00:00")));
+        assert!(range.contains(time("10:07:33:05.01")));
+        assert!(!range.contains(time("10:07:33:05.02")));
+        assert!(!range.contains(time("10:06:59:29")));
+        assert_eq!(
+            SmpteRange::from_to(SmpteType::Smpte25, time("10:07:01"), time("10:07:00:24")),
+            None
+        );
+
+        let time = |s: &str| s.parse::<UtcTime>().unwrap();
+        let range =
+            UtcRange::from_to(time("19961108T142000Z"), time("19961108T143520.25Z")).unwrap();
+        assert!(range.contains(time("19961108T142000.000Z")));
+        assert!(range.contains(time("19961108T143520.25Z")));
+        assert!(!range.contains(time("19961108T143520.26Z")));
+        assert!(!range.contains(time("19961107T150000Z")));
+        assert_eq!(
+            UtcRange::from_to(time("19961108T142000Z"), time("19961107T142000Z")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_npt_time_constructors() {
+        let time = NptTime::from_secs_f64(90.5);
+        assert_eq!(time, NptTime::Seconds(90, Some(500_000_000)));
+        assert_eq!(time.to_string(), "90.500000000");
+        assert_eq!(time.as_secs_f64(), Some(90.5));
+
+        assert_eq!(NptTime::from_secs_f64(0.0).to_string(), "0.000000000");
+        assert_eq!(
+            NptTime::from_secs_f64(0.25),
+            NptTime::Seconds(0, Some(250_000_000))
+        );
+
+        assert_eq!(NptTime::seconds(90).to_string(), "90");
+        assert_eq!(NptTime::seconds(90).as_secs_f64(), Some(90.0));
+
+        let time = NptTime::hms(1, 2, 3);
+        assert_eq!(time.to_string(), "01:02:03");
+        assert_eq!(time.as_secs_f64(), Some(3723.0));
+        assert_eq!(
+            NptTime::Hms(0, 1, 30, Some(500_000_000)).as_secs_f64(),
+            Some(90.5)
+        );
+
+        assert_eq!(NptTime::Now.as_secs_f64(), None);
+
+        let range = Range::Npt(NptRange::From(NptTime::from_secs_f64(90.5)));
+        assert_eq!(range.to_string(), "npt=90.500000000-");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_npt_time_hms_invalid() {
+        NptTime::hms(0, 60, 0);
     }
 }
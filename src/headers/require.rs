@@ -6,7 +6,7 @@ use super::features::*;
 use super::*;
 
 /// `Require` header ([RFC 7826 section 18.43](https://tools.ietf.org/html/rfc7826#section-18.43)).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Require(Vec<String>);
 
@@ -48,6 +48,12 @@ impl<'a> From<&'a [String]> for Require {
     }
 }
 
+impl std::iter::FromIterator<String> for Require {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        Require(iter.into_iter().collect())
+    }
+}
+
 impl<'a> From<&'a [&'a &str]> for Require {
     fn from(v: &'a [&'a &str]) -> Self {
         Require(v.iter().map(|s| String::from(**s)).collect())
@@ -60,6 +66,11 @@ impl Require {
         RequireBuilder(Vec::new())
     }
 
+    /// Returns the features of the `Require` header as [`Feature`]s.
+    pub fn features(&self) -> Vec<Feature> {
+        super::features::features_from_tags(&self.0)
+    }
+
     /// Check if the "play.basic" feature is required.
     ///
     /// See [RFC 7826 section 11.1](https://tools.ietf.org/html/rfc7826#section-11.1).
@@ -155,33 +166,13 @@ impl super::TypedHeader for Require {
 
     fn insert_into(&self, mut headers: impl AsMut<Headers>) {
         let headers = headers.as_mut();
-
-        let mut require = String::new();
-        for feature in &self.0 {
-            if !require.is_empty() {
-                require.push_str(", ");
-            }
-
-            require.push_str(feature);
-        }
-
-        headers.insert(REQUIRE, require);
+        headers.insert(REQUIRE, join_features(&self.0));
     }
 }
 
 impl super::TypedAppendableHeader for Require {
     fn append_to(&self, mut headers: impl AsMut<Headers>) {
         let headers = headers.as_mut();
-
-        let mut require = String::new();
-        for feature in &self.0 {
-            if !require.is_empty() {
-                require.push_str(", ");
-            }
-
-            require.push_str(feature);
-        }
-
-        headers.append(REQUIRE, require);
+        headers.append(REQUIRE, join_features(&self.0));
     }
 }
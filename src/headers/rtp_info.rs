@@ -100,18 +100,18 @@ pub mod v1 {
                     let uri = info
                         .uri
                         .and_then(|uri| url::Url::parse(uri).ok())
-                        .ok_or(HeaderParseError)?;
+                        .ok_or_else(HeaderParseError::new)?;
                     let seq = info
                         .seq
                         .map(|s| s.parse::<u16>())
                         .transpose()
-                        .map_err(|_| HeaderParseError)?;
+                        .map_err(|_| HeaderParseError::new())?;
 
                     let rtptime = info
                         .rtptime
                         .map(|s| s.parse::<u32>())
                         .transpose()
-                        .map_err(|_| HeaderParseError)?;
+                        .map_err(|_| HeaderParseError::new())?;
 
                     Ok(RtpInfo { uri, seq, rtptime })
                 },
@@ -160,7 +160,8 @@ pub mod v2 {
         use super::parser_helpers::{cond_parser, quoted_string, token, trim};
         use crate::nom_extensions::separated_list1_fold;
         use nom::branch::alt;
-        use nom::bytes::complete::{tag, take, take_while};
+        use nom::bytes::complete::{tag, take_while, take_while_m_n};
+        use nom::character::is_hex_digit;
         use nom::combinator::{all_consuming, map, map_res};
         use nom::multi::{many1, separated_list1};
         use nom::sequence::tuple;
@@ -189,9 +190,10 @@ pub mod v2 {
                 tuple((
                     trim(tag(b"ssrc")),
                     trim(tag(b"=")),
-                    map_res(map_res(take(8usize), str::from_utf8), |s| {
-                        u32::from_str_radix(s, 16)
-                    }),
+                    map_res(
+                        map_res(take_while_m_n(1, 8, is_hex_digit), str::from_utf8),
+                        |s| u32::from_str_radix(s, 16),
+                    ),
                     cond_parser(
                         trim(tag(b":")),
                         separated_list1_fold(
@@ -210,13 +212,17 @@ pub mod v2 {
                     let mut params = params.unwrap_or_default();
 
                     let seq = if let Some((_, Some(seq))) = params.remove_entry("seq") {
-                        Some(seq.parse::<u16>().map_err(|_| HeaderParseError)?)
+                        Some(seq.parse::<u16>().map_err(|_| HeaderParseError::new())?)
                     } else {
                         None
                     };
 
                     let rtptime = if let Some((_, Some(rtptime))) = params.remove_entry("rtptime") {
-                        Some(rtptime.parse::<u32>().map_err(|_| HeaderParseError)?)
+                        Some(
+                            rtptime
+                                .parse::<u32>()
+                                .map_err(|_| HeaderParseError::new())?,
+                        )
                     } else {
                         None
                     };
@@ -305,8 +311,8 @@ impl super::TypedHeader for RtpInfos {
             Some(header) => header,
         };
 
-        let (_rem, rtp_info) =
-            parser::rtp_infos(header.as_str().as_bytes()).map_err(|_| HeaderParseError)?;
+        let (_rem, rtp_info) = parser::rtp_infos(header.as_str().as_bytes())
+            .map_err(|_| HeaderParseError::for_header(RTP_INFO))?;
 
         Ok(Some(rtp_info))
     }
@@ -423,6 +429,55 @@ mod tests {
         assert_eq!(response, response2);
     }
 
+    #[test]
+    fn test_info_short_ssrc() {
+        for header in [
+            "url=\"rtsp://example.com/foo/audio\" ssrc=A13C760:seq=45102;rtptime=12345678",
+            "url=\"rtsp://example.com/foo/audio\" ssrc=0a13c760:seq=45102;rtptime=12345678",
+            "url=\"rtsp://example.com/foo/audio\" ssrc=a13c760:seq=45102;rtptime=12345678",
+        ] {
+            let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+                .header(crate::headers::RTP_INFO, header)
+                .empty();
+
+            let infos = response.typed_header::<super::RtpInfos>().unwrap().unwrap();
+
+            assert_eq!(
+                infos,
+                RtpInfos::V2(vec![v2::RtpInfo {
+                    uri: url::Url::parse("rtsp://example.com/foo/audio").unwrap(),
+                    ssrc_infos: vec![v2::SsrcInfo {
+                        ssrc: 0x0A13C760,
+                        seq: Some(45102),
+                        rtptime: Some(12345678),
+                        others: BTreeMap::new()
+                    }],
+                }]),
+                "{header}"
+            );
+
+            let response2 = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+                .typed_header(&infos)
+                .empty();
+
+            assert_eq!(
+                response2
+                    .header(&crate::headers::RTP_INFO)
+                    .unwrap()
+                    .as_str(),
+                "url=\"rtsp://example.com/foo/audio\" ssrc=0A13C760:seq=45102;rtptime=12345678"
+            );
+        }
+
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .header(
+                crate::headers::RTP_INFO,
+                "url=\"rtsp://example.com/foo/audio\" ssrc=10A13C760:seq=45102",
+            )
+            .empty();
+        assert!(response.typed_header::<super::RtpInfos>().is_err());
+    }
+
     #[test]
     fn test_info_multiple_ssrc() {
         let header =
@@ -557,4 +612,68 @@ mod tests {
 
         assert_eq!(response, response2);
     }
+
+    #[test]
+    fn test_info_serialization_layout() {
+        let serialize = |header: &str| {
+            let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+                .header(crate::headers::RTP_INFO, header)
+                .empty();
+
+            let infos = response.typed_header::<super::RtpInfos>().unwrap().unwrap();
+
+            let response2 = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+                .typed_header(&infos)
+                .empty();
+
+            String::from(
+                response2
+                    .header(&crate::headers::RTP_INFO)
+                    .unwrap()
+                    .as_str(),
+            )
+        };
+
+        let headers = [
+            // RTSP 1.0
+            (
+                "url=rtsp://example.com/foo/audio;seq=0;rtptime=0",
+                "url=rtsp://example.com/foo/audio;seq=0;rtptime=0",
+            ),
+            (
+                "url=rtsp://example.com/foo/audio;rtptime=4294967295;seq=65535",
+                "url=rtsp://example.com/foo/audio;seq=65535;rtptime=4294967295",
+            ),
+            (
+                "url=rtsp://example.com/foo/audio ; seq=00012,url=rtsp://example.com/foo/video",
+                "url=rtsp://example.com/foo/audio;seq=12,url=rtsp://example.com/foo/video",
+            ),
+            // RTSP 2.0
+            (
+                "url=\"rtsp://example.com/foo/audio\" ssrc=0A13C760:seq=0;rtptime=0",
+                "url=\"rtsp://example.com/foo/audio\" ssrc=0A13C760:seq=0;rtptime=0",
+            ),
+            (
+                "url=\"rtsp://example.com/foo/audio\"  ssrc=0A13C760 : rtptime=12345678 ; seq=00012",
+                "url=\"rtsp://example.com/foo/audio\" ssrc=0A13C760:seq=12;rtptime=12345678",
+            ),
+            (
+                "url=\"rtsp://example.com/foo/audio\" ssrc=0A13C760",
+                "url=\"rtsp://example.com/foo/audio\" ssrc=0A13C760",
+            ),
+            (
+                "url=\"rtsp://example.com/foo/audio\" ssrc=0A13C760:rtptime=1;foo=bar;baz",
+                "url=\"rtsp://example.com/foo/audio\" ssrc=0A13C760:rtptime=1;baz;foo=bar",
+            ),
+            (
+                "url=\"rtsp://example.com/foo/audio\" ssrc=0A13C760:seq=1 ssrc=9A9DE123,url=\"rtsp://example.com/foo/video\" ssrc=00000001:seq=2",
+                "url=\"rtsp://example.com/foo/audio\" ssrc=0A13C760:seq=1 ssrc=9A9DE123,url=\"rtsp://example.com/foo/video\" ssrc=00000001:seq=2",
+            ),
+        ];
+
+        for (header, expected) in &headers {
+            assert_eq!(serialize(header), *expected, "{}", header);
+            assert_eq!(serialize(expected), *expected, "{}", expected);
+        }
+    }
 }
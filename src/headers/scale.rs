@@ -5,10 +5,16 @@
 use super::*;
 
 /// `Scale` header ([RFC 7826 section 18.46](https://tools.ietf.org/html/rfc7826#section-18.46)).
+///
+/// The value is inserted in its minimal exact representation, e.g. `2` or `1.5`. Some devices
+/// only accept a fixed number of decimal places, which can be inserted with
+/// [`Scale::insert_with_precision`](#method.insert_with_precision).
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Scale(f64);
 
+impl_with_precision!(Scale, SCALE);
+
 impl std::ops::Deref for Scale {
     type Target = f64;
 
@@ -56,11 +62,15 @@ impl super::TypedHeader for Scale {
             Some(header) => header,
         };
 
+        // This also accepts a leading `+` as sent by some servers, but no infinity or NaN
         let scale = header
             .as_str()
+            .trim()
             .parse::<f64>()
-            .map(Scale)
-            .map_err(|_| HeaderParseError)?;
+            .ok()
+            .filter(|v| v.is_finite())
+            .map(Scale::from)
+            .ok_or_else(|| HeaderParseError::for_header(SCALE))?;
 
         Ok(Some(scale))
     }
@@ -71,3 +81,51 @@ impl super::TypedHeader for Scale {
         headers.insert(SCALE, self.0.to_string());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale_plus_sign() {
+        for (header, expected) in [("+2.0", 2.0), ("2.0", 2.0), ("-2.0", -2.0)] {
+            let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+                .header(SCALE, header)
+                .empty();
+
+            let scale = response.typed_header::<Scale>().unwrap().unwrap();
+            assert_eq!(*scale, expected, "{}", header);
+
+            let response2 = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+                .typed_header(&scale)
+                .empty();
+            assert_eq!(
+                response2.header(&SCALE).unwrap().as_str(),
+                header.trim_start_matches('+').trim_end_matches(".0")
+            );
+        }
+
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .header(SCALE, "+-2.0")
+            .empty();
+        assert!(response.typed_header::<Scale>().is_err());
+    }
+
+    #[test]
+    fn test_scale_precision() {
+        for (value, expected, expected_precision) in [(2.0, "2", "2.000"), (-0.5, "-0.5", "-0.500")]
+        {
+            let scale = Scale::from(value);
+            assert_eq!(scale.to_string_with_precision(3), expected_precision);
+
+            let mut request = crate::Request::builder(crate::Method::Play, crate::Version::V1_0)
+                .typed_header(&scale)
+                .empty();
+            assert_eq!(request.header(&SCALE).unwrap().as_str(), expected);
+
+            scale.insert_with_precision(&mut request, 3);
+            assert_eq!(request.header(&SCALE).unwrap().as_str(), expected_precision);
+            assert_eq!(request.typed_header::<Scale>(), Ok(Some(scale)));
+        }
+    }
+}
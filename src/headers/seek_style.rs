@@ -58,7 +58,10 @@ impl super::TypedHeader for SeekStyle {
             Some(header) => header,
         };
 
-        let seek_style = header.as_str().parse().map_err(|_| HeaderParseError)?;
+        let seek_style = header
+            .as_str()
+            .parse()
+            .map_err(|_| HeaderParseError::for_header(SEEK_STYLE))?;
 
         Ok(Some(seek_style))
     }
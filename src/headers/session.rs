@@ -5,6 +5,10 @@
 use super::*;
 
 /// `Session` header ([RFC 7826 section 18.49](https://tools.ietf.org/html/rfc7826#section-18.49)).
+///
+/// The header has the same `id;timeout=N` form in RTSP 1.0 and 2.0. Some RTSP 1.0 servers add
+/// further parameters, e.g. connection information, which are preserved and available via
+/// [`Session::params`].
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Session(
@@ -12,11 +16,27 @@ pub struct Session(
     pub String,
     /// Optional session timeout in seconds.
     pub Option<u64>,
+    /// Other parameters in the order they appear in the header.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub Vec<(String, Option<String>)>,
 );
 
 impl Session {
     pub fn with_timeout(id: String, timeout: u64) -> Self {
-        Self(id, Some(timeout))
+        Self(id, Some(timeout), Vec::new())
+    }
+
+    /// Other parameters in the order they appear in the header.
+    pub fn params(&self) -> &[(String, Option<String>)] {
+        &self.2
+    }
+
+    /// Replaces the other parameters.
+    ///
+    /// These are written after the timeout.
+    pub fn with_params(mut self, params: Vec<(String, Option<String>)>) -> Self {
+        self.2 = params;
+        self
     }
 }
 
@@ -36,18 +56,20 @@ impl AsRef<str> for Session {
 
 impl<'a> From<&'a str> for Session {
     fn from(v: &'a str) -> Session {
-        Session(v.into(), None)
+        Session(v.into(), None, Vec::new())
     }
 }
 
 impl From<String> for Session {
     fn from(v: String) -> Session {
-        Session(v, None)
+        Session(v, None, Vec::new())
     }
 }
 
 impl super::TypedHeader for Session {
     fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError> {
+        use super::parser_helpers::split_once;
+
         let headers = headers.as_ref();
 
         let header = match headers.get(&SESSION) {
@@ -57,24 +79,52 @@ impl super::TypedHeader for Session {
 
         let mut iter = header.as_str().split(';');
 
-        let session_id = iter.next().ok_or(HeaderParseError)?;
-        let timeout = iter
-            .find_map(|s| s.strip_prefix("timeout="))
-            .map(|s| s.parse::<u64>())
-            .transpose()
-            .map_err(|_| HeaderParseError)?;
+        let session_id = iter
+            .next()
+            .ok_or_else(|| HeaderParseError::for_header(SESSION))?;
 
-        Ok(Some(Session(session_id.into(), timeout)))
+        let mut timeout = None;
+        let mut others = Vec::new();
+        for param in iter.map(str::trim).filter(|param| !param.is_empty()) {
+            match param.strip_prefix("timeout=") {
+                Some(value) if timeout.is_none() => {
+                    timeout = Some(
+                        value
+                            .parse::<u64>()
+                            .map_err(|_| HeaderParseError::for_header(SESSION))?,
+                    );
+                }
+                _ => match split_once(param, '=') {
+                    Some((name, value)) => {
+                        others.push((String::from(name), Some(String::from(value))))
+                    }
+                    None => others.push((String::from(param), None)),
+                },
+            }
+        }
+
+        Ok(Some(Session(session_id.into(), timeout, others)))
     }
 
     fn insert_into(&self, mut headers: impl AsMut<Headers>) {
+        use std::fmt::Write;
+
         let headers = headers.as_mut();
 
+        let mut session = self.0.clone();
         if let Some(timeout) = self.1 {
-            headers.insert(SESSION, format!("{};timeout={}", self.0, timeout));
-        } else {
-            headers.insert(SESSION, self.0.to_string());
+            write!(&mut session, ";timeout={timeout}").unwrap();
         }
+        for (name, value) in &self.2 {
+            session.push(';');
+            session.push_str(name);
+            if let Some(value) = value {
+                session.push('=');
+                session.push_str(value);
+            }
+        }
+
+        headers.insert(SESSION, session);
     }
 }
 
@@ -85,61 +135,130 @@ mod tests {
     #[test]
     fn test_from_headers() {
         let strict_headers = [
-            ("12345678", Some(Session("12345678".to_string(), None))),
+            (
+                "12345678",
+                Some(Session("12345678".to_string(), None, Vec::new())),
+            ),
             (
                 "12345678;timeout=60",
-                Some(Session("12345678".to_string(), Some(60))),
+                Some(Session("12345678".to_string(), Some(60), Vec::new())),
             ),
             (
                 "lskdjf238742dkjlskjd;timeout=60",
-                Some(Session("lskdjf238742dkjlskjd".to_string(), Some(60))),
+                Some(Session(
+                    "lskdjf238742dkjlskjd".to_string(),
+                    Some(60),
+                    Vec::new(),
+                )),
             ),
             (
                 "alskdjalskjdalskjdalksjd;timeout=60",
-                Some(Session("alskdjalskjdalskjdalksjd".to_string(), Some(60))),
+                Some(Session(
+                    "alskdjalskjdalskjdalksjd".to_string(),
+                    Some(60),
+                    Vec::new(),
+                )),
             ),
         ];
 
         let loose_headers = [
             (
                 "12345678;timeout=60;special",
-                Some(Session("12345678".to_string(), Some(60))),
+                Some(Session(
+                    "12345678".to_string(),
+                    Some(60),
+                    vec![(String::from("special"), None)],
+                )),
             ),
             (
                 "12345678;timeout=60;393939393",
-                Some(Session("12345678".to_string(), Some(60))),
+                Some(Session(
+                    "12345678".to_string(),
+                    Some(60),
+                    vec![(String::from("393939393"), None)],
+                )),
             ),
             (
                 "12345678;timeout=60;393;93;93;93",
-                Some(Session("12345678".to_string(), Some(60))),
+                Some(Session(
+                    "12345678".to_string(),
+                    Some(60),
+                    vec![
+                        (String::from("393"), None),
+                        (String::from("93"), None),
+                        (String::from("93"), None),
+                        (String::from("93"), None),
+                    ],
+                )),
             ),
             (
                 "12345678;special;timeout=600",
-                Some(Session("12345678".to_string(), Some(600))),
+                Some(Session(
+                    "12345678".to_string(),
+                    Some(600),
+                    vec![(String::from("special"), None)],
+                )),
             ),
             (
                 "12345678;extra;extra;extra;timeout=600",
-                Some(Session("12345678".to_string(), Some(600))),
+                Some(Session(
+                    "12345678".to_string(),
+                    Some(600),
+                    vec![
+                        (String::from("extra"), None),
+                        (String::from("extra"), None),
+                        (String::from("extra"), None),
+                    ],
+                )),
             ),
             (
                 "wjdl38ek98;timeout=60;special",
-                Some(Session("wjdl38ek98".to_string(), Some(60))),
+                Some(Session(
+                    "wjdl38ek98".to_string(),
+                    Some(60),
+                    vec![(String::from("special"), None)],
+                )),
             ),
             (
                 "wjdl38ek98;timeout=60;393939393",
-                Some(Session("wjdl38ek98".to_string(), Some(60))),
+                Some(Session(
+                    "wjdl38ek98".to_string(),
+                    Some(60),
+                    vec![(String::from("393939393"), None)],
+                )),
             ),
             (
                 "wjdl38ek98;timeout=60;393;93;93;93",
-                Some(Session("wjdl38ek98".to_string(), Some(60))),
+                Some(Session(
+                    "wjdl38ek98".to_string(),
+                    Some(60),
+                    vec![
+                        (String::from("393"), None),
+                        (String::from("93"), None),
+                        (String::from("93"), None),
+                        (String::from("93"), None),
+                    ],
+                )),
             ),
             (
                 "wjdl38ek98;special;timeout=600",
-                Some(Session("wjdl38ek98".to_string(), Some(600))),
+                Some(Session(
+                    "wjdl38ek98".to_string(),
+                    Some(600),
+                    vec![(String::from("special"), None)],
+                )),
             ),
             (
                 "wjdl38ek98;extra;extra;extra;timeout=600",
-                Some(Session("wjdl38ek98".to_string(), Some(600))),
+                Some(Session(
+                    "wjdl38ek98".to_string(),
+                    Some(600),
+                    vec![
+                        (String::from("extra"), None),
+                        (String::from("extra"), None),
+                        (String::from("extra"), None),
+                    ],
+                )),
             ),
         ];
 
@@ -191,4 +310,51 @@ mod tests {
             assert_eq!(from_headers_result, None, "{header}:{value}");
         }
     }
+
+    #[test]
+    fn test_session_versions() {
+        for version in [crate::Version::V1_0, crate::Version::V2_0] {
+            for header in [
+                "12345678",
+                "12345678;timeout=60",
+                "12345678;timeout=60;conn=192.0.2.1:554",
+                "12345678;conn=192.0.2.1:554;persistent",
+            ] {
+                let response = crate::Response::builder(version, crate::StatusCode::Ok)
+                    .header(SESSION, header)
+                    .empty();
+                let session = response.typed_header::<Session>().unwrap().unwrap();
+                assert_eq!(session.0, "12345678");
+                assert_eq!(
+                    session.params().is_empty(),
+                    !header.contains("conn="),
+                    "{}",
+                    header
+                );
+
+                let response2 = crate::Response::builder(version, crate::StatusCode::Ok)
+                    .typed_header(&session)
+                    .empty();
+                assert_eq!(
+                    response2.header(&SESSION).unwrap().as_str(),
+                    header,
+                    "{:?}",
+                    version
+                );
+            }
+        }
+
+        let response = crate::Response::builder(crate::Version::V1_0, crate::StatusCode::Ok)
+            .header(SESSION, "12345678;timeout=60;conn=192.0.2.1:554;persistent")
+            .empty();
+        assert_eq!(
+            response.typed_header::<Session>(),
+            Ok(Some(
+                Session::with_timeout("12345678".to_string(), 60).with_params(vec![
+                    (String::from("conn"), Some(String::from("192.0.2.1:554"))),
+                    (String::from("persistent"), None),
+                ])
+            ))
+        );
+    }
 }
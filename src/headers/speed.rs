@@ -5,10 +5,16 @@
 use super::*;
 
 /// `Speed` header ([RFC 7826 section 18.50](https://tools.ietf.org/html/rfc7826#section-18.50)).
+///
+/// The value is inserted in its minimal exact representation, e.g. `2` or `1.5`. Some devices
+/// only accept a fixed number of decimal places, which can be inserted with
+/// [`Speed::insert_with_precision`](#method.insert_with_precision).
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Speed(f64);
 
+impl_with_precision!(Speed, SPEED);
+
 impl std::ops::Deref for Speed {
     type Target = f64;
 
@@ -56,11 +62,15 @@ impl super::TypedHeader for Speed {
             Some(header) => header,
         };
 
+        // This also accepts a leading `+` as sent by some servers, but no infinity or NaN
         let speed = header
             .as_str()
+            .trim()
             .parse::<f64>()
-            .map(Speed)
-            .map_err(|_| HeaderParseError)?;
+            .ok()
+            .filter(|v| v.is_finite())
+            .map(Speed::from)
+            .ok_or_else(|| HeaderParseError::for_header(SPEED))?;
 
         Ok(Some(speed))
     }
@@ -71,3 +81,50 @@ impl super::TypedHeader for Speed {
         headers.insert(SPEED, self.0.to_string());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_speed_plus_sign() {
+        for (header, expected) in [("+1.5", 1.5), ("1.5", 1.5), ("-1.5", -1.5)] {
+            let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+                .header(SPEED, header)
+                .empty();
+
+            let speed = response.typed_header::<Speed>().unwrap().unwrap();
+            assert_eq!(*speed, expected, "{}", header);
+
+            let response2 = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+                .typed_header(&speed)
+                .empty();
+            assert_eq!(
+                response2.header(&SPEED).unwrap().as_str(),
+                header.trim_start_matches('+').trim_end_matches(".0")
+            );
+        }
+
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .header(SPEED, "+-1.5")
+            .empty();
+        assert!(response.typed_header::<Speed>().is_err());
+    }
+
+    #[test]
+    fn test_speed_precision() {
+        for (value, expected, expected_precision) in [(1.0, "1", "1.000"), (2.5, "2.5", "2.500")] {
+            let speed = Speed::from(value);
+            assert_eq!(speed.to_string_with_precision(3), expected_precision);
+
+            let mut request = crate::Request::builder(crate::Method::Play, crate::Version::V1_0)
+                .typed_header(&speed)
+                .empty();
+            assert_eq!(request.header(&SPEED).unwrap().as_str(), expected);
+
+            speed.insert_with_precision(&mut request, 3);
+            assert_eq!(request.header(&SPEED).unwrap().as_str(), expected_precision);
+            assert_eq!(request.typed_header::<Speed>(), Ok(Some(speed)));
+        }
+    }
+}
@@ -6,7 +6,7 @@ use super::features::*;
 use super::*;
 
 /// `Supported` header ([RFC 7826 section 18.51](https://tools.ietf.org/html/rfc7826#section-18.51)).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Supported(Vec<String>);
 
@@ -48,6 +48,12 @@ impl<'a> From<&'a [String]> for Supported {
     }
 }
 
+impl std::iter::FromIterator<String> for Supported {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        Supported(iter.into_iter().collect())
+    }
+}
+
 impl<'a> From<&'a [&'a &str]> for Supported {
     fn from(v: &'a [&'a &str]) -> Self {
         Supported(v.iter().map(|s| String::from(**s)).collect())
@@ -60,6 +66,11 @@ impl Supported {
         SupportedBuilder(Vec::new())
     }
 
+    /// Returns the features of the `Supported` header as [`Feature`]s.
+    pub fn features(&self) -> Vec<Feature> {
+        super::features::features_from_tags(&self.0)
+    }
+
     /// Check if the "play.basic" feature is supported.
     ///
     /// See [RFC 7826 section 11.1](https://tools.ietf.org/html/rfc7826#section-11.1).
@@ -155,33 +166,13 @@ impl super::TypedHeader for Supported {
 
     fn insert_into(&self, mut headers: impl AsMut<Headers>) {
         let headers = headers.as_mut();
-
-        let mut supported = String::new();
-        for feature in &self.0 {
-            if !supported.is_empty() {
-                supported.push_str(", ");
-            }
-
-            supported.push_str(feature);
-        }
-
-        headers.insert(SUPPORTED, supported);
+        headers.insert(SUPPORTED, join_features(&self.0));
     }
 }
 
 impl super::TypedAppendableHeader for Supported {
     fn append_to(&self, mut headers: impl AsMut<Headers>) {
         let headers = headers.as_mut();
-
-        let mut supported = String::new();
-        for feature in &self.0 {
-            if !supported.is_empty() {
-                supported.push_str(", ");
-            }
-
-            supported.push_str(feature);
-        }
-
-        headers.append(SUPPORTED, supported);
+        headers.append(SUPPORTED, join_features(&self.0));
     }
 }
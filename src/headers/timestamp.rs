@@ -0,0 +1,156 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+/// `Timestamp` header ([RFC 7826 section 18.53](https://tools.ietf.org/html/rfc7826#section-18.53)).
+///
+/// ## Echoing the client timestamp
+///
+/// ```rust
+/// use rtsp_types::headers::Timestamp;
+///
+/// let request = rtsp_types::Request::builder(
+///         rtsp_types::Method::Options,
+///         rtsp_types::Version::V2_0
+///     )
+///     .header(rtsp_types::headers::TIMESTAMP, "1234.5")
+///     .empty();
+///
+/// let timestamp = request.typed_header::<Timestamp>().unwrap().unwrap();
+/// let response = rtsp_types::Response::builder(
+///         rtsp_types::Version::V2_0,
+///         rtsp_types::StatusCode::Ok
+///     )
+///     .typed_header(&Timestamp {
+///         delay: Some(0.25),
+///         ..timestamp
+///     })
+///     .empty();
+///
+/// assert_eq!(
+///     response.header(&rtsp_types::headers::TIMESTAMP).unwrap().as_str(),
+///     "1234.5 0.25"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Timestamp {
+    /// Timestamp as sent by the client.
+    pub timestamp: f64,
+    /// Delay in seconds between receiving the request and sending the response.
+    pub delay: Option<f64>,
+}
+
+impl Timestamp {
+    /// Creates a new timestamp without delay.
+    pub fn new(timestamp: f64) -> Self {
+        Timestamp {
+            timestamp,
+            delay: None,
+        }
+    }
+}
+
+// Parses a `1*DIGIT ["." *DIGIT]` number.
+fn number(s: &str) -> Option<f64> {
+    if !s.starts_with(|c: char| c.is_ascii_digit())
+        || !s.chars().all(|c| c.is_ascii_digit() || c == '.')
+    {
+        return None;
+    }
+
+    s.parse::<f64>().ok()
+}
+
+impl super::TypedHeader for Timestamp {
+    fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError> {
+        let headers = headers.as_ref();
+
+        let header = match headers.get(&TIMESTAMP) {
+            None => return Ok(None),
+            Some(header) => header,
+        };
+
+        let mut iter = header.as_str().split_ascii_whitespace();
+
+        let timestamp = iter
+            .next()
+            .and_then(number)
+            .ok_or_else(|| HeaderParseError::for_header(TIMESTAMP))?;
+        let delay = match iter.next() {
+            None => None,
+            Some(delay) => {
+                Some(number(delay).ok_or_else(|| HeaderParseError::for_header(TIMESTAMP))?)
+            }
+        };
+
+        if iter.next().is_some() {
+            return Err(HeaderParseError::for_header(TIMESTAMP));
+        }
+
+        Ok(Some(Timestamp { timestamp, delay }))
+    }
+
+    fn insert_into(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+
+        let value = match self.delay {
+            Some(delay) => format!("{} {}", self.timestamp, delay),
+            None => self.timestamp.to_string(),
+        };
+        headers.insert(TIMESTAMP, value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamp() {
+        for (header, expected) in [
+            ("1234", Timestamp::new(1234.0)),
+            ("0.543", Timestamp::new(0.543)),
+            (
+                "1234.5 0.25",
+                Timestamp {
+                    timestamp: 1234.5,
+                    delay: Some(0.25),
+                },
+            ),
+        ] {
+            let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+                .header(TIMESTAMP, header)
+                .empty();
+
+            let timestamp = response.typed_header::<Timestamp>().unwrap().unwrap();
+            assert_eq!(timestamp, expected, "{}", header);
+
+            let response2 = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+                .typed_header(&timestamp)
+                .empty();
+            assert_eq!(response, response2, "{}", header);
+        }
+
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .header(TIMESTAMP, "  1234.5   0.25 ")
+            .empty();
+        assert_eq!(
+            response.typed_header::<Timestamp>().unwrap(),
+            Some(Timestamp {
+                timestamp: 1234.5,
+                delay: Some(0.25),
+            })
+        );
+
+        for header in ["", "abc", "-1", "1 2 3", "1 inf", ".5"] {
+            let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+                .header(TIMESTAMP, header)
+                .empty();
+            let err = response.typed_header::<Timestamp>().unwrap_err();
+            assert_eq!(err.header_name(), Some(&TIMESTAMP), "{}", header);
+        }
+    }
+}
@@ -4,9 +4,12 @@
 
 use super::*;
 
+use super::parser_helpers::split_once;
+use crate::Host;
 use std::collections::BTreeMap;
 use std::convert::TryFrom;
 use std::fmt;
+use std::ops::RangeInclusive;
 
 /// `Transport` header ([RFC 7826 section 18.54](https://tools.ietf.org/html/rfc7826#section-18.54)).
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -51,6 +54,107 @@ impl<'a> From<&'a [Transport]> for Transports {
     }
 }
 
+impl std::iter::FromIterator<Transport> for Transports {
+    fn from_iter<I: IntoIterator<Item = Transport>>(iter: I) -> Self {
+        Transports(iter.into_iter().collect())
+    }
+}
+
+impl Transports {
+    /// All port ranges assigned by the RTP transports.
+    ///
+    /// Single ports are returned as a range containing only this port. This allows detecting
+    /// overlapping port assignments between multiple transports.
+    pub fn assigned_ports(&self) -> Vec<(PortKind, RangeInclusive<u16>)> {
+        fn range((start, end): (u16, Option<u16>)) -> RangeInclusive<u16> {
+            start..=end.unwrap_or(start)
+        }
+
+        let mut ports = Vec::new();
+        for transport in &self.0 {
+            if let Transport::Rtp(rtp) = transport {
+                if let Some(port) = rtp.params.port {
+                    ports.push((PortKind::Port, range(port)));
+                }
+                if let Some(port) = rtp.params.client_port {
+                    ports.push((PortKind::ClientPort, range(port)));
+                }
+                if let Some(port) = rtp.params.server_port {
+                    ports.push((PortKind::ServerPort, range(port)));
+                }
+            }
+        }
+
+        ports
+    }
+
+    /// All interleaved channel ranges assigned by the RTP transports.
+    ///
+    /// Single channels are returned as a range containing only this channel. This allows
+    /// detecting overlapping channel assignments between multiple transports.
+    pub fn assigned_channels(&self) -> Vec<RangeInclusive<u8>> {
+        self.0
+            .iter()
+            .filter_map(|transport| match transport {
+                Transport::Rtp(rtp) => rtp.params.interleaved,
+                _ => None,
+            })
+            .map(|(start, end)| start..=end.unwrap_or(start))
+            .collect()
+    }
+
+    /// RTP and optional RTCP channels of all interleaved RTP transports.
+    ///
+    /// The RTCP channel is the channel following the RTP channel. If only a single channel is
+    /// given then there is no separate RTCP channel, e.g. because RTP and RTCP are muxed. Channel
+    /// ranges that are neither a single channel nor a pair of channels are skipped, see
+    /// [`Transports::assigned_channels`] for these.
+    pub fn channel_pairs(&self) -> Vec<(u8, Option<u8>)> {
+        self.assigned_channels()
+            .into_iter()
+            .filter_map(|channels| {
+                let (start, end) = channels.into_inner();
+                if start == end {
+                    Some((start, None))
+                } else if start.checked_add(1) == Some(end) {
+                    Some((start, Some(end)))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    /// Flattened views of all transports.
+    ///
+    /// The returned `Vec` has one entry per transport, in the same order. Entries are `None` for
+    /// non-RTP transports and RTP transports with an unknown lower transport. See
+    /// [`RtpTransport::setup`].
+    pub fn setups(&self) -> Vec<Option<TransportSetup>> {
+        self.0
+            .iter()
+            .map(|transport| match transport {
+                Transport::Rtp(rtp) => rtp.setup(),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Kind of a port assigned by a transport.
+///
+/// See [`Transports::assigned_ports`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PortKind {
+    /// Multicast RTP/RTCP port (`port`).
+    Port,
+    /// Client RTP/RTCP port (`client_port`).
+    ClientPort,
+    /// Server RTP/RTCP port (`server_port`).
+    ServerPort,
+}
+
 /// Transport.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -62,6 +166,19 @@ pub enum Transport {
     Other(OtherTransport),
 }
 
+impl Transport {
+    /// Check if this transport is semantically equivalent to another one.
+    ///
+    /// See [`RtpTransport::equivalent`] for details.
+    pub fn equivalent(&self, other: &Transport) -> bool {
+        match (self, other) {
+            (Transport::Rtp(a), Transport::Rtp(b)) => a.equivalent(b),
+            (Transport::Other(a), Transport::Other(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
 /// RTP profiles.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -97,14 +214,19 @@ impl fmt::Display for RtpProfile {
     }
 }
 
+/// Well-known profiles are matched case-insensitively, other profiles are kept verbatim.
 impl<'a> From<&'a str> for RtpProfile {
     fn from(profile: &'a str) -> RtpProfile {
-        match profile {
-            "AVP" => RtpProfile::Avp,
-            "AVPF" => RtpProfile::AvpF,
-            "SAVP" => RtpProfile::SAvp,
-            "SAVPF" => RtpProfile::SAvpF,
-            other => RtpProfile::Other(other.into()),
+        if profile.eq_ignore_ascii_case("AVP") {
+            RtpProfile::Avp
+        } else if profile.eq_ignore_ascii_case("AVPF") {
+            RtpProfile::AvpF
+        } else if profile.eq_ignore_ascii_case("SAVP") {
+            RtpProfile::SAvp
+        } else if profile.eq_ignore_ascii_case("SAVPF") {
+            RtpProfile::SAvpF
+        } else {
+            RtpProfile::Other(profile.into())
         }
     }
 }
@@ -117,10 +239,261 @@ pub struct RtpTransport {
     pub profile: RtpProfile,
     /// RTP lower transport.
     pub lower_transport: Option<RtpLowerTransport>,
+    /// Additional, unknown components of the transport specification after the lower transport,
+    /// e.g. `EXT` for `RTP/AVP/TCP/EXT`.
+    ///
+    /// These are only serialized if a lower transport is set.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub spec_extensions: Vec<String>,
     /// RTP transport parameters.
     pub params: RtpTransportParameters,
 }
 
+impl RtpTransport {
+    /// Creates a new RTP transport builder for the given profile.
+    ///
+    /// ## Building a TCP/interleaved transport
+    ///
+    /// ```rust
+    /// use rtsp_types::headers::{RtpLowerTransport, RtpProfile, RtpTransport};
+    ///
+    /// let transport = RtpTransport::builder(RtpProfile::Avp)
+    ///     .lower_transport(RtpLowerTransport::Tcp)
+    ///     .unicast()
+    ///     .interleaved(0, Some(1))
+    ///     .rtcp_mux()
+    ///     .build();
+    ///
+    /// assert!(transport.params.rtcp_mux);
+    /// ```
+    pub fn builder(profile: RtpProfile) -> RtpTransportBuilder {
+        RtpTransportBuilder(RtpTransport {
+            profile,
+            lower_transport: None,
+            spec_extensions: Vec::new(),
+            params: RtpTransportParameters::default(),
+        })
+    }
+
+    /// Check if this RTP transport is semantically equivalent to another one.
+    ///
+    /// Unlike `==` this considers a missing lower transport to be equivalent to UDP, a missing
+    /// mode to be equivalent to `PLAY`, and ignores the order of modes and SSRCs.
+    pub fn equivalent(&self, other: &RtpTransport) -> bool {
+        let lower_transport_equivalent = match (&self.lower_transport, &other.lower_transport) {
+            (None, None) => true,
+            (None, Some(RtpLowerTransport::Udp)) | (Some(RtpLowerTransport::Udp), None) => true,
+            (Some(a), Some(b)) => a == b,
+            _ => false,
+        };
+
+        self.profile == other.profile
+            && lower_transport_equivalent
+            && self.spec_extensions == other.spec_extensions
+            && self.params.equivalent(&other.params)
+    }
+
+    /// Flattened view of this transport with the information needed for setting up sockets.
+    ///
+    /// Returns `None` for unknown lower transports.
+    ///
+    /// UDP ports are taken from `port` or `client_port`. RTSP 2.0 transports without either of
+    /// them carry the ports in `dest_addr`, with the RTP address first and the RTCP address
+    /// second, which are used instead.
+    ///
+    /// ## Opening sockets for a unicast UDP transport
+    ///
+    /// ```rust
+    /// use rtsp_types::headers::{RtpProfile, RtpTransport, TransportDelivery};
+    ///
+    /// let transport = RtpTransport::builder(RtpProfile::Avp)
+    ///     .unicast()
+    ///     .client_port(8000, Some(8001))
+    ///     .build();
+    ///
+    /// let setup = transport.setup().unwrap();
+    /// assert!(!setup.multicast);
+    /// assert_eq!(
+    ///     setup.delivery,
+    ///     TransportDelivery::Udp {
+    ///         rtp_port: Some(8000),
+    ///         rtcp_port: Some(8001),
+    ///     }
+    /// );
+    /// ```
+    pub fn setup(&self) -> Option<TransportSetup> {
+        let params = &self.params;
+
+        let delivery = match (&self.lower_transport, params.interleaved) {
+            (None, Some((rtp_channel, rtcp_channel)))
+            | (Some(RtpLowerTransport::Tcp), Some((rtp_channel, rtcp_channel))) => {
+                TransportDelivery::Interleaved {
+                    rtp_channel,
+                    rtcp_channel,
+                }
+            }
+            (Some(RtpLowerTransport::Tcp), None) => TransportDelivery::Tcp,
+            (None, None) | (Some(RtpLowerTransport::Udp), _) => {
+                // Multicast transports use `port`, unicast transports `client_port`
+                let ports = if params.multicast {
+                    params.port.or(params.client_port)
+                } else {
+                    params.client_port.or(params.port)
+                };
+                let ports = ports.or_else(|| {
+                    let addrs = params.parsed_dest_addr().ok()?;
+                    let rtp_port = addrs.first()?.1?;
+                    Some((rtp_port, addrs.get(1).and_then(|(_, port)| *port)))
+                });
+
+                TransportDelivery::Udp {
+                    rtp_port: ports.map(|(rtp, _)| rtp),
+                    rtcp_port: ports.and_then(|(_, rtcp)| rtcp),
+                }
+            }
+            (Some(RtpLowerTransport::Other(_)), _) => return None,
+        };
+
+        let destinations = if !params.dest_addr.is_empty() {
+            params.dest_addr.clone()
+        } else {
+            params.destination.iter().cloned().collect()
+        };
+
+        Some(TransportSetup {
+            multicast: params.multicast,
+            delivery,
+            ttl: params.ttl,
+            destinations,
+            rtcp_mux: params.rtcp_mux,
+        })
+    }
+}
+
+/// Flattened view of an RTP transport.
+///
+/// See [`RtpTransport::setup`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransportSetup {
+    /// Multicast instead of unicast transport.
+    pub multicast: bool,
+    /// How the media is delivered.
+    pub delivery: TransportDelivery,
+    /// Multicast packet time-to-live.
+    pub ttl: Option<u8>,
+    /// Destination addresses from `dest_addr` (RTSP 2.0) or `destination` (RTSP 1.0).
+    pub destinations: Vec<String>,
+    /// RTP and RTCP are muxed on the same port or channel.
+    pub rtcp_mux: bool,
+}
+
+/// Delivery of the media of an RTP transport.
+///
+/// See [`TransportSetup`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum TransportDelivery {
+    /// UDP with the RTP and RTCP ports from `client_port` or, for multicast, `port`.
+    Udp {
+        /// RTP port.
+        rtp_port: Option<u16>,
+        /// RTCP port.
+        rtcp_port: Option<u16>,
+    },
+    /// Separate TCP connection.
+    Tcp,
+    /// Interleaved in the RTSP connection.
+    Interleaved {
+        /// RTP channel.
+        rtp_channel: u8,
+        /// RTCP channel.
+        rtcp_channel: Option<u8>,
+    },
+}
+
+/// Builder for an RTP transport.
+#[derive(Debug, Clone)]
+pub struct RtpTransportBuilder(RtpTransport);
+
+impl RtpTransportBuilder {
+    /// Set the lower transport.
+    pub fn lower_transport(mut self, lower_transport: RtpLowerTransport) -> Self {
+        self.0.lower_transport = Some(lower_transport);
+        self
+    }
+
+    /// Use unicast transport.
+    pub fn unicast(mut self) -> Self {
+        self.0.params.unicast = true;
+        self.0.params.multicast = false;
+        self
+    }
+
+    /// Use multicast transport.
+    pub fn multicast(mut self) -> Self {
+        self.0.params.unicast = false;
+        self.0.params.multicast = true;
+        self
+    }
+
+    /// Set the TCP/interleaved transport channels.
+    pub fn interleaved(mut self, start: u8, end: Option<u8>) -> Self {
+        self.0.params.interleaved = Some((start, end));
+        self
+    }
+
+    /// Set the client RTP/RTCP ports. RTSP 1.0 only.
+    pub fn client_port(mut self, start: u16, end: Option<u16>) -> Self {
+        self.0.params.client_port = Some((start, end));
+        self
+    }
+
+    /// Set the server RTP/RTCP ports. RTSP 1.0 only.
+    pub fn server_port(mut self, start: u16, end: Option<u16>) -> Self {
+        self.0.params.server_port = Some((start, end));
+        self
+    }
+
+    /// Add a stream SSRC.
+    pub fn ssrc(mut self, ssrc: u32) -> Self {
+        self.0.params.ssrc.push(ssrc);
+        self
+    }
+
+    /// Add a transport mode.
+    pub fn mode(mut self, mode: TransportMode) -> Self {
+        self.0.params.mode.push(mode);
+        self
+    }
+
+    /// Mux RTP and RTCP on the same transport channel.
+    pub fn rtcp_mux(mut self) -> Self {
+        self.0.params.rtcp_mux = true;
+        self
+    }
+
+    /// Append to the resource. RTSP 1.0 RECORD mode only.
+    pub fn append(mut self) -> Self {
+        self.0.params.append = true;
+        self
+    }
+
+    /// Add an additional component to the transport specification after the lower transport.
+    ///
+    /// This is only serialized if a lower transport is set.
+    pub fn spec_extension(mut self, extension: &str) -> Self {
+        self.0.spec_extensions.push(String::from(extension));
+        self
+    }
+
+    /// Build the RTP transport.
+    pub fn build(self) -> RtpTransport {
+        self.0
+    }
+}
+
 /// RTP transport parameters.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -133,7 +506,8 @@ pub struct RtpTransportParameters {
     pub interleaved: Option<(u8, Option<u8>)>,
     /// Multicast packet time-to-live.
     pub ttl: Option<u8>,
-    // TODO layers
+    /// Number of multicast layers. RTSP 1.0 only.
+    pub layers: Option<u32>,
     /// Stream SSRCs if known.
     pub ssrc: Vec<u32>,
     /// Transport mode.
@@ -172,24 +546,32 @@ impl TryFrom<TransportParameters> for RtpTransportParameters {
 
         for (name, value) in params.0 {
             match name.as_str() {
+                // Values are ignored as some implementations send e.g. `unicast=1`
                 "unicast" => {
                     rtp_params.unicast = true;
                 }
                 "multicast" => {
                     rtp_params.multicast = true;
                 }
+                // Some implementations send `cast=unicast` or `cast=multicast` instead
+                "cast" if value.as_deref() == Some("unicast") => {
+                    rtp_params.unicast = true;
+                }
+                "cast" if value.as_deref() == Some("multicast") => {
+                    rtp_params.multicast = true;
+                }
                 "interleaved" => {
-                    let channels = value.ok_or(HeaderParseError)?;
+                    let channels = value.ok_or_else(HeaderParseError::new)?;
                     let mut channels = channels.splitn(2, '-');
 
                     let channel_start = channels
                         .next()
                         .and_then(|s| s.parse::<u8>().ok())
-                        .ok_or(HeaderParseError)?;
+                        .ok_or_else(HeaderParseError::new)?;
 
                     let channel_end = channels
                         .next()
-                        .map(|s| s.parse::<u8>().map_err(|_| HeaderParseError))
+                        .map(|s| s.parse::<u8>().map_err(|_| HeaderParseError::new()))
                         .transpose()?;
 
                     rtp_params.interleaved = Some((channel_start, channel_end));
@@ -197,57 +579,66 @@ impl TryFrom<TransportParameters> for RtpTransportParameters {
                 "ttl" => {
                     let ttl = value
                         .and_then(|s| s.parse::<u8>().ok())
-                        .ok_or(HeaderParseError)?;
+                        .ok_or_else(HeaderParseError::new)?;
 
                     rtp_params.ttl = Some(ttl);
                 }
+                "layers" => {
+                    let layers = value
+                        .and_then(|s| s.parse::<u32>().ok())
+                        .ok_or_else(HeaderParseError::new)?;
+
+                    rtp_params.layers = Some(layers);
+                }
                 "ssrc" => {
                     let ssrc = value
-                        .ok_or(HeaderParseError)?
+                        .ok_or_else(HeaderParseError::new)?
                         .split('/')
-                        .map(|s| u32::from_str_radix(s, 16).map_err(|_| HeaderParseError))
+                        .map(|s| u32::from_str_radix(s, 16).map_err(|_| HeaderParseError::new()))
                         .collect::<Result<Vec<_>, _>>()?;
 
                     if ssrc.is_empty() {
-                        return Err(HeaderParseError);
+                        return Err(HeaderParseError::new());
                     }
 
                     rtp_params.ssrc = ssrc;
                 }
                 "mode" => {
-                    let modes = value.ok_or(HeaderParseError)?;
+                    let modes = value.ok_or_else(HeaderParseError::new)?;
                     let modes = match (modes.starts_with('"'), modes.ends_with('"')) {
-                        (true, true) => &modes[1..(modes.len() - 1)],
+                        (true, true) if modes.len() >= 2 => &modes[1..(modes.len() - 1)],
                         (false, false) => &modes,
-                        _ => return Err(HeaderParseError),
+                        _ => return Err(HeaderParseError::new()),
                     };
                     let modes = modes
                         .split(',')
-                        .map(TransportMode::from)
+                        .map(|mode| TransportMode::from(mode.trim()))
                         .collect::<Vec<_>>();
 
                     if modes.is_empty() {
-                        return Err(HeaderParseError);
+                        return Err(HeaderParseError::new());
                     }
 
                     rtp_params.mode = modes;
                 }
                 "dest_addr" | "src_addr" => {
                     let addrs = value
-                        .ok_or(HeaderParseError)?
+                        .ok_or_else(HeaderParseError::new)?
                         .split('/')
                         .map(|s| {
-                            if !s.starts_with('"') || !s.ends_with('"') {
-                                return Err(HeaderParseError);
-                            }
+                            let mut s = s
+                                .strip_prefix('"')
+                                .and_then(|s| s.strip_suffix('"'))
+                                .ok_or_else(HeaderParseError::new)?
+                                .as_bytes();
 
                             // Unescape quoted string
                             let mut res = Vec::with_capacity(s.len());
-                            let mut s = s.as_bytes();
-                            s = &s[1..(s.len() - 1)];
                             while !s.is_empty() {
                                 if s.starts_with(b"\\") {
-                                    assert!(s.len() >= 2);
+                                    if s.len() < 2 {
+                                        return Err(HeaderParseError::new());
+                                    }
                                     res.push(s[1]);
                                     s = &s[2..];
                                 } else {
@@ -262,7 +653,7 @@ impl TryFrom<TransportParameters> for RtpTransportParameters {
                         .collect::<Result<Vec<_>, _>>()?;
 
                     if addrs.is_empty() {
-                        return Err(HeaderParseError);
+                        return Err(HeaderParseError::new());
                     }
 
                     if name == "src_addr" {
@@ -272,17 +663,17 @@ impl TryFrom<TransportParameters> for RtpTransportParameters {
                     }
                 }
                 "port" | "server_port" | "client_port" => {
-                    let ports = value.ok_or(HeaderParseError)?;
+                    let ports = value.ok_or_else(HeaderParseError::new)?;
                     let mut ports = ports.splitn(2, '-');
 
                     let port_start = ports
                         .next()
                         .and_then(|s| s.parse::<u16>().ok())
-                        .ok_or(HeaderParseError)?;
+                        .ok_or_else(HeaderParseError::new)?;
 
                     let port_end = ports
                         .next()
-                        .map(|s| s.parse::<u16>().map_err(|_| HeaderParseError))
+                        .map(|s| s.parse::<u16>().map_err(|_| HeaderParseError::new()))
                         .transpose()?;
 
                     if name == "port" {
@@ -294,15 +685,16 @@ impl TryFrom<TransportParameters> for RtpTransportParameters {
                     }
                 }
                 "destination" => {
-                    rtp_params.destination = Some(value.ok_or(HeaderParseError)?);
+                    rtp_params.destination = Some(value.ok_or_else(HeaderParseError::new)?);
                 }
                 "source" => {
-                    rtp_params.source = Some(value.ok_or(HeaderParseError)?);
+                    rtp_params.source = Some(value.ok_or_else(HeaderParseError::new)?);
                 }
                 "append" => {
                     rtp_params.append = true;
                 }
-                "RTCP-mux" => {
+                // Some implementations use the lowercase spelling of the feature tag
+                name if name.eq_ignore_ascii_case("RTCP-mux") => {
                     rtp_params.rtcp_mux = true;
                 }
                 _ => {
@@ -315,6 +707,129 @@ impl TryFrom<TransportParameters> for RtpTransportParameters {
     }
 }
 
+impl RtpTransportParameters {
+    /// Check if these RTP transport parameters are semantically equivalent to other ones.
+    ///
+    /// Unlike `==` this considers a missing mode to be equivalent to `PLAY`, and ignores the
+    /// order of modes and SSRCs.
+    pub fn equivalent(&self, other: &RtpTransportParameters) -> bool {
+        use std::collections::BTreeSet;
+
+        fn modes(params: &RtpTransportParameters) -> BTreeSet<&TransportMode> {
+            if params.mode.is_empty() {
+                std::iter::once(&TransportMode::Play).collect()
+            } else {
+                params.mode.iter().collect()
+            }
+        }
+
+        // Destructure to make sure new fields are not forgotten here
+        let RtpTransportParameters {
+            unicast,
+            multicast,
+            interleaved,
+            ttl,
+            layers,
+            ssrc,
+            mode: _,
+            rtcp_mux,
+            dest_addr,
+            src_addr,
+            append,
+            port,
+            client_port,
+            server_port,
+            destination,
+            source,
+            others,
+        } = self;
+
+        *unicast == other.unicast
+            && *multicast == other.multicast
+            && *interleaved == other.interleaved
+            && *ttl == other.ttl
+            && *layers == other.layers
+            && ssrc.iter().collect::<BTreeSet<_>>() == other.ssrc.iter().collect::<BTreeSet<_>>()
+            && modes(self) == modes(other)
+            && *rtcp_mux == other.rtcp_mux
+            && *dest_addr == other.dest_addr
+            && *src_addr == other.src_addr
+            && *append == other.append
+            && *port == other.port
+            && *client_port == other.client_port
+            && *server_port == other.server_port
+            && *destination == other.destination
+            && *source == other.source
+            && *others == other.others
+    }
+
+    /// Check if these RTP transport parameters are consistent.
+    ///
+    /// Currently this checks that the multicast-only `ttl` and `layers` parameters are not used
+    /// together with an explicit `unicast`. Without `unicast` or `multicast` RTSP 1.0 defaults to
+    /// multicast, so they are valid then. Parsing accepts them in any case.
+    pub fn is_valid(&self) -> bool {
+        !self.unicast || (self.ttl.is_none() && self.layers.is_none())
+    }
+
+    /// Parse the destination addresses into host and port pairs.
+    ///
+    /// The host is `None` if the address only contains a port.
+    #[allow(clippy::type_complexity)]
+    pub fn parsed_dest_addr(&self) -> Result<Vec<(Option<Host>, Option<u16>)>, HeaderParseError> {
+        self.dest_addr
+            .iter()
+            .map(|addr| parse_host_port(addr))
+            .collect()
+    }
+
+    /// Parse the source addresses into host and port pairs.
+    ///
+    /// The host is `None` if the address only contains a port.
+    #[allow(clippy::type_complexity)]
+    pub fn parsed_src_addr(&self) -> Result<Vec<(Option<Host>, Option<u16>)>, HeaderParseError> {
+        self.src_addr
+            .iter()
+            .map(|addr| parse_host_port(addr))
+            .collect()
+    }
+}
+
+// Parses `host [":" port]` or `":" port`, with IPv6 hosts in brackets
+fn parse_host_port(addr: &str) -> Result<(Option<Host>, Option<u16>), HeaderParseError> {
+    let (host, port) = if addr.starts_with('[') {
+        let end = addr.find(']').ok_or_else(HeaderParseError::new)?;
+        let (host, rem) = addr.split_at(end + 1);
+        if rem.is_empty() {
+            (host, None)
+        } else {
+            (
+                host,
+                Some(rem.strip_prefix(':').ok_or_else(HeaderParseError::new)?),
+            )
+        }
+    } else if let Some((host, port)) = split_once(addr, ':') {
+        (host, Some(port))
+    } else {
+        (addr, None)
+    };
+
+    let host = if host.is_empty() {
+        None
+    } else {
+        Some(Host::parse(host).map_err(|_| HeaderParseError::new())?)
+    };
+    let port = port
+        .map(|port| port.parse::<u16>().map_err(|_| HeaderParseError::new()))
+        .transpose()?;
+
+    if host.is_none() && port.is_none() {
+        return Err(HeaderParseError::new());
+    }
+
+    Ok((host, port))
+}
+
 /// Lower RTP transport protocol.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -327,12 +842,16 @@ pub enum RtpLowerTransport {
     Other(String),
 }
 
+/// Well-known lower transports are matched case-insensitively, other lower transports are kept
+/// verbatim.
 impl<'a> From<&'a str> for RtpLowerTransport {
     fn from(lower_transport: &'a str) -> RtpLowerTransport {
-        match lower_transport {
-            "TCP" => RtpLowerTransport::Tcp,
-            "UDP" => RtpLowerTransport::Udp,
-            other => RtpLowerTransport::Other(other.into()),
+        if lower_transport.eq_ignore_ascii_case("TCP") {
+            RtpLowerTransport::Tcp
+        } else if lower_transport.eq_ignore_ascii_case("UDP") {
+            RtpLowerTransport::Udp
+        } else {
+            RtpLowerTransport::Other(lower_transport.into())
         }
     }
 }
@@ -413,10 +932,12 @@ pub struct TransportParameters(pub BTreeMap<String, Option<String>>);
 mod parser {
     use super::*;
 
-    use super::parser_helpers::{cond_parser, rtsp_unreserved, token, trim};
+    use super::parser_helpers::{cond_parser, token, trim};
     use nom::branch::alt;
-    use nom::bytes::complete::tag;
-    use nom::combinator::{all_consuming, map_res};
+    use nom::bytes::complete::{tag, take_while};
+    use nom::character::complete::space0;
+    use nom::character::is_alphanumeric;
+    use nom::combinator::{all_consuming, map, map_res};
     use nom::multi::{fold_many0, separated_list1};
     use nom::sequence::{preceded, tuple};
     use nom::{Err, IResult, Needed};
@@ -493,6 +1014,11 @@ mod parser {
         Ok((snd, fst))
     }
 
+    // rtsp_unreserved characters plus `/` for lists like `ssrc=0A13C760/9A9DE123`
+    fn unquoted_value(input: &[u8]) -> IResult<&[u8], &[u8]> {
+        take_while(|i| is_alphanumeric(i) || b"$-_.+!*'()/".contains(&i))(input)
+    }
+
     fn parameter(input: &[u8]) -> IResult<&[u8], (&str, Option<&str>)> {
         if input.is_empty() {
             return Err(Err::Error(nom::error::Error::new(
@@ -501,12 +1027,14 @@ mod parser {
             )));
         }
 
+        // Whitespace around the `=` is not allowed by the grammar but accepted as some devices
+        // send e.g. `interleaved = 0-1`
         tuple((
             trim(map_res(token, str::from_utf8)),
             cond_parser(
                 tag(b"="),
                 trim(map_res(
-                    alt((quoted_string_or_address_list, rtsp_unreserved)),
+                    alt((quoted_string_or_address_list, unquoted_value)),
                     str::from_utf8,
                 )),
             ),
@@ -532,32 +1060,50 @@ mod parser {
     fn transport(input: &[u8]) -> IResult<&[u8], Transport> {
         map_res(tuple((spec, parameters)), |(spec, params)| {
             match spec.as_slice() {
-                ["RTP", profile, lower_transport] => {
+                [""] => Err(HeaderParseError::new()),
+                // Some implementations put a trailing slash after the profile if there is no lower
+                // transport.
+                [protocol, profile] | [protocol, profile, ""]
+                    if protocol.eq_ignore_ascii_case("RTP") =>
+                {
                     let profile = RtpProfile::from(*profile);
-                    let lower_transport = Some(RtpLowerTransport::from(*lower_transport));
-                    let params = match RtpTransportParameters::try_from(params) {
-                        Ok(params) => params,
-                        Err(err) => return Err(err),
-                    };
+                    let params = RtpTransportParameters::try_from(params)?;
 
                     Ok(Transport::Rtp(RtpTransport {
                         profile,
-                        lower_transport,
+                        lower_transport: None,
+                        spec_extensions: Vec::new(),
                         params,
                     }))
                 }
-                ["RTP", profile] => {
+                // Keep additional components after a TCP/UDP lower transport for forward
+                // compatibility. Anything else is handled as a non-RTP transport.
+                [protocol, profile, lower_transport, spec_extensions @ ..]
+                    if protocol.eq_ignore_ascii_case("RTP")
+                        && (spec_extensions.is_empty()
+                            || lower_transport.eq_ignore_ascii_case("TCP")
+                            || lower_transport.eq_ignore_ascii_case("UDP")) =>
+                {
                     let profile = RtpProfile::from(*profile);
-                    let params = RtpTransportParameters::try_from(params)?;
+                    let lower_transport = Some(RtpLowerTransport::from(*lower_transport));
+                    let params = match RtpTransportParameters::try_from(params) {
+                        Ok(params) => params,
+                        Err(err) => return Err(err),
+                    };
 
                     Ok(Transport::Rtp(RtpTransport {
                         profile,
-                        lower_transport: None,
+                        lower_transport,
+                        spec_extensions: spec_extensions
+                            .iter()
+                            .copied()
+                            .map(String::from)
+                            .collect(),
                         params,
                     }))
                 }
                 other => Ok(Transport::Other(OtherTransport {
-                    spec: other.iter().copied().map(String::from).collect(),
+                    spec: other.join("/"),
                     params,
                 })),
             }
@@ -565,7 +1111,22 @@ mod parser {
     }
 
     pub(super) fn transports(input: &[u8]) -> IResult<&[u8], Vec<Transport>> {
-        all_consuming(separated_list1(tag(b","), transport))(input)
+        // Some devices send stray commas, so skip empty entries but require at least one
+        // transport overall.
+        all_consuming(map_res(
+            separated_list1(
+                tag(b","),
+                alt((map(transport, Some), map(space0, |_| None))),
+            ),
+            |transports| {
+                let transports = transports.into_iter().flatten().collect::<Vec<_>>();
+                if transports.is_empty() {
+                    Err(HeaderParseError::new())
+                } else {
+                    Ok(transports)
+                }
+            },
+        ))(input)
     }
 }
 
@@ -578,16 +1139,28 @@ impl super::TypedHeader for Transports {
             Some(header) => header,
         };
 
-        let (_rem, transport) =
-            parser::transports(header.as_str().as_bytes()).map_err(|_| HeaderParseError)?;
+        let (_rem, transport) = parser::transports(header.as_str().as_bytes())
+            .map_err(|_| HeaderParseError::for_header(TRANSPORT))?;
 
         Ok(Some(transport.into()))
     }
 
     fn insert_into(&self, mut headers: impl AsMut<Headers>) {
-        use std::fmt::Write;
+        let headers = headers.as_mut();
+        headers.insert(TRANSPORT, self.header_value());
+    }
+}
 
+impl super::TypedAppendableHeader for Transports {
+    fn append_to(&self, mut headers: impl AsMut<Headers>) {
         let headers = headers.as_mut();
+        headers.append(TRANSPORT, self.header_value());
+    }
+}
+
+impl Transports {
+    fn header_value(&self) -> String {
+        use std::fmt::Write;
 
         let mut transports = String::new();
         for transport in &self.0 {
@@ -602,6 +1175,11 @@ impl super::TypedHeader for Transports {
                     if let Some(lower_transport) = &rtp.lower_transport {
                         transports.push('/');
                         transports.push_str(lower_transport.as_str());
+
+                        for extension in &rtp.spec_extensions {
+                            transports.push('/');
+                            transports.push_str(extension);
+                        }
                     }
 
                     if rtp.params.unicast {
@@ -627,20 +1205,9 @@ impl super::TypedHeader for Transports {
                         write!(&mut transports, "ttl={ttl}").unwrap();
                     }
 
-                    if !rtp.params.ssrc.is_empty() {
+                    if let Some(layers) = rtp.params.layers {
                         transports.push(';');
-
-                        transports.push_str("ssrc=");
-                        let mut first = true;
-                        for ssrc in &rtp.params.ssrc {
-                            if first {
-                                first = false;
-                            } else {
-                                transports.push('/');
-                            }
-
-                            write!(&mut transports, "{ssrc:08X}").unwrap();
-                        }
+                        write!(&mut transports, "layers={layers}").unwrap();
                     }
 
                     if !rtp.params.dest_addr.is_empty() {
@@ -714,6 +1281,22 @@ impl super::TypedHeader for Transports {
                         write!(&mut transports, "source={source}").unwrap();
                     }
 
+                    if !rtp.params.ssrc.is_empty() {
+                        transports.push(';');
+
+                        transports.push_str("ssrc=");
+                        let mut first = true;
+                        for ssrc in &rtp.params.ssrc {
+                            if first {
+                                first = false;
+                            } else {
+                                transports.push('/');
+                            }
+
+                            write!(&mut transports, "{ssrc:08X}").unwrap();
+                        }
+                    }
+
                     if !rtp.params.mode.is_empty() {
                         transports.push(';');
 
@@ -763,7 +1346,7 @@ impl super::TypedHeader for Transports {
             }
         }
 
-        headers.insert(TRANSPORT, transports);
+        transports
     }
 }
 
@@ -788,6 +1371,7 @@ mod tests {
             vec![Transport::Rtp(RtpTransport {
                 profile: super::RtpProfile::Avp,
                 lower_transport: None,
+                spec_extensions: Vec::new(),
                 params: RtpTransportParameters {
                     unicast: true,
                     multicast: false,
@@ -807,6 +1391,266 @@ mod tests {
         assert_eq!(request, request2);
     }
 
+    #[test]
+    fn test_transport_append() {
+        let parse = |header: &str| {
+            crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+                .header(crate::headers::TRANSPORT, header)
+                .empty()
+                .typed_header::<super::Transports>()
+                .unwrap()
+                .unwrap()
+        };
+
+        let tcp = parse("RTP/AVP/TCP;unicast;interleaved=0-1");
+        let udp = parse("RTP/AVP;unicast;client_port=42860-42861");
+
+        let mut request = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+            .typed_header(&tcp)
+            .empty();
+        request.append_typed_header(&udp);
+
+        assert_eq!(
+            request.header(&crate::headers::TRANSPORT).unwrap().as_str(),
+            "RTP/AVP/TCP;unicast;interleaved=0-1, RTP/AVP;unicast;client_port=42860-42861"
+        );
+
+        let transports = request
+            .typed_header::<super::Transports>()
+            .unwrap()
+            .unwrap();
+        assert_eq!(transports[0], tcp[0]);
+        assert_eq!(transports[1], udp[0]);
+    }
+
+    #[test]
+    fn test_transport_parse_error() {
+        let request = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+            .header(crate::headers::CSEQ, "1")
+            .header(crate::headers::TRANSPORT, "RTP/AVP;unicast;client_port=foo")
+            .empty();
+
+        assert_eq!(
+            request.typed_header::<crate::headers::CSeq>(),
+            Ok(Some(1.into()))
+        );
+
+        let err = request.typed_header::<super::Transports>().unwrap_err();
+        assert_eq!(err.header_name(), Some(&crate::headers::TRANSPORT));
+        assert_eq!(err.to_string(), "Error parsing header 'Transport'");
+    }
+
+    #[test]
+    fn test_transport_trailing_slash() {
+        let request = crate::Request::builder(crate::Method::Setup, crate::Version::V1_0)
+            .header(
+                crate::headers::TRANSPORT,
+                "RTP/AVP/;unicast;client_port=42860-42861",
+            )
+            .empty();
+
+        let transports = request
+            .typed_header::<super::Transports>()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            transports,
+            vec![Transport::Rtp(RtpTransport {
+                profile: super::RtpProfile::Avp,
+                lower_transport: None,
+                spec_extensions: Vec::new(),
+                params: RtpTransportParameters {
+                    unicast: true,
+                    client_port: Some((42860, Some(42861))),
+                    ..Default::default()
+                },
+            })]
+            .into()
+        );
+    }
+
+    #[test]
+    fn test_transport_canonical_order() {
+        let headers = [
+            "RTP/AVP/TCP;unicast;interleaved=0-1",
+            "RTP/AVP/TCP;unicast;interleaved=0-1;ssrc=0A13C760;mode=\"PLAY\"",
+            "RTP/AVP;unicast;client_port=8000-8001",
+            "RTP/AVP;unicast;client_port=8000-8001;server_port=9000-9001;ssrc=0A13C760;mode=\"PLAY\"",
+            "RTP/AVP;multicast;ttl=127;port=3456-3457;destination=224.2.0.1;ssrc=0A13C760;mode=\"PLAY\"",
+        ];
+
+        for header in &headers {
+            let request = crate::Request::builder(crate::Method::Setup, crate::Version::V1_0)
+                .header(crate::headers::TRANSPORT, *header)
+                .empty();
+
+            let transports = request
+                .typed_header::<super::Transports>()
+                .unwrap()
+                .unwrap();
+
+            let request2 = crate::Request::builder(crate::Method::Setup, crate::Version::V1_0)
+                .typed_header(&transports)
+                .empty();
+
+            assert_eq!(
+                request2
+                    .header(&crate::headers::TRANSPORT)
+                    .unwrap()
+                    .as_str(),
+                *header
+            );
+        }
+    }
+
+    #[test]
+    fn test_transport_case_insensitive() {
+        let request = crate::Request::builder(crate::Method::Setup, crate::Version::V1_0)
+            .header(
+                crate::headers::TRANSPORT,
+                "rtp/avp/udp;unicast;client_port=8000-8001,Rtp/SAvpF/Tcp;interleaved=0-1,RTP/foo/bar",
+            )
+            .empty();
+
+        let transports = request
+            .typed_header::<super::Transports>()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            transports,
+            vec![
+                Transport::Rtp(RtpTransport {
+                    profile: super::RtpProfile::Avp,
+                    lower_transport: Some(super::RtpLowerTransport::Udp),
+                    spec_extensions: Vec::new(),
+                    params: RtpTransportParameters {
+                        unicast: true,
+                        client_port: Some((8000, Some(8001))),
+                        ..Default::default()
+                    },
+                }),
+                Transport::Rtp(RtpTransport {
+                    profile: super::RtpProfile::SAvpF,
+                    lower_transport: Some(super::RtpLowerTransport::Tcp),
+                    spec_extensions: Vec::new(),
+                    params: RtpTransportParameters {
+                        interleaved: Some((0, Some(1))),
+                        ..Default::default()
+                    },
+                }),
+                Transport::Rtp(RtpTransport {
+                    profile: super::RtpProfile::Other(String::from("foo")),
+                    lower_transport: Some(super::RtpLowerTransport::Other(String::from("bar"))),
+                    spec_extensions: Vec::new(),
+                    params: RtpTransportParameters::default(),
+                }),
+            ]
+            .into()
+        );
+
+        let request2 = crate::Request::builder(crate::Method::Setup, crate::Version::V1_0)
+            .typed_header(&transports)
+            .empty();
+        assert_eq!(
+            request2
+                .header(&crate::headers::TRANSPORT)
+                .unwrap()
+                .as_str(),
+            "RTP/AVP/UDP;unicast;client_port=8000-8001,RTP/SAVPF/TCP;interleaved=0-1,RTP/foo/bar"
+        );
+    }
+
+    #[test]
+    fn test_transport_assigned_ports() {
+        fn overlap<T: PartialOrd>(a: &RangeInclusive<T>, b: &RangeInclusive<T>) -> bool {
+            a.start() <= b.end() && b.start() <= a.end()
+        }
+
+        let parse = |header: &str| {
+            crate::Request::builder(crate::Method::Setup, crate::Version::V1_0)
+                .header(crate::headers::TRANSPORT, header)
+                .empty()
+                .typed_header::<super::Transports>()
+                .unwrap()
+                .unwrap()
+        };
+
+        let transports = parse(
+            "RTP/AVP;unicast;client_port=8000-8001;server_port=9000-9001,RTP/AVP;unicast;client_port=8001-8002,RTP/AVP;multicast;port=5000",
+        );
+        let ports = transports.assigned_ports();
+        assert_eq!(
+            ports,
+            vec![
+                (super::PortKind::ClientPort, 8000..=8001),
+                (super::PortKind::ServerPort, 9000..=9001),
+                (super::PortKind::ClientPort, 8001..=8002),
+                (super::PortKind::Port, 5000..=5000),
+            ]
+        );
+        assert!(overlap(&ports[0].1, &ports[2].1));
+        assert!(!overlap(&ports[0].1, &ports[1].1));
+        assert!(transports.assigned_channels().is_empty());
+
+        let transports =
+            parse("RTP/AVP/TCP;unicast;interleaved=0-1,RTP/AVP/TCP;unicast;interleaved=2");
+        let channels = transports.assigned_channels();
+        assert_eq!(channels, vec![0..=1, 2..=2]);
+        assert!(!overlap(&channels[0], &channels[1]));
+        assert!(transports.assigned_ports().is_empty());
+    }
+
+    #[test]
+    fn test_transport_round_trip_matrix() {
+        let headers = [
+            "RTP/AVP/TCP;unicast;interleaved=0-1;ssrc=0A13C760/9A9DE123;mode=\"PLAY\"",
+            "RTP/AVP;unicast;client_port=8000-8001;server_port=9000-9001;ssrc=0A13C760/9A9DE123",
+            "RTP/AVP;multicast;ttl=127;dest_addr=\"224.2.0.1:3456\"/\"224.2.0.1:3457\";mode=\"PLAY\"",
+            "RTP/AVP/UDP;unicast;dest_addr=\":4588\"/\":4589\";src_addr=\"192.0.2.224:6256\"/\"192.0.2.224:6257\";ssrc=2A3F93ED",
+            "RTP/AVP;unicast;dest_addr=\"192.0.2.5:3456\"/\"192.0.2.5:3457\";ssrc=0A13C760;mode=\"PLAY, RECORD\";RTCP-mux",
+        ];
+
+        for header in &headers {
+            let request = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+                .header(crate::headers::TRANSPORT, *header)
+                .empty();
+
+            let transports = request
+                .typed_header::<super::Transports>()
+                .unwrap()
+                .unwrap();
+
+            let request2 = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+                .typed_header(&transports)
+                .empty();
+
+            assert_eq!(
+                request2
+                    .header(&crate::headers::TRANSPORT)
+                    .unwrap()
+                    .as_str(),
+                *header
+            );
+        }
+
+        let request = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+            .header(
+                crate::headers::TRANSPORT,
+                "RTP/AVP/TCP;interleaved=0-1;ssrc=0A13C760/9A9DE123",
+            )
+            .empty();
+        match &request
+            .typed_header::<super::Transports>()
+            .unwrap()
+            .unwrap()[0]
+        {
+            Transport::Rtp(rtp) => assert_eq!(rtp.params.ssrc, [0x0A13C760, 0x9A9DE123]),
+            _ => unreachable!(),
+        }
+    }
+
     #[test]
     fn test_transport_multicast() {
         let header = "RTP/AVP;multicast";
@@ -824,6 +1668,7 @@ mod tests {
             vec![Transport::Rtp(RtpTransport {
                 profile: super::RtpProfile::Avp,
                 lower_transport: None,
+                spec_extensions: Vec::new(),
                 params: RtpTransportParameters {
                     unicast: false,
                     multicast: true,
@@ -840,6 +1685,44 @@ mod tests {
         assert_eq!(request, request2);
     }
 
+    #[test]
+    fn test_transport_multicast_only_params() {
+        let parse = |header: &str| {
+            let request = crate::Request::builder(crate::Method::Setup, crate::Version::V1_0)
+                .header(crate::headers::TRANSPORT, header)
+                .empty();
+
+            match request
+                .typed_header::<super::Transports>()
+                .unwrap()
+                .unwrap()
+                .remove(0)
+            {
+                Transport::Rtp(rtp) => rtp,
+                _ => unreachable!(),
+            }
+        };
+
+        let rtp = parse("RTP/AVP;multicast;ttl=16;layers=2");
+        assert_eq!(rtp.params.ttl, Some(16));
+        assert_eq!(rtp.params.layers, Some(2));
+        assert!(rtp.params.is_valid());
+
+        let request = crate::Request::builder(crate::Method::Setup, crate::Version::V1_0)
+            .typed_header(&super::Transports::from(vec![Transport::Rtp(rtp)]))
+            .empty();
+        assert_eq!(
+            request.header(&crate::headers::TRANSPORT).unwrap().as_str(),
+            "RTP/AVP;multicast;ttl=16;layers=2"
+        );
+
+        assert!(!parse("RTP/AVP;unicast;ttl=5").params.is_valid());
+        assert!(!parse("RTP/AVP;unicast;layers=2").params.is_valid());
+        assert!(parse("RTP/AVP;ttl=5").params.is_valid());
+        assert!(parse("RTP/AVP;layers=2").params.is_valid());
+        assert!(parse("RTP/AVP;unicast").params.is_valid());
+    }
+
     #[test]
     fn test_transport_v1() {
         let header = "RTP/AVP;unicast;client_port=42860-42861";
@@ -857,6 +1740,7 @@ mod tests {
             vec![Transport::Rtp(RtpTransport {
                 profile: super::RtpProfile::Avp,
                 lower_transport: None,
+                spec_extensions: Vec::new(),
                 params: RtpTransportParameters {
                     unicast: true,
                     multicast: false,
@@ -892,6 +1776,7 @@ mod tests {
                 Transport::Rtp(RtpTransport {
                     profile: super::RtpProfile::Avp,
                     lower_transport: None,
+                    spec_extensions: Vec::new(),
                     params: RtpTransportParameters {
                         multicast: true,
                         unicast: false,
@@ -902,6 +1787,7 @@ mod tests {
                 Transport::Rtp(RtpTransport {
                     profile: super::RtpProfile::Avp,
                     lower_transport: None,
+                    spec_extensions: Vec::new(),
                     params: RtpTransportParameters {
                         unicast: true,
                         multicast: false,
@@ -921,6 +1807,36 @@ mod tests {
         assert_eq!(request, request2);
     }
 
+    #[test]
+    fn test_transports_from_iter() {
+        let request = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+            .header(
+                TRANSPORT,
+                "RTP/AVP/TCP;unicast;interleaved=0-1, RTP/AVP;multicast;ttl=5, \
+                 RTP/AVP;unicast;client_port=8000-8001",
+            )
+            .empty();
+        let transports = request.typed_header::<Transports>().unwrap().unwrap();
+
+        let unicast = transports
+            .iter()
+            .filter(|transport| match transport {
+                Transport::Rtp(rtp) => rtp.params.unicast,
+                _ => false,
+            })
+            .cloned()
+            .collect::<Transports>();
+        assert_eq!(unicast.len(), 2);
+
+        let request = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+            .typed_header(&unicast)
+            .empty();
+        assert_eq!(
+            request.header(&TRANSPORT).unwrap().as_str(),
+            "RTP/AVP/TCP;unicast;interleaved=0-1,RTP/AVP;unicast;client_port=8000-8001"
+        );
+    }
+
     #[test]
     fn test_transport_mode_no_quotes() {
         let header = "RTP/AVP;multicast;mode=PLAY,RTP/AVP;unicast;dest_addr=\"192.0.2.5:3456\"/\"192.0.2.5:3457\";mode=\"play\"";
@@ -939,6 +1855,7 @@ mod tests {
                 Transport::Rtp(RtpTransport {
                     profile: super::RtpProfile::Avp,
                     lower_transport: None,
+                    spec_extensions: Vec::new(),
                     params: RtpTransportParameters {
                         multicast: true,
                         unicast: false,
@@ -949,6 +1866,7 @@ mod tests {
                 Transport::Rtp(RtpTransport {
                     profile: super::RtpProfile::Avp,
                     lower_transport: None,
+                    spec_extensions: Vec::new(),
                     params: RtpTransportParameters {
                         unicast: true,
                         multicast: false,
@@ -961,4 +1879,495 @@ mod tests {
             .into()
         );
     }
+
+    #[test]
+    fn test_transport_parsed_addr() {
+        let header = "RTP/AVP;unicast;dest_addr=\"192.0.2.5:3456\"/\"192.0.2.5\";src_addr=\":6256\"/\"example.com:6257\"";
+        let request = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+            .header(crate::headers::TRANSPORT, header)
+            .empty();
+
+        let transports = request
+            .typed_header::<super::Transports>()
+            .unwrap()
+            .unwrap();
+
+        let params = match &transports[0] {
+            Transport::Rtp(rtp) => &rtp.params,
+            _ => unreachable!(),
+        };
+
+        assert_eq!(
+            params.parsed_dest_addr().unwrap(),
+            vec![
+                (Some(Host::Ipv4([192, 0, 2, 5].into())), Some(3456)),
+                (Some(Host::Ipv4([192, 0, 2, 5].into())), None),
+            ]
+        );
+        assert_eq!(
+            params.parsed_src_addr().unwrap(),
+            vec![
+                (None, Some(6256)),
+                (Some(Host::Domain("example.com".into())), Some(6257)),
+            ]
+        );
+
+        let request2 = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+            .typed_header(&transports)
+            .empty();
+
+        assert_eq!(request, request2);
+    }
+
+    #[test]
+    fn test_transport_parsed_addr_ipv6() {
+        let header = "RTP/AVP;unicast;dest_addr=\"[2001:db8::5]:3456\"/\"[2001:db8::5]\"";
+        let request = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+            .header(crate::headers::TRANSPORT, header)
+            .empty();
+
+        let transports = request
+            .typed_header::<super::Transports>()
+            .unwrap()
+            .unwrap();
+
+        let params = match &transports[0] {
+            Transport::Rtp(rtp) => &rtp.params,
+            _ => unreachable!(),
+        };
+
+        let addr = "2001:db8::5".parse::<std::net::Ipv6Addr>().unwrap();
+        assert_eq!(
+            params.parsed_dest_addr().unwrap(),
+            vec![
+                (Some(Host::Ipv6(addr)), Some(3456)),
+                (Some(Host::Ipv6(addr)), None)
+            ]
+        );
+
+        let params = RtpTransportParameters {
+            dest_addr: vec!["[2001:db8::5".into()],
+            ..Default::default()
+        };
+        assert!(params.parsed_dest_addr().is_err());
+
+        let params = RtpTransportParameters {
+            dest_addr: vec!["[2001:db8::5]3456".into()],
+            ..Default::default()
+        };
+        assert!(params.parsed_dest_addr().is_err());
+    }
+
+    #[test]
+    fn test_transport_equivalent() {
+        let parse = |header: &str| {
+            let request = crate::Request::builder(crate::Method::Setup, crate::Version::V1_0)
+                .header(crate::headers::TRANSPORT, header)
+                .empty();
+
+            request
+                .typed_header::<super::Transports>()
+                .unwrap()
+                .unwrap()
+                .remove(0)
+        };
+
+        let equivalent = [
+            (
+                "RTP/AVP;unicast;client_port=42860-42861",
+                "RTP/AVP/UDP;unicast;client_port=42860-42861;mode=PLAY",
+            ),
+            (
+                "RTP/AVP;multicast;mode=\"PLAY,RECORD\";ssrc=0A13C760",
+                "RTP/AVP;mode=\"RECORD,PLAY\";multicast;ssrc=0A13C760",
+            ),
+            (
+                "RTP/AVP/TCP;unicast;interleaved=0-1;mode=\"PLAY\";foo=bar",
+                "RTP/AVP/TCP;foo=bar;interleaved=0-1;unicast",
+            ),
+        ];
+
+        for (a, b) in equivalent {
+            let a_transport = parse(a);
+            let b_transport = parse(b);
+            assert_ne!(a_transport, b_transport, "{} / {}", a, b);
+            assert!(a_transport.equivalent(&b_transport), "{} / {}", a, b);
+            assert!(b_transport.equivalent(&a_transport), "{} / {}", b, a);
+        }
+
+        let not_equivalent = [
+            ("RTP/AVP;unicast", "RTP/AVP;multicast"),
+            ("RTP/AVP;unicast", "RTP/AVP/TCP;unicast"),
+            ("RTP/AVP;unicast", "RTP/SAVP;unicast"),
+            ("RTP/AVP;unicast", "RTP/AVP;unicast;mode=RECORD"),
+            ("RTP/AVP;unicast;foo", "RTP/AVP;unicast;bar"),
+            ("RTP/AVP;unicast", "FOO/BAR;unicast"),
+        ];
+
+        for (a, b) in not_equivalent {
+            let a_transport = parse(a);
+            let b_transport = parse(b);
+            assert!(!a_transport.equivalent(&b_transport), "{} / {}", a, b);
+            assert!(!b_transport.equivalent(&a_transport), "{} / {}", b, a);
+        }
+    }
+
+    #[test]
+    fn test_transport_malformed_quoted_values() {
+        let headers = [
+            "RTP/AVP;unicast;dest_addr=\"",
+            "RTP/AVP;unicast;dest_addr=\":4588\"/\"",
+            "RTP/AVP;unicast;dest_addr=\"/\":4589\"",
+            "RTP/AVP;unicast;src_addr=\"192.0.2.53:4588\"/\"",
+            "RTP/AVP;unicast;mode=\"",
+        ];
+
+        for header in headers {
+            let request = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+                .header(TRANSPORT, header)
+                .empty();
+
+            assert!(request.typed_header::<Transports>().is_err(), "{}", header);
+        }
+    }
+
+    #[test]
+    fn test_transport_rtcp_mux_append() {
+        for header in [
+            "RTP/AVP/TCP;unicast;interleaved=0-1;RTCP-mux",
+            "RTP/AVP/TCP;unicast;interleaved=0-1;rtcp-mux",
+            "RTP/AVP/TCP;unicast;interleaved=0-1;Rtcp-Mux",
+        ] {
+            let request = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+                .header(TRANSPORT, header)
+                .empty();
+            let transports = request.typed_header::<Transports>().unwrap().unwrap();
+            match &transports[0] {
+                Transport::Rtp(rtp) => {
+                    assert!(rtp.params.rtcp_mux, "{}", header);
+                    assert!(rtp.params.others.is_empty(), "{}", header);
+                }
+                transport => panic!("unexpected transport {:?}", transport),
+            }
+        }
+
+        let transport = RtpTransport::builder(RtpProfile::Avp)
+            .lower_transport(RtpLowerTransport::Tcp)
+            .unicast()
+            .interleaved(0, Some(1))
+            .mode(TransportMode::Record)
+            .append()
+            .rtcp_mux()
+            .build();
+        assert_eq!(
+            transport,
+            RtpTransport {
+                profile: RtpProfile::Avp,
+                lower_transport: Some(RtpLowerTransport::Tcp),
+                spec_extensions: Vec::new(),
+                params: RtpTransportParameters {
+                    unicast: true,
+                    interleaved: Some((0, Some(1))),
+                    mode: vec![TransportMode::Record],
+                    append: true,
+                    rtcp_mux: true,
+                    ..Default::default()
+                },
+            }
+        );
+
+        let transports = Transports::from(vec![Transport::Rtp(transport)]);
+        let request = crate::Request::builder(crate::Method::Setup, crate::Version::V1_0)
+            .typed_header(&transports)
+            .empty();
+        assert_eq!(
+            request.header(&TRANSPORT).unwrap().as_str(),
+            "RTP/AVP/TCP;unicast;interleaved=0-1;append;mode=\"RECORD\";RTCP-mux"
+        );
+        assert_eq!(
+            request.typed_header::<Transports>().unwrap().unwrap(),
+            transports
+        );
+    }
+
+    #[test]
+    fn test_transport_spec_extensions() {
+        let header = "RTP/AVP/TCP/EXT;unicast;interleaved=0-1";
+        let request = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+            .header(TRANSPORT, header)
+            .empty();
+
+        let transports = request.typed_header::<Transports>().unwrap().unwrap();
+        assert_eq!(
+            transports,
+            Transports::from(vec![Transport::Rtp(RtpTransport {
+                profile: RtpProfile::Avp,
+                lower_transport: Some(RtpLowerTransport::Tcp),
+                spec_extensions: vec![String::from("EXT")],
+                params: RtpTransportParameters {
+                    unicast: true,
+                    interleaved: Some((0, Some(1))),
+                    ..Default::default()
+                },
+            })])
+        );
+
+        match &transports[0] {
+            Transport::Rtp(rtp) => assert_eq!(rtp.spec_extensions, [String::from("EXT")]),
+            transport => panic!("unexpected transport {:?}", transport),
+        }
+        assert_eq!(
+            transports[0],
+            Transport::Rtp(
+                RtpTransport::builder(RtpProfile::Avp)
+                    .lower_transport(RtpLowerTransport::Tcp)
+                    .spec_extension("EXT")
+                    .unicast()
+                    .interleaved(0, Some(1))
+                    .build()
+            )
+        );
+
+        let request2 = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+            .typed_header(&transports)
+            .empty();
+        assert_eq!(request2.header(&TRANSPORT).unwrap().as_str(), header);
+
+        // Only known lower transports can be followed by additional components
+        let request = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+            .header(TRANSPORT, "RTP/AVP/FOO/EXT;unicast")
+            .empty();
+        let transports = request.typed_header::<Transports>().unwrap().unwrap();
+        match &transports[0] {
+            Transport::Other(other) => assert_eq!(other.spec, "RTP/AVP/FOO/EXT"),
+            transport => panic!("unexpected transport {:?}", transport),
+        }
+
+        let request2 = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+            .typed_header(&transports)
+            .empty();
+        assert_eq!(
+            request2.header(&TRANSPORT).unwrap().as_str(),
+            "RTP/AVP/FOO/EXT;unicast"
+        );
+    }
+
+    #[test]
+    fn test_transport_cast_with_value() {
+        for (header, normalized) in [
+            (
+                "RTP/AVP/TCP;unicast=1;interleaved=0-1",
+                "RTP/AVP/TCP;unicast;interleaved=0-1",
+            ),
+            (
+                "RTP/AVP;cast=unicast;client_port=8000-8001",
+                "RTP/AVP;unicast;client_port=8000-8001",
+            ),
+            (
+                "RTP/AVP;multicast=yes;ttl=5;destination=224.2.0.1",
+                "RTP/AVP;multicast;ttl=5;destination=224.2.0.1",
+            ),
+            ("RTP/AVP;cast=multicast;ttl=5", "RTP/AVP;multicast;ttl=5"),
+        ] {
+            let request = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+                .header(TRANSPORT, header)
+                .empty();
+            let transports = request.typed_header::<Transports>().unwrap().unwrap();
+
+            let request2 = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+                .header(TRANSPORT, normalized)
+                .empty();
+            assert_eq!(
+                transports,
+                request2.typed_header::<Transports>().unwrap().unwrap(),
+                "{}",
+                header
+            );
+        }
+
+        // Unknown `cast` values are kept as other parameters
+        let request = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+            .header(TRANSPORT, "RTP/AVP;cast=broadcast")
+            .empty();
+        let transports = request.typed_header::<Transports>().unwrap().unwrap();
+        match transports[0] {
+            Transport::Rtp(ref rtp) => {
+                assert!(!rtp.params.unicast && !rtp.params.multicast);
+                assert_eq!(
+                    rtp.params.others.get("cast"),
+                    Some(&Some(String::from("broadcast")))
+                );
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_transport_parameter_spaces() {
+        for (header, normalized) in [
+            (
+                "RTP/AVP/TCP;unicast;interleaved = 0-1",
+                "RTP/AVP/TCP;unicast;interleaved=0-1",
+            ),
+            (
+                "RTP/AVP;multicast; ttl = 5 ;destination =224.2.0.1; mode= \"PLAY\"",
+                "RTP/AVP;multicast;ttl=5;destination=224.2.0.1;mode=\"PLAY\"",
+            ),
+            (
+                "RTP/AVP;unicast;client_port\t=\t8000-8001",
+                "RTP/AVP;unicast;client_port=8000-8001",
+            ),
+        ] {
+            let request = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+                .header(TRANSPORT, header)
+                .empty();
+            let transports = request.typed_header::<Transports>().unwrap().unwrap();
+
+            let request2 = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+                .header(TRANSPORT, normalized)
+                .empty();
+            assert_eq!(
+                transports,
+                request2.typed_header::<Transports>().unwrap().unwrap(),
+                "{}",
+                header
+            );
+
+            let request3 = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+                .typed_header(&transports)
+                .empty();
+            assert_eq!(request3.header(&TRANSPORT).unwrap().as_str(), normalized);
+        }
+
+        let request = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+            .header(TRANSPORT, "RTP/AVP;multicast;ttl = 5;interleaved = 0-1")
+            .empty();
+        let transports = request.typed_header::<Transports>().unwrap().unwrap();
+        match &transports[0] {
+            Transport::Rtp(rtp) => {
+                assert_eq!(rtp.params.ttl, Some(5));
+                assert_eq!(rtp.params.interleaved, Some((0, Some(1))));
+            }
+            transport => panic!("unexpected transport {:?}", transport),
+        }
+    }
+
+    #[test]
+    fn test_transport_empty_entries() {
+        let expected = Transports::from(vec![
+            Transport::Rtp(RtpTransport {
+                profile: RtpProfile::Avp,
+                lower_transport: None,
+                spec_extensions: Vec::new(),
+                params: RtpTransportParameters::default(),
+            }),
+            Transport::Rtp(RtpTransport {
+                profile: RtpProfile::Avp,
+                lower_transport: Some(RtpLowerTransport::Tcp),
+                spec_extensions: Vec::new(),
+                params: RtpTransportParameters::default(),
+            }),
+        ]);
+
+        for (header, expected) in [
+            ("RTP/AVP,", &expected[..1]),
+            (",RTP/AVP", &expected[..1]),
+            ("RTP/AVP,,RTP/AVP/TCP", &expected[..]),
+            (" , RTP/AVP , , RTP/AVP/TCP , ", &expected[..]),
+        ] {
+            let request = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+                .header(crate::headers::TRANSPORT, header)
+                .empty();
+
+            let transports = request.typed_header::<Transports>().unwrap().unwrap();
+            assert_eq!(&transports[..], expected, "{}", header);
+        }
+
+        for header in ["", ",", " , , "] {
+            let request = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+                .header(crate::headers::TRANSPORT, header)
+                .empty();
+
+            assert!(request.typed_header::<Transports>().is_err(), "{}", header);
+        }
+    }
+
+    #[test]
+    fn test_transport_setup() {
+        let request = crate::Request::builder(crate::Method::Setup, crate::Version::V1_0)
+            .header(
+                crate::headers::TRANSPORT,
+                "RTP/AVP;unicast;client_port=8000-8001;destination=192.0.2.1, \
+                 RTP/AVP/TCP;unicast;interleaved=2-3, \
+                 RTP/AVP;multicast;port=5000;ttl=16, \
+                 RTP/AVP/SCTP;unicast, \
+                 RAW/RAW/UDP;unicast",
+            )
+            .empty();
+        let transports = request.typed_header::<Transports>().unwrap().unwrap();
+
+        assert_eq!(
+            transports.setups(),
+            vec![
+                Some(TransportSetup {
+                    multicast: false,
+                    delivery: TransportDelivery::Udp {
+                        rtp_port: Some(8000),
+                        rtcp_port: Some(8001),
+                    },
+                    ttl: None,
+                    destinations: vec![String::from("192.0.2.1")],
+                    rtcp_mux: false,
+                }),
+                Some(TransportSetup {
+                    multicast: false,
+                    delivery: TransportDelivery::Interleaved {
+                        rtp_channel: 2,
+                        rtcp_channel: Some(3),
+                    },
+                    ttl: None,
+                    destinations: Vec::new(),
+                    rtcp_mux: false,
+                }),
+                Some(TransportSetup {
+                    multicast: true,
+                    delivery: TransportDelivery::Udp {
+                        rtp_port: Some(5000),
+                        rtcp_port: None,
+                    },
+                    ttl: Some(16),
+                    destinations: Vec::new(),
+                    rtcp_mux: false,
+                }),
+                None,
+                None,
+            ]
+        );
+
+        let request = crate::Request::builder(crate::Method::Setup, crate::Version::V2_0)
+            .header(
+                crate::headers::TRANSPORT,
+                "RTP/AVP/TCP;unicast;RTCP-mux, RTP/AVP/UDP;unicast;dest_addr=\":8000\"/\":8001\"",
+            )
+            .empty();
+        let transports = request.typed_header::<Transports>().unwrap().unwrap();
+        let setups = transports
+            .setups()
+            .into_iter()
+            .map(Option::unwrap)
+            .collect::<Vec<_>>();
+        assert_eq!(setups[0].delivery, TransportDelivery::Tcp);
+        assert!(setups[0].rtcp_mux);
+        assert_eq!(
+            setups[1].delivery,
+            TransportDelivery::Udp {
+                rtp_port: Some(8000),
+                rtcp_port: Some(8001),
+            }
+        );
+        assert_eq!(
+            setups[1].destinations,
+            vec![String::from(":8000"), String::from(":8001")]
+        );
+    }
 }
@@ -15,28 +15,45 @@ use crate::message_ref::HeaderRef;
 /// [`Request`](../struct.Request.html) and [`Response`](../struct.Response.html) implement
 /// `AsRef<Headers>` and `AsMut<Headers>, which allows functions working with headers to be
 /// implemented generically over those traits.
-#[derive(Debug, Clone, PartialEq, Eq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Headers(pub(crate) BTreeMap<HeaderName, HeaderValue>);
+///
+/// If enabled via [`ParseOptions::preserve_raw_headers`](../struct.ParseOptions.html#method.preserve_raw_headers)
+/// the headers of parsed messages also keep the raw header values as received, see
+/// [`raw_value`](#method.raw_value). These are not considered for comparisons.
+#[derive(Debug, Clone)]
+pub struct Headers(
+    pub(crate) BTreeMap<HeaderName, HeaderValue>,
+    pub(crate) BTreeMap<HeaderName, Vec<Vec<u8>>>,
+);
 
 impl Headers {
     pub(crate) fn new() -> Headers {
-        Headers(BTreeMap::new())
+        Headers(BTreeMap::new(), BTreeMap::new())
     }
 
-    pub(crate) fn from_headers_ref<'a, V: AsRef<[HeaderRef<'a>]>>(headers: V) -> Headers {
+    pub(crate) fn from_headers_ref<'a, V: AsRef<[HeaderRef<'a>]>>(
+        headers: V,
+        preserve_raw: bool,
+    ) -> Headers {
         let headers = headers.as_ref();
         let mut owned_headers = Headers::new();
 
         for header in headers.iter() {
             // Header values can be split over multiple lines, in which case there
-            // will be a CRLF followed by one or more spaces/tabs. Here we replace
-            // the CRLF and spaces/tabs with a single space.
+            // will be a CRLF, or a bare LF if accepted by the parser, followed by one or more
+            // spaces/tabs. Here we replace the line ending and spaces/tabs with a single space.
             let mut value = Vec::with_capacity(header.value.len());
             let mut raw_value = header.value.as_bytes();
             while !raw_value.is_empty() {
-                if raw_value.starts_with(b"\r\n") {
-                    raw_value = raw_value.split_at(2).1;
+                let line_end_len = if raw_value.starts_with(b"\r\n") {
+                    2
+                } else if raw_value.starts_with(b"\n") {
+                    1
+                } else {
+                    0
+                };
+
+                if line_end_len > 0 {
+                    raw_value = raw_value.split_at(line_end_len).1;
                     if let Some((non_space_pos, _)) = raw_value
                         .iter()
                         .enumerate()
@@ -57,7 +74,14 @@ impl Headers {
             let name = HeaderName::try_from(header.name).expect("Non-ASCII characters");
             let value = String::from_utf8(value).expect("Non-UTF8 characters");
 
-            owned_headers.append(name, HeaderValue::from(value));
+            if preserve_raw {
+                owned_headers
+                    .1
+                    .entry(name.clone())
+                    .or_default()
+                    .push(Vec::from(header.value.as_bytes()));
+            }
+            owned_headers.append_value(name, HeaderValue::from(value));
         }
 
         owned_headers
@@ -70,21 +94,108 @@ impl Headers {
     /// See [`append`](#method.append) for appending additional values to a header.
     pub fn insert<V: Into<HeaderValue>>(&mut self, name: HeaderName, value: V) {
         let value = value.into();
+        self.1.remove(&name);
         self.0.insert(name, value);
     }
 
     /// Appends a value to an existing RTSP header or inserts it.
     ///
     /// Additional values are comma separated as defined in [RFC 7826 section 5.2](https://tools.ietf.org/html/rfc7826#section-5.2).
+    /// Empty values are not joined so that no empty list elements are created.
     pub fn append<V: Into<HeaderValue>>(&mut self, name: HeaderName, value: V) {
-        let value = value.into();
-        self.0
-            .entry(name)
-            .and_modify(|old_value| {
-                old_value.0.push_str(", ");
-                old_value.0.push_str(&value.0);
-            })
-            .or_insert(value);
+        self.1.remove(&name);
+        self.append_value(name, value.into());
+    }
+
+    fn append_value(&mut self, name: HeaderName, value: HeaderValue) {
+        use std::collections::btree_map::Entry;
+
+        match self.0.entry(name) {
+            Entry::Occupied(mut entry) => {
+                let old_value = entry.get_mut();
+                if old_value.0.is_empty() {
+                    *old_value = value;
+                } else if !value.0.is_empty() {
+                    old_value.0.push_str(", ");
+                    old_value.0.push_str(&value.0);
+                }
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+            }
+        }
+    }
+
+    /// Merges all headers of `other` into these headers.
+    ///
+    /// Headers that only exist in one of both are always kept. For headers that exist in both,
+    /// `policy` defines which value is used.
+    pub fn merge(&mut self, other: Headers, policy: MergePolicy) {
+        for (name, value) in other.0 {
+            match policy {
+                MergePolicy::Overwrite => self.insert(name, value),
+                MergePolicy::KeepExisting => {
+                    if !self.0.contains_key(&name) {
+                        self.insert(name, value);
+                    }
+                }
+                MergePolicy::Append if super::is_list_header(&name) => self.append(name, value),
+                MergePolicy::Append => self.insert(name, value),
+            }
+        }
+    }
+
+    /// Compares these headers with `other` and returns all differences, ordered by header name.
+    ///
+    /// `self` is considered the old and `other` the new state of the headers, e.g. before and
+    /// after a proxy rewrote them. Header values are compared as strings.
+    pub fn diff(&self, other: &Headers) -> Vec<HeaderDiff> {
+        let mut diff = Vec::new();
+
+        for (name, value) in &self.0 {
+            match other.0.get(name) {
+                None => diff.push(HeaderDiff::Removed(name.clone(), value.clone())),
+                Some(new_value) if new_value != value => diff.push(HeaderDiff::Changed {
+                    name: name.clone(),
+                    old: value.clone(),
+                    new: new_value.clone(),
+                }),
+                Some(_) => (),
+            }
+        }
+
+        for (name, value) in &other.0 {
+            if !self.0.contains_key(name) {
+                diff.push(HeaderDiff::Added(name.clone(), value.clone()));
+            }
+        }
+
+        diff.sort_by(|a, b| a.name().cmp(b.name()));
+
+        diff
+    }
+
+    /// Insert an RTSP header with its value after validating both.
+    ///
+    /// The name must be a non-empty token and the value must not contain any control characters
+    /// other than tab, in particular no CR or LF. Otherwise the headers are left unchanged.
+    ///
+    /// If a header with the same name already exists then its value will be replaced.
+    pub fn try_insert(&mut self, name: &str, value: &str) -> Result<(), HeaderError> {
+        fn is_token_char(b: u8) -> bool {
+            b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b)
+        }
+
+        if name.is_empty() || !name.bytes().all(is_token_char) {
+            return Err(HeaderError::InvalidName);
+        }
+
+        let name = HeaderName::try_from(name).map_err(|_| HeaderError::InvalidName)?;
+        let value = HeaderValue::new(value).map_err(|_| HeaderError::InvalidValue)?;
+
+        self.insert(name, value);
+
+        Ok(())
     }
 
     /// Insert a typed RTSP header.
@@ -104,6 +215,40 @@ impl Headers {
     /// Removes and RTSP header if it exists.
     pub fn remove(&mut self, name: &HeaderName) {
         self.0.remove(name);
+        self.1.remove(name);
+    }
+
+    /// Removes all hop-by-hop headers.
+    ///
+    /// This removes the `Connection` header and all headers listed in it, as well as the
+    /// connection specific `Connection-Credentials` and `Proxy-Authenticate`,
+    /// `Proxy-Authentication-Info` and `Proxy-Authorization` headers.
+    ///
+    /// Proxies should call this before forwarding a message to the next hop. See
+    /// [RFC 7826 section 18.12](https://tools.ietf.org/html/rfc7826#section-18.12).
+    pub fn strip_hop_by_hop(&mut self) {
+        use super::{
+            CONNECTION, CONNECTION_CREDENTIALS, PROXY_AUTHENTICATE, PROXY_AUTHENTICATION_INFO,
+            PROXY_AUTHORIZATION,
+        };
+
+        if let Some(connection) = self.0.remove(&CONNECTION) {
+            self.1.remove(&CONNECTION);
+            for token in connection.as_str().split(',') {
+                if let Ok(name) = HeaderName::try_from(token.trim()) {
+                    self.remove(&name);
+                }
+            }
+        }
+
+        for name in &[
+            CONNECTION_CREDENTIALS,
+            PROXY_AUTHENTICATE,
+            PROXY_AUTHENTICATION_INFO,
+            PROXY_AUTHORIZATION,
+        ] {
+            self.remove(name);
+        }
     }
 
     /// Gets an RTSP header value if it exists.
@@ -112,20 +257,80 @@ impl Headers {
     }
 
     /// Gets a typed RTSP header value if it exists.
+    ///
+    /// On parsing errors the name of the header is available via
+    /// [`HeaderParseError::header_name`].
     pub fn get_typed<H: TypedHeader>(&self) -> Result<Option<H>, HeaderParseError> {
         H::from_headers(self)
     }
 
     /// Gets a mutable reference to an RTSP header value if it exists.
+    ///
+    /// This discards the raw value of the header, if any.
     pub fn get_mut(&mut self, name: &HeaderName) -> Option<&mut HeaderValue> {
+        self.1.remove(name);
         self.0.get_mut(name)
     }
 
+    /// Gets the raw value of an RTSP header as received, if available.
+    ///
+    /// Raw values are only available for headers of parsed messages if
+    /// [`ParseOptions::preserve_raw_headers`](../struct.ParseOptions.html#method.preserve_raw_headers)
+    /// was enabled, and only until the header is modified.
+    ///
+    /// Unlike [`get`](#method.get), the raw value contains line folding as received and is not
+    /// required to be valid UTF-8. Only leading and trailing whitespace and the final CRLF are
+    /// not included.
+    ///
+    /// If the header appeared multiple times in the message then this returns the raw value of
+    /// the first occurrence, see [`raw_values`](#method.raw_values) for all of them.
+    pub fn raw_value(&self, name: &HeaderName) -> Option<&[u8]> {
+        self.raw_values(name).next()
+    }
+
+    /// Iterator over the raw values of all occurrences of an RTSP header, in the order they were
+    /// received.
+    ///
+    /// See [`raw_value`](#method.raw_value) for details.
+    pub fn raw_values(&self, name: &HeaderName) -> impl Iterator<Item = &[u8]> {
+        self.1
+            .get(name)
+            .into_iter()
+            .flat_map(|values| values.iter().map(Vec::as_slice))
+    }
+
     /// Iterator over all header name and value pairs.
     pub fn iter(&self) -> impl Iterator<Item = (&HeaderName, &HeaderValue)> {
         self.0.iter()
     }
 
+    /// Iterator over all header name and value pairs in canonical RTSP order.
+    ///
+    /// This yields the `CSeq` header first and all other headers case-insensitive alphabetically
+    /// afterwards, which is also the order used when serializing with
+    /// [`WriteOptions::canonical_header_order`](../struct.WriteOptions.html#method.canonical_header_order).
+    pub fn sorted_canonical(&self) -> impl Iterator<Item = (&HeaderName, &HeaderValue)> {
+        self.iter_ordered(true)
+    }
+
+    // Iterator over all headers, with the `CSeq` header first if `canonical_order` is set.
+    pub(crate) fn iter_ordered(
+        &self,
+        canonical_order: bool,
+    ) -> impl Iterator<Item = (&HeaderName, &HeaderValue)> + Clone {
+        let cseq = if canonical_order {
+            self.0.get_key_value(&super::CSEQ)
+        } else {
+            None
+        };
+
+        cseq.into_iter().chain(
+            self.0
+                .iter()
+                .filter(move |(name, _)| cseq.is_none() || **name != super::CSEQ),
+        )
+    }
+
     /// Iterator over all header names.
     pub fn names(&self) -> impl Iterator<Item = &HeaderName> {
         self.0.keys()
@@ -137,6 +342,35 @@ impl Headers {
     }
 }
 
+impl PartialEq for Headers {
+    fn eq(&self, other: &Headers) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for Headers {}
+
+// Raw values are not serialized, which keeps the same representation as a newtype around the
+// header map.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Headers {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct("Headers", &self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Headers {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Headers")]
+        struct Map(BTreeMap<HeaderName, HeaderValue>);
+
+        let Map(map) = Map::deserialize(deserializer)?;
+        Ok(Headers(map, BTreeMap::new()))
+    }
+}
+
 impl AsRef<Headers> for Headers {
     fn as_ref(&self) -> &Headers {
         self
@@ -238,29 +472,29 @@ impl PartialOrd for HeaderName {
 /// Case-insensitive ordering of header names.
 impl Ord for HeaderName {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        let s = self.0.as_bytes();
-        let o = other.0.as_bytes();
-
-        let len = std::cmp::min(s.len(), o.len());
+        cmp_names(self.as_str(), other.as_str())
+    }
+}
 
-        let s = &s[..len];
-        let o = &o[..len];
+// Case-insensitive ordering of header names as strings.
+pub(crate) fn cmp_names(s: &str, o: &str) -> std::cmp::Ordering {
+    let s = s.as_bytes();
+    let o = o.as_bytes();
 
-        for (s, o) in Iterator::zip(s.iter(), o.iter()) {
-            let mut s = *s;
-            let mut o = *o;
+    for (s, o) in Iterator::zip(s.iter(), o.iter()) {
+        let mut s = *s;
+        let mut o = *o;
 
-            s.make_ascii_lowercase();
-            o.make_ascii_lowercase();
+        s.make_ascii_lowercase();
+        o.make_ascii_lowercase();
 
-            match s.cmp(&o) {
-                std::cmp::Ordering::Equal => (),
-                non_eq => return non_eq,
-            }
+        match s.cmp(&o) {
+            std::cmp::Ordering::Equal => (),
+            non_eq => return non_eq,
         }
-
-        s.len().cmp(&o.len())
     }
+
+    s.len().cmp(&o.len())
 }
 
 /// Case-insensitive hashing of header names.
@@ -270,7 +504,7 @@ impl std::hash::Hash for HeaderName {
         H: std::hash::Hasher,
     {
         for b in self.0.as_bytes() {
-            b.hash(h)
+            b.to_ascii_lowercase().hash(h)
         }
     }
 }
@@ -333,18 +567,39 @@ impl fmt::Display for HeaderName {
 pub struct HeaderValue(String);
 
 impl HeaderValue {
+    /// Create a header value after checking that it contains no control characters.
+    ///
+    /// CR and LF in particular would allow injecting additional header lines or a body when
+    /// serializing the message, so this should be used for values from untrusted sources.
+    /// Horizontal tabs are allowed.
+    pub fn new<S: Into<String>>(v: S) -> Result<HeaderValue, ControlCharError> {
+        let v = v.into();
+
+        if v.bytes().any(|b| (b < 32 && b != b'\t') || b == 127) {
+            return Err(ControlCharError);
+        }
+
+        Ok(HeaderValue(v))
+    }
+
     /// Get a `&str` for the header value.
     pub fn as_str(&self) -> &str {
         self.0.as_str()
     }
 }
 
+/// Create a header value from a `String`.
+///
+/// This does not validate the value, see [`HeaderValue::new`] for a checked alternative.
 impl From<String> for HeaderValue {
     fn from(v: String) -> HeaderValue {
         HeaderValue(v)
     }
 }
 
+/// Create a header value from a `&str`.
+///
+/// This does not validate the value, see [`HeaderValue::new`] for a checked alternative.
 impl<'a> From<&'a str> for HeaderValue {
     fn from(v: &'a str) -> HeaderValue {
         HeaderValue(String::from(v))
@@ -400,6 +655,25 @@ impl fmt::Display for HeaderValue {
 }
 
 /// Trait for typed headers.
+///
+/// Requests, responses and [`Headers`] all implement `AsRef<Headers>`, so code that is generic
+/// over them can read typed headers via [`TypedHeader::from_headers`].
+///
+/// ```rust
+/// use rtsp_types::headers::{self, Headers, Scale, TypedHeader};
+///
+/// fn scale<M: AsRef<Headers>>(msg: &M) -> Option<f64> {
+///     Scale::from_headers(msg).ok().flatten().map(f64::from)
+/// }
+///
+/// let request = rtsp_types::Request::builder(
+///         rtsp_types::Method::Play,
+///         rtsp_types::Version::V2_0
+///     )
+///     .header(headers::SCALE, "2.0")
+///     .empty();
+/// assert_eq!(scale(&request), Some(2.0));
+/// ```
 pub trait TypedHeader: Sized {
     /// Parses the header from headers.
     fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError>;
@@ -408,11 +682,68 @@ pub trait TypedHeader: Sized {
 }
 
 /// Trait for typed headers that can be appended.
+///
+/// This is only implemented for headers that are defined as comma-separated lists, where
+/// appending a value is equivalent to extending the list. Single-valued headers like
+/// [`CSeq`](crate::headers::CSeq) or [`Scale`](crate::headers::Scale) can only be inserted.
 pub trait TypedAppendableHeader: TypedHeader {
     /// Appends the header to headers.
     fn append_to(&self, headers: impl AsMut<Headers>);
 }
 
+/// Joins methods into a comma-separated header value.
+///
+/// This is the format used by the `Allow` and `Public` headers.
+pub fn join_methods(methods: &[crate::Method]) -> String {
+    let mut value = String::new();
+    for method in methods {
+        if !value.is_empty() {
+            value.push_str(", ");
+        }
+
+        value.push_str(method.into());
+    }
+
+    value
+}
+
+/// Splits a comma-separated header value into methods.
+///
+/// If `lenient` is set then the case of known methods is ignored, see
+/// [`Method::parse_lenient`](../enum.Method.html#method.parse_lenient).
+pub(crate) fn split_methods(value: &str, lenient: bool) -> Vec<crate::Method> {
+    // Empty entries, e.g. from a trailing comma, are skipped
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|method| !method.is_empty())
+        .map(|method| {
+            if lenient {
+                crate::Method::parse_lenient(method)
+            } else {
+                crate::Method::from(method)
+            }
+        })
+        .collect()
+}
+
+/// Joins feature tags into a comma-separated header value.
+///
+/// This is the format used by the `Supported`, `Require`, `Proxy-Require` and `Unsupported`
+/// headers.
+pub fn join_features<S: AsRef<str>>(features: &[S]) -> String {
+    let mut value = String::new();
+    for feature in features {
+        if !value.is_empty() {
+            value.push_str(", ");
+        }
+
+        value.push_str(feature.as_ref());
+    }
+
+    value
+}
+
 /// Parsing a `HeaderName` failed because it contained non-ASCII characters.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AsciiError;
@@ -437,14 +768,376 @@ impl fmt::Display for Utf8Error {
     }
 }
 
-/// Parsing a `HeaderValue` failed.
+/// Creating a `HeaderValue` failed because it contained control characters like CR or LF.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ControlCharError;
+
+impl error::Error for ControlCharError {}
+
+impl fmt::Display for ControlCharError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "Invalid control character")
+    }
+}
+
+/// Policy for headers that exist in both header sets in [`Headers::merge`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MergePolicy {
+    /// Replace the existing value with the merged one.
+    Overwrite,
+    /// Keep the existing value and ignore the merged one.
+    KeepExisting,
+    /// Append the merged value to the existing one, comma separated, if the header is a
+    /// well-known list-valued header like `Supported` or `Transport`.
+    ///
+    /// All other headers, including unknown ones, are replaced like with
+    /// [`MergePolicy::Overwrite`] as a list of values would not be valid for them.
+    Append,
+}
+
+/// A difference between two header sets as returned by [`Headers::diff`].
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct HeaderParseError;
+pub enum HeaderDiff {
+    /// The header only exists in the new headers.
+    Added(HeaderName, HeaderValue),
+    /// The header only exists in the old headers.
+    Removed(HeaderName, HeaderValue),
+    /// The header exists in both but with different values.
+    Changed {
+        /// Name of the header.
+        name: HeaderName,
+        /// Value in the old headers.
+        old: HeaderValue,
+        /// Value in the new headers.
+        new: HeaderValue,
+    },
+}
+
+impl HeaderDiff {
+    /// Name of the header this difference is about.
+    pub fn name(&self) -> &HeaderName {
+        match self {
+            HeaderDiff::Added(name, _) | HeaderDiff::Removed(name, _) => name,
+            HeaderDiff::Changed { name, .. } => name,
+        }
+    }
+}
+
+impl fmt::Display for HeaderDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HeaderDiff::Added(name, value) => write!(f, "+ {name}: {value}"),
+            HeaderDiff::Removed(name, value) => write!(f, "- {name}: {value}"),
+            HeaderDiff::Changed { name, old, new } => write!(f, "~ {name}: {old} -> {new}"),
+        }
+    }
+}
+
+/// Inserting a header via [`Headers::try_insert`] failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HeaderError {
+    /// The header name is not a valid token.
+    InvalidName,
+    /// The header value contains control characters.
+    InvalidValue,
+}
+
+impl error::Error for HeaderError {}
+
+impl fmt::Display for HeaderError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HeaderError::InvalidName => write!(fmt, "Invalid header name"),
+            HeaderError::InvalidValue => write!(fmt, "Invalid header value"),
+        }
+    }
+}
+
+/// Parsing a `HeaderValue` failed.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct HeaderParseError {
+    header_name: Option<HeaderName>,
+}
+
+impl HeaderParseError {
+    /// Creates a new header parse error without any further information.
+    pub fn new() -> Self {
+        HeaderParseError { header_name: None }
+    }
+
+    /// Creates a new header parse error for the header with the given name.
+    ///
+    /// This should be used by [`TypedHeader::from_headers`] implementations.
+    pub fn for_header(header_name: HeaderName) -> Self {
+        HeaderParseError {
+            header_name: Some(header_name),
+        }
+    }
+
+    /// Name of the header that failed parsing, if known.
+    pub fn header_name(&self) -> Option<&HeaderName> {
+        self.header_name.as_ref()
+    }
+}
 
 impl error::Error for HeaderParseError {}
 
 impl fmt::Display for HeaderParseError {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(fmt, "Error parsing error")
+        match self.header_name {
+            Some(ref header_name) => write!(fmt, "Error parsing header '{header_name}'"),
+            None => write!(fmt, "Error parsing header"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::headers::*;
+
+    #[test]
+    fn test_strip_hop_by_hop() {
+        let mut request = crate::Request::builder(crate::Method::Play, crate::Version::V2_0)
+            .header(CSEQ, "2")
+            .header(CONNECTION, "close, X-Custom")
+            .header(HeaderName::from_static_str("X-Custom").unwrap(), "foo")
+            .header(PROXY_AUTHORIZATION, "Basic Zm9vOmJhcg==")
+            .header(SESSION, "12345678")
+            .empty();
+
+        AsMut::<Headers>::as_mut(&mut request).strip_hop_by_hop();
+
+        assert_eq!(
+            request.header_names().collect::<Vec<_>>(),
+            vec![&CSEQ, &SESSION]
+        );
+    }
+
+    #[test]
+    fn test_join_helpers() {
+        use crate::Method;
+
+        let methods = [
+            Method::Options,
+            Method::Setup,
+            Method::Play,
+            Method::Teardown,
+        ];
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .typed_header(&Public::from(&methods[..]))
+            .empty();
+        assert_eq!(
+            response.header(&PUBLIC).unwrap().as_str(),
+            join_methods(&methods)
+        );
+        assert_eq!(join_methods(&methods), "OPTIONS, SETUP, PLAY, TEARDOWN");
+
+        let features = [Feature::PlayBasic, Feature::PlayScale];
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .typed_header(&Supported::builder().play_basic().play_scale().build())
+            .empty();
+        assert_eq!(
+            response.header(&SUPPORTED).unwrap().as_str(),
+            join_features(&features)
+        );
+        assert_eq!(join_features(&["play.basic"]), "play.basic");
+        assert_eq!(join_features::<&str>(&[]), "");
+    }
+
+    #[test]
+    fn test_header_value_new() {
+        assert_eq!(
+            HeaderValue::new("play.basic,\tplay.scale")
+                .unwrap()
+                .as_str(),
+            "play.basic,\tplay.scale"
+        );
+        assert_eq!(
+            HeaderValue::new("1\r\nSession: 12345678"),
+            Err(ControlCharError)
+        );
+        assert_eq!(HeaderValue::new("1\n"), Err(ControlCharError));
+        assert_eq!(HeaderValue::new("1\r"), Err(ControlCharError));
+        assert_eq!(HeaderValue::new(String::from("1\0")), Err(ControlCharError));
+    }
+
+    #[test]
+    fn test_try_insert() {
+        let mut headers = Headers::new();
+
+        assert_eq!(headers.try_insert("X-Custom", "foo\tbar"), Ok(()));
+        assert_eq!(
+            headers
+                .get(&HeaderName::from_static_str("x-custom").unwrap())
+                .unwrap()
+                .as_str(),
+            "foo\tbar"
+        );
+        assert_eq!(headers.try_insert("CSeq", "1"), Ok(()));
+        assert_eq!(headers.get(&CSEQ).unwrap().as_str(), "1");
+
+        for name in ["", "X Custom", "X-Custom:", "X-Cüstom", "X-Custom\r\nCSeq"] {
+            assert_eq!(
+                headers.try_insert(name, "foo"),
+                Err(HeaderError::InvalidName),
+                "{}",
+                name
+            );
+        }
+
+        for value in ["1\r\nX-Injected: 1", "1\n", "1\r", "1\0"] {
+            assert_eq!(
+                headers.try_insert("CSeq", value),
+                Err(HeaderError::InvalidValue),
+                "{}",
+                value
+            );
+        }
+
+        assert_eq!(headers.get(&CSEQ).unwrap().as_str(), "1");
+        assert_eq!(headers.names().count(), 2);
+    }
+
+    #[test]
+    fn test_from_headers_generic() {
+        fn scale_and_speed<M: AsRef<Headers>>(
+            msg: &M,
+        ) -> Result<(Option<Scale>, Option<Speed>), HeaderParseError> {
+            Ok((Scale::from_headers(msg)?, Speed::from_headers(msg)?))
+        }
+
+        let request = crate::Request::builder(crate::Method::Play, crate::Version::V2_0)
+            .header(SCALE, "-2.5")
+            .header(SPEED, "1.0")
+            .empty();
+        let (scale, speed) = scale_and_speed(&request).unwrap();
+        assert_eq!(scale.map(f64::from), Some(-2.5));
+        assert_eq!(speed.map(f64::from), Some(1.0));
+
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .header(SCALE, "-2.0")
+            .empty();
+        let (scale, speed) = scale_and_speed(&response).unwrap();
+        assert_eq!(scale.map(f64::from), Some(-2.0));
+        assert!(speed.is_none());
+
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .header(SPEED, "fast")
+            .empty();
+        assert!(scale_and_speed(&response).is_err());
+    }
+
+    #[test]
+    fn test_diff() {
+        let custom = HeaderName::from_static_str("X-Custom").unwrap();
+
+        let mut before = Headers::new();
+        before.insert(CSEQ, "1");
+        before.insert(SERVER, "PhonyServer/1.0");
+        before.insert(SUPPORTED, "play.basic");
+        before.insert(PROXY_AUTHORIZATION, "Basic Zm9vOmJhcg==");
+
+        // Simulate a proxy rewriting the headers
+        let mut after = before.clone();
+        assert!(before.diff(&after).is_empty());
+        after.strip_hop_by_hop();
+        after.append(SUPPORTED, "play.scale");
+        after.insert(custom.clone(), "a");
+        after.insert(HeaderName::from_static_str("cseq").unwrap(), "1");
+
+        assert_eq!(
+            before.diff(&after),
+            vec![
+                HeaderDiff::Removed(PROXY_AUTHORIZATION, "Basic Zm9vOmJhcg==".into()),
+                HeaderDiff::Changed {
+                    name: SUPPORTED,
+                    old: "play.basic".into(),
+                    new: "play.basic, play.scale".into(),
+                },
+                HeaderDiff::Added(custom.clone(), "a".into()),
+            ]
+        );
+
+        let diff = after.diff(&before);
+        assert_eq!(
+            diff.iter().map(|d| d.to_string()).collect::<Vec<_>>(),
+            [
+                "+ Proxy-Authorization: Basic Zm9vOmJhcg==",
+                "~ Supported: play.basic, play.scale -> play.basic",
+                "- X-Custom: a",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let custom = HeaderName::from_static_str("X-Custom").unwrap();
+
+        let mut template = Headers::new();
+        template.insert(SERVER, "PhonyServer/1.0");
+        template.insert(SUPPORTED, "play.basic");
+        template.insert(CSEQ, "1");
+        template.insert(custom.clone(), "a");
+
+        let mut other = Headers::new();
+        other.insert(SUPPORTED, "play.scale");
+        other.insert(CSEQ, "2");
+        other.insert(custom.clone(), "b");
+        other.insert(SESSION, "12345678");
+
+        let merged = |policy| {
+            let mut headers = template.clone();
+            headers.merge(other.clone(), policy);
+            assert_eq!(headers.get(&SERVER).unwrap(), "PhonyServer/1.0");
+            assert_eq!(headers.get(&SESSION).unwrap(), "12345678");
+            assert_eq!(headers.names().count(), 5);
+            headers
+        };
+
+        let headers = merged(MergePolicy::Overwrite);
+        assert_eq!(headers.get(&SUPPORTED).unwrap(), "play.scale");
+        assert_eq!(headers.get(&CSEQ).unwrap(), "2");
+        assert_eq!(headers.get(&custom).unwrap(), "b");
+
+        let headers = merged(MergePolicy::KeepExisting);
+        assert_eq!(headers.get(&SUPPORTED).unwrap(), "play.basic");
+        assert_eq!(headers.get(&CSEQ).unwrap(), "1");
+        assert_eq!(headers.get(&custom).unwrap(), "a");
+
+        let headers = merged(MergePolicy::Append);
+        assert_eq!(headers.get(&SUPPORTED).unwrap(), "play.basic, play.scale");
+        assert_eq!(headers.get(&CSEQ).unwrap(), "2");
+        assert_eq!(headers.get(&custom).unwrap(), "b");
+        assert!(headers
+            .get_typed::<Supported>()
+            .unwrap()
+            .unwrap()
+            .contains_play_scale());
+    }
+
+    #[test]
+    fn test_header_name_ordering() {
+        use std::collections::HashSet;
+
+        assert_ne!(ACCEPT, ACCEPT_RANGES);
+        assert!(ACCEPT < ACCEPT_RANGES);
+        assert!(ACCEPT_RANGES > ACCEPT);
+        assert_eq!(
+            HeaderName::from_static_str("accept-ranges").unwrap(),
+            ACCEPT_RANGES
+        );
+
+        let names: HashSet<_> = [
+            ACCEPT,
+            HeaderName::from_static_str("accept").unwrap(),
+            ACCEPT_RANGES,
+        ]
+        .iter()
+        .cloned()
+        .collect();
+        assert_eq!(names.len(), 2);
     }
 }
@@ -6,7 +6,7 @@ use super::features::*;
 use super::*;
 
 /// `Unsupported` header ([RFC 7826 section 18.55](https://tools.ietf.org/html/rfc7826#section-18.55)).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Unsupported(Vec<String>);
 
@@ -48,6 +48,12 @@ impl<'a> From<&'a [String]> for Unsupported {
     }
 }
 
+impl std::iter::FromIterator<String> for Unsupported {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        Unsupported(iter.into_iter().collect())
+    }
+}
+
 impl<'a> From<&'a [&'a &str]> for Unsupported {
     fn from(v: &'a [&'a &str]) -> Self {
         Unsupported(v.iter().map(|s| String::from(**s)).collect())
@@ -60,6 +66,11 @@ impl Unsupported {
         UnsupportedBuilder(Vec::new())
     }
 
+    /// Returns the features of the `Unsupported` header as [`Feature`]s.
+    pub fn features(&self) -> Vec<Feature> {
+        super::features::features_from_tags(&self.0)
+    }
+
     /// Check if the "play.basic" feature is unsupported.
     ///
     /// See [RFC 7826 section 11.1](https://tools.ietf.org/html/rfc7826#section-11.1).
@@ -155,33 +166,13 @@ impl super::TypedHeader for Unsupported {
 
     fn insert_into(&self, mut headers: impl AsMut<Headers>) {
         let headers = headers.as_mut();
-
-        let mut unsupported = String::new();
-        for feature in &self.0 {
-            if !unsupported.is_empty() {
-                unsupported.push_str(", ");
-            }
-
-            unsupported.push_str(feature);
-        }
-
-        headers.insert(UNSUPPORTED, unsupported);
+        headers.insert(UNSUPPORTED, join_features(&self.0));
     }
 }
 
 impl super::TypedAppendableHeader for Unsupported {
     fn append_to(&self, mut headers: impl AsMut<Headers>) {
         let headers = headers.as_mut();
-
-        let mut unsupported = String::new();
-        for feature in &self.0 {
-            if !unsupported.is_empty() {
-                unsupported.push_str(", ");
-            }
-
-            unsupported.push_str(feature);
-        }
-
-        headers.append(UNSUPPORTED, unsupported);
+        headers.append(UNSUPPORTED, join_features(&self.0));
     }
 }
@@ -0,0 +1,375 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+use std::fmt;
+
+use url::Url;
+
+/// URI reference ([RFC 3986 section 4.1](https://tools.ietf.org/html/rfc3986#section-4.1)).
+///
+/// Relative references are kept as is and can be resolved against a base URI with
+/// [`resolve`](#method.resolve).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "String", into = "String")
+)]
+pub enum UriReference {
+    /// Absolute URI.
+    Absolute(Url),
+    /// Relative reference.
+    Relative(String),
+}
+
+impl UriReference {
+    /// Returns the URI reference as string.
+    pub fn as_str(&self) -> &str {
+        match self {
+            UriReference::Absolute(url) => url.as_str(),
+            UriReference::Relative(s) => s.as_str(),
+        }
+    }
+
+    /// Returns the absolute URI, if this is not a relative reference.
+    pub fn as_url(&self) -> Option<&Url> {
+        match self {
+            UriReference::Absolute(url) => Some(url),
+            UriReference::Relative(_) => None,
+        }
+    }
+
+    /// Resolves the URI reference against `base`.
+    ///
+    /// Absolute URIs are returned as is.
+    pub fn resolve(&self, base: &Url) -> Result<Url, url::ParseError> {
+        match self {
+            UriReference::Absolute(url) => Ok(url.clone()),
+            UriReference::Relative(s) => base.join(s),
+        }
+    }
+}
+
+impl fmt::Display for UriReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for UriReference {
+    type Err = HeaderParseError;
+
+    fn from_str(s: &str) -> Result<Self, HeaderParseError> {
+        match Url::parse(s) {
+            Ok(url) => Ok(UriReference::Absolute(url)),
+            Err(url::ParseError::RelativeUrlWithoutBase)
+                if !s.is_empty()
+                    && !s
+                        .bytes()
+                        .any(|b| b.is_ascii_whitespace() || b.is_ascii_control()) =>
+            {
+                Ok(UriReference::Relative(String::from(s)))
+            }
+            Err(_) => Err(HeaderParseError::new()),
+        }
+    }
+}
+
+impl std::convert::TryFrom<String> for UriReference {
+    type Error = HeaderParseError;
+
+    fn try_from(s: String) -> Result<Self, HeaderParseError> {
+        s.parse()
+    }
+}
+
+impl From<UriReference> for String {
+    fn from(v: UriReference) -> String {
+        match v {
+            UriReference::Absolute(url) => url.into(),
+            UriReference::Relative(s) => s,
+        }
+    }
+}
+
+impl From<Url> for UriReference {
+    fn from(v: Url) -> UriReference {
+        UriReference::Absolute(v)
+    }
+}
+
+// Defines a newtype header around `Url` or `UriReference`.
+macro_rules! uri_header {
+    ($(#[$attr:meta])* $name:ident, $header:ident, $ty:ty) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct $name($ty);
+
+        impl std::ops::Deref for $name {
+            type Target = $ty;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl std::ops::DerefMut for $name {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl AsRef<$ty> for $name {
+            fn as_ref(&self) -> &$ty {
+                &self.0
+            }
+        }
+
+        impl AsMut<$ty> for $name {
+            fn as_mut(&mut self) -> &mut $ty {
+                &mut self.0
+            }
+        }
+
+        impl From<$ty> for $name {
+            fn from(v: $ty) -> $name {
+                $name(v)
+            }
+        }
+
+        impl From<$name> for $ty {
+            fn from(v: $name) -> $ty {
+                v.0
+            }
+        }
+
+        impl super::TypedHeader for $name {
+            fn from_headers(
+                headers: impl AsRef<Headers>,
+            ) -> Result<Option<Self>, HeaderParseError> {
+                let headers = headers.as_ref();
+
+                let header = match headers.get(&$header) {
+                    None => return Ok(None),
+                    Some(header) => header,
+                };
+
+                let uri = header
+                    .as_str()
+                    .trim()
+                    .parse::<$ty>()
+                    .map($name)
+                    .map_err(|_| HeaderParseError::for_header($header))?;
+
+                Ok(Some(uri))
+            }
+
+            fn insert_into(&self, mut headers: impl AsMut<Headers>) {
+                let headers = headers.as_mut();
+
+                headers.insert($header, self.0.as_str());
+            }
+        }
+    };
+}
+
+uri_header!(
+    /// `Content-Base` header ([RFC 7826 section 18.14](https://tools.ietf.org/html/rfc7826#section-18.14)).
+    ///
+    /// Only absolute URIs are supported, relative ones fail to parse.
+    ///
+    /// Relative URIs, e.g. the `a=control` attributes of an SDP, are resolved against this URI.
+    ///
+    /// ## Resolving a relative control URI
+    ///
+    /// ```rust
+    /// use rtsp_types::headers::ContentBase;
+    ///
+    /// let response = rtsp_types::Response::builder(
+    ///         rtsp_types::Version::V1_0,
+    ///         rtsp_types::StatusCode::Ok
+    ///     )
+    ///     .header(rtsp_types::headers::CONTENT_BASE, "rtsp://example.com/media/")
+    ///     .empty();
+    ///
+    /// let content_base = response.typed_header::<ContentBase>().unwrap().unwrap();
+    /// assert_eq!(
+    ///     content_base.join("trackID=1").unwrap().as_str(),
+    ///     "rtsp://example.com/media/trackID=1"
+    /// );
+    /// ```
+    ContentBase,
+    CONTENT_BASE,
+    Url
+);
+
+uri_header!(
+    /// `Content-Location` header ([RFC 7826 section 18.18](https://tools.ietf.org/html/rfc7826#section-18.18)).
+    ///
+    /// This can be an absolute URI or a relative reference.
+    /// [`Response::resolved_content_location`](../../struct.Response.html#method.resolved_content_location)
+    /// resolves relative references against the `Content-Base` header.
+    ContentLocation,
+    CONTENT_LOCATION,
+    UriReference
+);
+
+uri_header!(
+    /// `Location` header ([RFC 7826 section 18.28](https://tools.ietf.org/html/rfc7826#section-18.28)).
+    ///
+    /// Only absolute URIs are supported, relative ones fail to parse.
+    Location,
+    LOCATION,
+    Url
+);
+
+uri_header!(
+    /// `Referrer` header ([RFC 7826 section 18.41](https://tools.ietf.org/html/rfc7826#section-18.41)).
+    ///
+    /// This can be an absolute URI or a relative reference.
+    Referrer,
+    REFERRER,
+    UriReference
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uri_reference() {
+        let uri = "rtsp://example.com/media.mp4"
+            .parse::<UriReference>()
+            .unwrap();
+        assert_eq!(
+            uri.as_url(),
+            Some(&Url::parse("rtsp://example.com/media.mp4").unwrap())
+        );
+
+        let uri = "../media.mp4".parse::<UriReference>().unwrap();
+        assert_eq!(uri, UriReference::Relative(String::from("../media.mp4")));
+        assert_eq!(uri.as_url(), None);
+        assert_eq!(
+            uri.resolve(&Url::parse("rtsp://example.com/media/stream").unwrap())
+                .unwrap()
+                .as_str(),
+            "rtsp://example.com/media.mp4"
+        );
+
+        for uri in ["", "media 1.mp4", "rtsp://[::1/media.mp4"] {
+            assert!(uri.parse::<UriReference>().is_err(), "{}", uri);
+        }
+    }
+
+    #[test]
+    fn test_content_base() {
+        let response = crate::Response::builder(crate::Version::V1_0, crate::StatusCode::Ok)
+            .header(CONTENT_BASE, "rtsp://example.com/media.mp4/")
+            .empty();
+
+        let content_base = response.typed_header::<ContentBase>().unwrap().unwrap();
+        assert_eq!(content_base.as_str(), "rtsp://example.com/media.mp4/");
+        assert_eq!(
+            content_base.join("streamid=0").unwrap().as_str(),
+            "rtsp://example.com/media.mp4/streamid=0"
+        );
+
+        let response2 = crate::Response::builder(crate::Version::V1_0, crate::StatusCode::Ok)
+            .typed_header(&content_base)
+            .empty();
+        assert_eq!(response, response2);
+
+        let response = crate::Response::builder(crate::Version::V1_0, crate::StatusCode::Ok)
+            .header(CONTENT_BASE, "media.mp4/")
+            .empty();
+        let err = response.typed_header::<ContentBase>().unwrap_err();
+        assert_eq!(err.header_name(), Some(&CONTENT_BASE));
+    }
+
+    #[test]
+    fn test_content_location() {
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .header(CONTENT_LOCATION, "rtsp://example.com/media.mp4")
+            .empty();
+
+        let content_location = response.typed_header::<ContentLocation>().unwrap().unwrap();
+        assert_eq!(content_location.as_str(), "rtsp://example.com/media.mp4");
+        assert!(content_location.as_url().is_some());
+
+        let response2 = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .typed_header(&content_location)
+            .empty();
+        assert_eq!(response, response2);
+
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .header(CONTENT_LOCATION, "media.mp4/streamid=0")
+            .empty();
+        let content_location = response.typed_header::<ContentLocation>().unwrap().unwrap();
+        assert_eq!(
+            *content_location,
+            UriReference::Relative(String::from("media.mp4/streamid=0"))
+        );
+
+        let response2 = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .typed_header(&content_location)
+            .empty();
+        assert_eq!(response, response2);
+
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Ok)
+            .header(CONTENT_LOCATION, "rtsp://[::1/media.mp4")
+            .empty();
+        assert!(response.typed_header::<ContentLocation>().is_err());
+    }
+
+    #[test]
+    fn test_location() {
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Found)
+            .header(LOCATION, "rtsp://other.example.com:8554/media.mp4")
+            .empty();
+
+        let location = response.typed_header::<Location>().unwrap().unwrap();
+        assert_eq!(location.host_str(), Some("other.example.com"));
+        assert_eq!(location.port(), Some(8554));
+
+        let response2 = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Found)
+            .typed_header(&location)
+            .empty();
+        assert_eq!(response, response2);
+
+        let response = crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Found)
+            .header(LOCATION, "")
+            .empty();
+        assert!(response.typed_header::<Location>().is_err());
+    }
+
+    #[test]
+    fn test_referrer() {
+        let request = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+            .header(REFERRER, "http://example.com/index.html")
+            .empty();
+
+        let referrer = request.typed_header::<Referrer>().unwrap().unwrap();
+        assert_eq!(referrer.as_str(), "http://example.com/index.html");
+
+        let request2 = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+            .typed_header(&referrer)
+            .empty();
+        assert_eq!(request, request2);
+
+        let request = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+            .header(REFERRER, "/index.html")
+            .empty();
+        let referrer = request.typed_header::<Referrer>().unwrap().unwrap();
+        assert_eq!(referrer.as_str(), "/index.html");
+        assert_eq!(referrer.as_url(), None);
+
+        let request = crate::Request::builder(crate::Method::Describe, crate::Version::V2_0)
+            .header(REFERRER, "")
+            .empty();
+        assert!(request.typed_header::<Referrer>().is_err());
+    }
+}
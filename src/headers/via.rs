@@ -0,0 +1,302 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+use std::collections::BTreeMap;
+
+/// `Via` header ([RFC 7826 section 18.57](https://tools.ietf.org/html/rfc7826#section-18.57)).
+///
+/// Every proxy forwarding a message adds itself to this header, so unlike the product tokens of
+/// the `Server` and `User-Agent` headers, which are only set once by the sender, it can be
+/// appended to an existing header with
+/// [`Request::append_typed_header`](../struct.Request.html#method.append_typed_header).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Via(Vec<ViaEntry>);
+
+/// A single intermediary of the `Via` header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViaEntry {
+    /// Sent protocol, e.g. `RTSP/2.0` or `RTSP/2.0/TCP`.
+    pub protocol: String,
+    /// Host and optional port of the intermediary.
+    pub sent_by: String,
+    /// Parameters like `received` or `branch`, including unknown ones.
+    pub params: BTreeMap<String, Option<String>>,
+}
+
+impl ViaEntry {
+    /// Creates a new entry without any parameters.
+    pub fn new<P: Into<String>, S: Into<String>>(protocol: P, sent_by: S) -> Self {
+        ViaEntry {
+            protocol: protocol.into(),
+            sent_by: sent_by.into(),
+            params: BTreeMap::new(),
+        }
+    }
+
+    /// Value of the parameter with the given name, if any.
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params.get(name).and_then(|v| v.as_deref())
+    }
+
+    /// Value of the `received` parameter, if any.
+    pub fn received(&self) -> Option<&str> {
+        self.param("received")
+    }
+
+    /// Value of the `branch` parameter, if any.
+    pub fn branch(&self) -> Option<&str> {
+        self.param("branch")
+    }
+}
+
+impl std::ops::Deref for Via {
+    type Target = Vec<ViaEntry>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for Via {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<Vec<ViaEntry>> for Via {
+    fn as_ref(&self) -> &Vec<ViaEntry> {
+        &self.0
+    }
+}
+
+impl AsMut<Vec<ViaEntry>> for Via {
+    fn as_mut(&mut self) -> &mut Vec<ViaEntry> {
+        &mut self.0
+    }
+}
+
+impl From<Vec<ViaEntry>> for Via {
+    fn from(v: Vec<ViaEntry>) -> Self {
+        Via(v)
+    }
+}
+
+impl<'a> From<&'a [ViaEntry]> for Via {
+    fn from(v: &'a [ViaEntry]) -> Self {
+        Via(v.to_vec())
+    }
+}
+
+impl Via {
+    /// Creates a new `Via` header builder.
+    pub fn builder() -> ViaBuilder {
+        ViaBuilder(Vec::new())
+    }
+
+    fn header_value(&self) -> String {
+        let mut via = String::new();
+        for entry in &self.0 {
+            if !via.is_empty() {
+                via.push_str(", ");
+            }
+
+            via.push_str(&entry.protocol);
+            via.push(' ');
+            via.push_str(&entry.sent_by);
+
+            for (name, value) in &entry.params {
+                via.push(';');
+                via.push_str(name);
+                if let Some(value) = value {
+                    via.push('=');
+                    via.push_str(value);
+                }
+            }
+        }
+
+        via
+    }
+}
+
+/// Builder for the 'Via' header.
+#[derive(Debug, Clone)]
+pub struct ViaBuilder(Vec<ViaEntry>);
+
+impl ViaBuilder {
+    /// Add the provided entry to the `Via` header.
+    pub fn entry(mut self, entry: ViaEntry) -> Self {
+        self.0.push(entry);
+        self
+    }
+
+    /// Build the `Via` header.
+    pub fn build(self) -> Via {
+        Via(self.0)
+    }
+}
+
+impl super::TypedHeader for Via {
+    fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError> {
+        use super::parser_helpers::{split_once, split_unquoted};
+
+        let headers = headers.as_ref();
+
+        let header = match headers.get(&VIA) {
+            None => return Ok(None),
+            Some(header) => header,
+        };
+
+        let mut entries = Vec::new();
+        for entry in split_unquoted(header.as_str(), ',') {
+            let entry = entry.trim();
+
+            // Protocol and sent-by are separated by spaces or tabs
+            let (protocol, rest) = [' ', '\t']
+                .iter()
+                .filter_map(|d| split_once(entry, *d))
+                .min_by_key(|(protocol, _)| protocol.len())
+                .ok_or_else(|| HeaderParseError::for_header(VIA))?;
+
+            let mut iter = split_unquoted(rest, ';').into_iter();
+            let sent_by = iter.next().unwrap_or_default().trim();
+            if protocol.is_empty() || sent_by.is_empty() {
+                return Err(HeaderParseError::for_header(VIA));
+            }
+
+            let mut params = BTreeMap::new();
+            for param in iter {
+                let param = param.trim();
+                if let Some((name, value)) = split_once(param, '=') {
+                    params.insert(String::from(name.trim()), Some(String::from(value.trim())));
+                } else {
+                    params.insert(String::from(param), None);
+                }
+            }
+
+            entries.push(ViaEntry {
+                protocol: String::from(protocol),
+                sent_by: String::from(sent_by),
+                params,
+            });
+        }
+
+        Ok(Some(Via(entries)))
+    }
+
+    fn insert_into(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+        headers.insert(VIA, self.header_value());
+    }
+}
+
+impl super::TypedAppendableHeader for Via {
+    fn append_to(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+        headers.append(VIA, self.header_value());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_via() {
+        let header = "RTSP/2.0 proxy.example.com;branch=z9hG4bK, RTSP/2.0/TCP 192.0.2.1:554;received=198.51.100.3;hidden";
+        let request = crate::Request::builder(crate::Method::Play, crate::Version::V2_0)
+            .header(VIA, header)
+            .empty();
+
+        let via = request.typed_header::<Via>().unwrap().unwrap();
+
+        let mut proxy = ViaEntry::new("RTSP/2.0", "proxy.example.com");
+        proxy
+            .params
+            .insert(String::from("branch"), Some(String::from("z9hG4bK")));
+
+        let mut other = ViaEntry::new("RTSP/2.0/TCP", "192.0.2.1:554");
+        other
+            .params
+            .insert(String::from("received"), Some(String::from("198.51.100.3")));
+        other.params.insert(String::from("hidden"), None);
+
+        assert_eq!(via, Via::builder().entry(proxy).entry(other).build());
+        assert_eq!(via[0].branch(), Some("z9hG4bK"));
+        assert_eq!(via[0].received(), None);
+        assert_eq!(via[1].received(), Some("198.51.100.3"));
+        assert_eq!(via[1].param("hidden"), None);
+        assert!(via[1].params.contains_key("hidden"));
+
+        let request2 = crate::Request::builder(crate::Method::Play, crate::Version::V2_0)
+            .typed_header(&via)
+            .empty();
+
+        assert_eq!(
+            request2.header(&VIA).unwrap().as_str(),
+            "RTSP/2.0 proxy.example.com;branch=z9hG4bK, RTSP/2.0/TCP 192.0.2.1:554;hidden;received=198.51.100.3"
+        );
+        assert_eq!(request2.typed_header::<Via>().unwrap().unwrap(), via);
+
+        let request = crate::Request::builder(crate::Method::Play, crate::Version::V2_0)
+            .header(VIA, "proxy.example.com")
+            .empty();
+        assert!(request.typed_header::<Via>().is_err());
+
+        let request = crate::Request::builder(crate::Method::Play, crate::Version::V2_0)
+            .header(VIA, "RTSP/2.0\tproxy.example.com ;branch=z9hG4bK")
+            .empty();
+        let via = request.typed_header::<Via>().unwrap().unwrap();
+        assert_eq!(via[0].protocol, "RTSP/2.0");
+        assert_eq!(via[0].sent_by, "proxy.example.com");
+        assert_eq!(via[0].branch(), Some("z9hG4bK"));
+
+        // Separators inside quoted strings are part of the value
+        let header = "RTSP/2.0 proxy.example.com;x-info=\"a, b; c\", RTSP/2.0 192.0.2.1";
+        let request = crate::Request::builder(crate::Method::Play, crate::Version::V2_0)
+            .header(VIA, header)
+            .empty();
+
+        let via = request.typed_header::<Via>().unwrap().unwrap();
+        assert_eq!(via.len(), 2);
+        assert_eq!(via[0].param("x-info"), Some("\"a, b; c\""));
+        assert_eq!(via[1].sent_by, "192.0.2.1");
+
+        let request2 = crate::Request::builder(crate::Method::Play, crate::Version::V2_0)
+            .typed_header(&via)
+            .empty();
+        assert_eq!(request2.header(&VIA).unwrap().as_str(), header);
+    }
+
+    #[test]
+    fn test_via_append() {
+        let mut request = crate::Request::builder(crate::Method::Play, crate::Version::V2_0)
+            .typed_header(
+                &Via::builder()
+                    .entry(ViaEntry::new("RTSP/2.0", "proxy1.example.com"))
+                    .build(),
+            )
+            .empty();
+
+        let mut entry = ViaEntry::new("RTSP/2.0/TCP", "proxy2.example.com:554");
+        entry
+            .params
+            .insert(String::from("branch"), Some(String::from("z9hG4bK")));
+        request.append_typed_header(&Via::builder().entry(entry.clone()).build());
+
+        assert_eq!(
+            request.header(&VIA).unwrap().as_str(),
+            "RTSP/2.0 proxy1.example.com, RTSP/2.0/TCP proxy2.example.com:554;branch=z9hG4bK"
+        );
+
+        let via = request.typed_header::<Via>().unwrap().unwrap();
+        assert_eq!(
+            via,
+            Via::from(vec![ViaEntry::new("RTSP/2.0", "proxy1.example.com"), entry])
+        );
+    }
+}
@@ -0,0 +1,442 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+use std::fmt;
+
+/// `WWW-Authenticate` header ([RFC 7826 section 18.59](https://tools.ietf.org/html/rfc7826#section-18.59)).
+///
+/// Servers often offer multiple challenges in a single header, e.g. `Digest` and `Basic`.
+///
+/// ## Digest challenge
+///
+/// ```rust
+/// use rtsp_types::headers::WwwAuthenticate;
+///
+/// let response = rtsp_types::Response::builder(
+///         rtsp_types::Version::V1_0,
+///         rtsp_types::StatusCode::Unauthorized
+///     )
+///     .header(
+///         rtsp_types::headers::WWW_AUTHENTICATE,
+///         "Digest realm=\"LIVE555 Streaming Media\", nonce=\"a5b5fbcd0f8e3c5c\"",
+///     )
+///     .empty();
+///
+/// let www_authenticate = response.typed_header::<WwwAuthenticate>().unwrap().unwrap();
+/// assert_eq!(www_authenticate[0].scheme, "Digest");
+/// assert_eq!(
+///     www_authenticate[0].realm.as_deref(),
+///     Some("LIVE555 Streaming Media")
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WwwAuthenticate(Vec<Challenge>);
+
+/// Authentication challenge ([RFC 7235 section 2.1](https://tools.ietf.org/html/rfc7235#section-2.1)).
+///
+/// Parameter values are stored without quotes. Challenges in the `token68` form are not
+/// supported.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Challenge {
+    /// Authentication scheme, e.g. `Digest` or `Basic`.
+    pub scheme: String,
+    /// `realm` parameter.
+    pub realm: Option<String>,
+    /// `nonce` parameter.
+    pub nonce: Option<String>,
+    /// `algorithm` parameter.
+    pub algorithm: Option<String>,
+    /// `qop` parameter, e.g. `auth` or `auth,auth-int`.
+    pub qop: Option<String>,
+    /// `opaque` parameter.
+    pub opaque: Option<String>,
+    /// `stale` parameter.
+    pub stale: Option<bool>,
+    /// Extension parameters in the order they appear in the header.
+    pub params: Vec<(String, String)>,
+}
+
+impl Challenge {
+    /// Creates a new challenge for the given scheme without parameters.
+    pub fn new<S: Into<String>>(scheme: S) -> Self {
+        Challenge {
+            scheme: scheme.into(),
+            ..Default::default()
+        }
+    }
+
+    fn set_param(&mut self, name: &str, value: String) {
+        if name.eq_ignore_ascii_case("realm") {
+            self.realm = Some(value);
+        } else if name.eq_ignore_ascii_case("nonce") {
+            self.nonce = Some(value);
+        } else if name.eq_ignore_ascii_case("algorithm") {
+            self.algorithm = Some(value);
+        } else if name.eq_ignore_ascii_case("qop") {
+            self.qop = Some(value);
+        } else if name.eq_ignore_ascii_case("opaque") {
+            self.opaque = Some(value);
+        } else if name.eq_ignore_ascii_case("stale") {
+            self.stale = Some(value.eq_ignore_ascii_case("true"));
+        } else {
+            self.params.push((String::from(name), value));
+        }
+    }
+}
+
+impl fmt::Display for Challenge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.scheme)?;
+
+        let mut first = true;
+        let mut param = |f: &mut fmt::Formatter<'_>, name: &str, value: &str, quote: bool| {
+            f.write_str(if first { " " } else { ", " })?;
+            first = false;
+
+            if quote || !is_token(value) {
+                write!(f, "{}=\"", name)?;
+                for c in value.chars() {
+                    if c == '"' || c == '\\' {
+                        f.write_str("\\")?;
+                    }
+                    write!(f, "{}", c)?;
+                }
+                f.write_str("\"")
+            } else {
+                write!(f, "{}={}", name, value)
+            }
+        };
+
+        // Only `algorithm` and `stale` are tokens, all others must be quoted
+        if let Some(ref realm) = self.realm {
+            param(f, "realm", realm, true)?;
+        }
+        if let Some(ref nonce) = self.nonce {
+            param(f, "nonce", nonce, true)?;
+        }
+        if let Some(ref algorithm) = self.algorithm {
+            param(f, "algorithm", algorithm, false)?;
+        }
+        if let Some(ref qop) = self.qop {
+            param(f, "qop", qop, true)?;
+        }
+        if let Some(ref opaque) = self.opaque {
+            param(f, "opaque", opaque, true)?;
+        }
+        if let Some(stale) = self.stale {
+            param(f, "stale", if stale { "true" } else { "false" }, false)?;
+        }
+        for (name, value) in &self.params {
+            param(f, name, value, false)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn is_token(s: &str) -> bool {
+    use super::parser_helpers::token;
+
+    matches!(token(s.as_bytes()), Ok((rem, t)) if rem.is_empty() && !t.is_empty())
+}
+
+// Parses a token or quoted string parameter value and removes the quotes.
+fn param_value(s: &str) -> Result<String, HeaderParseError> {
+    use super::parser_helpers::quoted_string;
+
+    if !s.starts_with('"') {
+        if is_token(s) {
+            return Ok(String::from(s));
+        } else {
+            return Err(HeaderParseError::for_header(WWW_AUTHENTICATE));
+        }
+    }
+
+    match quoted_string(s.as_bytes()) {
+        Ok(([], _)) => (),
+        _ => return Err(HeaderParseError::for_header(WWW_AUTHENTICATE)),
+    }
+
+    let mut value = String::with_capacity(s.len() - 2);
+    let mut chars = s[1..(s.len() - 1)].chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            value.extend(chars.next());
+        } else {
+            value.push(c);
+        }
+    }
+
+    Ok(value)
+}
+
+impl std::ops::Deref for WwwAuthenticate {
+    type Target = Vec<Challenge>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for WwwAuthenticate {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<Vec<Challenge>> for WwwAuthenticate {
+    fn as_ref(&self) -> &Vec<Challenge> {
+        &self.0
+    }
+}
+
+impl AsMut<Vec<Challenge>> for WwwAuthenticate {
+    fn as_mut(&mut self) -> &mut Vec<Challenge> {
+        &mut self.0
+    }
+}
+
+impl From<Vec<Challenge>> for WwwAuthenticate {
+    fn from(v: Vec<Challenge>) -> Self {
+        WwwAuthenticate(v)
+    }
+}
+
+impl<'a> From<&'a [Challenge]> for WwwAuthenticate {
+    fn from(v: &'a [Challenge]) -> Self {
+        WwwAuthenticate(v.to_vec())
+    }
+}
+
+impl std::iter::FromIterator<Challenge> for WwwAuthenticate {
+    fn from_iter<I: IntoIterator<Item = Challenge>>(iter: I) -> Self {
+        WwwAuthenticate(iter.into_iter().collect())
+    }
+}
+
+impl WwwAuthenticate {
+    fn to_header_value(&self) -> String {
+        let mut value = String::new();
+        for challenge in &self.0 {
+            if !value.is_empty() {
+                value.push_str(", ");
+            }
+            value.push_str(&challenge.to_string());
+        }
+
+        value
+    }
+}
+
+impl super::TypedHeader for WwwAuthenticate {
+    fn from_headers(headers: impl AsRef<Headers>) -> Result<Option<Self>, HeaderParseError> {
+        use super::parser_helpers::{split_once, split_unquoted};
+
+        let headers = headers.as_ref();
+
+        let header = match headers.get(&WWW_AUTHENTICATE) {
+            None => return Ok(None),
+            Some(header) => header,
+        };
+
+        // Challenges and their parameters are both separated by commas. A new challenge starts
+        // with an item that begins with the scheme instead of a `name=value` parameter. There
+        // can be whitespace around the `=` of a parameter, e.g. `realm = "abc"`.
+        let mut challenges = Vec::<Challenge>::new();
+        for item in split_unquoted(header.as_str(), ',') {
+            let item = item.trim();
+            if item.is_empty() {
+                continue;
+            }
+
+            let scheme_param = [' ', '\t']
+                .iter()
+                .filter_map(|d| split_once(item, *d))
+                .min_by_key(|(scheme, _)| scheme.len());
+            let param = match scheme_param {
+                Some((scheme, param))
+                    if !scheme.contains('=') && !param.trim_start().starts_with('=') =>
+                {
+                    if !is_token(scheme) {
+                        return Err(HeaderParseError::for_header(WWW_AUTHENTICATE));
+                    }
+                    challenges.push(Challenge::new(scheme));
+                    param.trim()
+                }
+                None if !item.contains('=') => {
+                    if !is_token(item) {
+                        return Err(HeaderParseError::for_header(WWW_AUTHENTICATE));
+                    }
+                    challenges.push(Challenge::new(item));
+                    continue;
+                }
+                _ => item,
+            };
+
+            let challenge = challenges
+                .last_mut()
+                .ok_or_else(|| HeaderParseError::for_header(WWW_AUTHENTICATE))?;
+            let (name, value) = split_once(param, '=')
+                .ok_or_else(|| HeaderParseError::for_header(WWW_AUTHENTICATE))?;
+            let name = name.trim();
+            if !is_token(name) {
+                return Err(HeaderParseError::for_header(WWW_AUTHENTICATE));
+            }
+
+            challenge.set_param(name, param_value(value.trim())?);
+        }
+
+        if challenges.is_empty() {
+            return Err(HeaderParseError::for_header(WWW_AUTHENTICATE));
+        }
+
+        Ok(Some(WwwAuthenticate(challenges)))
+    }
+
+    fn insert_into(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+        headers.insert(WWW_AUTHENTICATE, self.to_header_value());
+    }
+}
+
+impl super::TypedAppendableHeader for WwwAuthenticate {
+    fn append_to(&self, mut headers: impl AsMut<Headers>) {
+        let headers = headers.as_mut();
+        headers.append(WWW_AUTHENTICATE, self.to_header_value());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_www_authenticate() {
+        let header = "Digest realm=\"LIVE555 Streaming Media\", nonce=\"a5b5fbcd0f8e3c5c\"";
+        let response =
+            crate::Response::builder(crate::Version::V1_0, crate::StatusCode::Unauthorized)
+                .header(WWW_AUTHENTICATE, header)
+                .empty();
+
+        let www_authenticate = response.typed_header::<WwwAuthenticate>().unwrap().unwrap();
+        assert_eq!(
+            *www_authenticate,
+            vec![Challenge {
+                realm: Some(String::from("LIVE555 Streaming Media")),
+                nonce: Some(String::from("a5b5fbcd0f8e3c5c")),
+                ..Challenge::new("Digest")
+            }]
+        );
+
+        let response2 =
+            crate::Response::builder(crate::Version::V1_0, crate::StatusCode::Unauthorized)
+                .typed_header(&www_authenticate)
+                .empty();
+        assert_eq!(response, response2);
+    }
+
+    #[test]
+    fn test_www_authenticate_multiple_challenges() {
+        let header = "Digest realm=\"http-auth@example.org\", qop=\"auth, auth-int\", \
+                      algorithm=SHA-256, nonce=\"7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v\", \
+                      opaque=\"FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS\", stale=TRUE, \
+                      charset=UTF-8, Basic realm=\"a \\\"quoted\\\", realm\",Negotiate";
+        let response =
+            crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Unauthorized)
+                .header(WWW_AUTHENTICATE, header)
+                .empty();
+
+        let www_authenticate = response.typed_header::<WwwAuthenticate>().unwrap().unwrap();
+        assert_eq!(
+            *www_authenticate,
+            vec![
+                Challenge {
+                    realm: Some(String::from("http-auth@example.org")),
+                    nonce: Some(String::from("7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v")),
+                    algorithm: Some(String::from("SHA-256")),
+                    qop: Some(String::from("auth, auth-int")),
+                    opaque: Some(String::from("FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS")),
+                    stale: Some(true),
+                    params: vec![(String::from("charset"), String::from("UTF-8"))],
+                    ..Challenge::new("Digest")
+                },
+                Challenge {
+                    realm: Some(String::from("a \"quoted\", realm")),
+                    ..Challenge::new("Basic")
+                },
+                Challenge::new("Negotiate"),
+            ]
+        );
+
+        let response2 =
+            crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Unauthorized)
+                .typed_header(&www_authenticate)
+                .empty();
+        assert_eq!(
+            response2.header(&WWW_AUTHENTICATE).unwrap().as_str(),
+            "Digest realm=\"http-auth@example.org\", \
+             nonce=\"7ypf/xlj9XXwfDPEoM4URrv/xwf94BcCAzFZH4GiTo0v\", algorithm=SHA-256, \
+             qop=\"auth, auth-int\", opaque=\"FQhe/qaU925kfnzjCev0ciny7QMkPqMAFRtzCUYo5tdS\", \
+             stale=true, charset=UTF-8, Basic realm=\"a \\\"quoted\\\", realm\", Negotiate"
+        );
+        assert_eq!(
+            response2
+                .typed_header::<WwwAuthenticate>()
+                .unwrap()
+                .unwrap(),
+            www_authenticate
+        );
+
+        for header in [
+            "",
+            "realm=\"abc\"",
+            "Digest realm=\"abc",
+            "Digest realm",
+            "Digest realm=a b",
+            "Dig/est realm=\"abc\"",
+            "realm = \"abc\"",
+        ] {
+            let response =
+                crate::Response::builder(crate::Version::V2_0, crate::StatusCode::Unauthorized)
+                    .header(WWW_AUTHENTICATE, header)
+                    .empty();
+            assert!(
+                response.typed_header::<WwwAuthenticate>().is_err(),
+                "{}",
+                header
+            );
+        }
+    }
+
+    #[test]
+    fn test_www_authenticate_whitespace_around_equals() {
+        let header = "Digest realm = \"LIVE555 Streaming Media\", nonce =\"a5b5fbcd0f8e3c5c\", \
+                      stale\t= false, Basic realm= \"abc\"";
+        let response =
+            crate::Response::builder(crate::Version::V1_0, crate::StatusCode::Unauthorized)
+                .header(WWW_AUTHENTICATE, header)
+                .empty();
+
+        let www_authenticate = response.typed_header::<WwwAuthenticate>().unwrap().unwrap();
+        assert_eq!(
+            *www_authenticate,
+            vec![
+                Challenge {
+                    realm: Some(String::from("LIVE555 Streaming Media")),
+                    nonce: Some(String::from("a5b5fbcd0f8e3c5c")),
+                    stale: Some(false),
+                    ..Challenge::new("Digest")
+                },
+                Challenge {
+                    realm: Some(String::from("abc")),
+                    ..Challenge::new("Basic")
+                },
+            ]
+        );
+    }
+}
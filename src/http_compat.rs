@@ -0,0 +1,101 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! Conversions from and to the types of the [`http`](https://docs.rs/http) crate.
+
+use super::*;
+use std::convert::TryFrom;
+
+/// Converts an HTTP method into an RTSP method.
+///
+/// Methods without an RTSP equivalent, e.g. `GET`, are mapped to `Method::Extension`.
+impl From<::http::Method> for Method {
+    fn from(v: ::http::Method) -> Self {
+        Method::from(v.as_str())
+    }
+}
+
+/// Converts an RTSP method into an HTTP method.
+///
+/// This only fails if an extension method is not a valid HTTP method token.
+impl TryFrom<Method> for ::http::Method {
+    type Error = ::http::method::InvalidMethod;
+
+    fn try_from(v: Method) -> Result<Self, Self::Error> {
+        ::http::Method::from_bytes(<&str>::from(&v).as_bytes())
+    }
+}
+
+/// Converts an HTTP status code into an RTSP status code.
+///
+/// Status codes without an RTSP equivalent are mapped to `StatusCode::Extension`.
+impl From<::http::StatusCode> for StatusCode {
+    fn from(v: ::http::StatusCode) -> Self {
+        StatusCode::from(v.as_u16())
+    }
+}
+
+/// Converts an RTSP status code into an HTTP status code.
+///
+/// This only fails if an extension status code is outside the range allowed by HTTP.
+impl TryFrom<StatusCode> for ::http::StatusCode {
+    type Error = ::http::status::InvalidStatusCode;
+
+    fn try_from(v: StatusCode) -> Result<Self, Self::Error> {
+        ::http::StatusCode::from_u16(u16::from(v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_method() {
+        assert_eq!(Method::from(::http::Method::OPTIONS), Method::Options);
+        assert_eq!(
+            Method::from(::http::Method::GET),
+            Method::Extension(String::from("GET"))
+        );
+        assert_eq!(
+            Method::from(::http::Method::from_bytes(b"GET_PARAMETER").unwrap()),
+            Method::GetParameter
+        );
+
+        assert_eq!(
+            ::http::Method::try_from(Method::Options).unwrap(),
+            ::http::Method::OPTIONS
+        );
+        assert_eq!(
+            ::http::Method::try_from(Method::Teardown).unwrap().as_str(),
+            "TEARDOWN"
+        );
+        assert!(::http::Method::try_from(Method::Extension(String::from("FOO BAR"))).is_err());
+    }
+
+    #[test]
+    fn test_status_code() {
+        assert_eq!(StatusCode::from(::http::StatusCode::OK), StatusCode::Ok);
+        assert_eq!(
+            StatusCode::from(::http::StatusCode::NOT_FOUND),
+            StatusCode::NotFound
+        );
+        assert_eq!(
+            StatusCode::from(::http::StatusCode::IM_A_TEAPOT),
+            StatusCode::Extension(418)
+        );
+
+        assert_eq!(
+            ::http::StatusCode::try_from(StatusCode::Ok).unwrap(),
+            ::http::StatusCode::OK
+        );
+        assert_eq!(
+            ::http::StatusCode::try_from(StatusCode::SessionNotFound)
+                .unwrap()
+                .as_u16(),
+            454
+        );
+        assert!(::http::StatusCode::try_from(StatusCode::Extension(1000)).is_err());
+    }
+}
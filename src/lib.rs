@@ -115,13 +115,26 @@
 
 mod message;
 pub use message::*;
+mod channel_map;
+pub use channel_map::{ChannelKind, ChannelMap};
+mod summary;
+pub use summary::MessageSummary;
+mod message_writer;
+pub use message_writer::MessageWriter;
 // TODO: Maybe make this public at a later time
 mod message_ref;
+pub use message_ref::HeaderRef;
 pub(crate) use message_ref::*;
 mod nom_extensions;
 mod parser;
 mod serializer;
 
+#[cfg(feature = "http")]
+mod http_compat;
+
+#[cfg(feature = "auth")]
+pub mod auth;
+
 pub mod headers;
 pub use headers::{HeaderName, HeaderValue, Headers};
 
@@ -148,8 +161,25 @@ pub enum Version {
 ///
 /// These are defined in [RFC 7826 section 17](https://tools.ietf.org/html/rfc7826#section-17)
 /// together with their semantics for the different requests.
+///
+/// New status codes might be added in the future, so matching on a `StatusCode` requires a
+/// wildcard arm:
+///
+/// ```rust
+/// fn is_redirect(status: rtsp_types::StatusCode) -> bool {
+///     match status {
+///         rtsp_types::StatusCode::MovedPermanently
+///         | rtsp_types::StatusCode::Found
+///         | rtsp_types::StatusCode::SeeOther => true,
+///         _ => false,
+///     }
+/// }
+///
+/// assert!(is_redirect(rtsp_types::StatusCode::Found));
+/// ```
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum StatusCode {
     /// Continue
     Continue,
@@ -471,6 +501,78 @@ impl fmt::Display for StatusCode {
     }
 }
 
+/// Parses a `StatusCode` from its three digit numeric value, e.g. `"200"`.
+///
+/// Status codes outside the range `100` to `599` are rejected.
+impl std::str::FromStr for StatusCode {
+    type Err = StatusCodeParseError;
+
+    fn from_str(s: &str) -> Result<Self, StatusCodeParseError> {
+        if s.len() != 3 || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(StatusCodeParseError);
+        }
+
+        let v = s.parse::<u16>().map_err(|_| StatusCodeParseError)?;
+        if !(100..600).contains(&v) {
+            return Err(StatusCodeParseError);
+        }
+
+        Ok(StatusCode::from(v))
+    }
+}
+
+impl<'a> std::convert::TryFrom<&'a str> for StatusCode {
+    type Error = StatusCodeParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, StatusCodeParseError> {
+        s.parse()
+    }
+}
+
+/// Parsing a `StatusCode` from a string failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatusCodeParseError;
+
+impl std::error::Error for StatusCodeParseError {}
+
+impl fmt::Display for StatusCodeParseError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "Invalid status code")
+    }
+}
+
+/// Provider for default reason phrases of `StatusCode`s.
+///
+/// This allows replacing the built-in English reason phrases, e.g. with localized ones, via
+/// [`ResponseBuilder::reason_phrases`](struct.ResponseBuilder.html#method.reason_phrases).
+///
+/// ```rust
+/// struct German;
+///
+/// impl rtsp_types::ReasonPhrases for German {
+///     fn reason_phrase(&self, status: rtsp_types::StatusCode) -> Option<&str> {
+///         match status {
+///             rtsp_types::StatusCode::NotFound => Some("Nicht gefunden"),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// let response = rtsp_types::Response::builder(
+///         rtsp_types::Version::V2_0,
+///         rtsp_types::StatusCode::NotFound,
+///     )
+///     .reason_phrases(&German)
+///     .empty();
+/// assert_eq!(response.reason_phrase(), "Nicht gefunden");
+/// ```
+pub trait ReasonPhrases {
+    /// Returns the reason phrase for `status`.
+    ///
+    /// If `None` is returned then the built-in default reason phrase is used.
+    fn reason_phrase(&self, status: StatusCode) -> Option<&str>;
+}
+
 /// Empty body.
 ///
 /// This can be used as the `Response` or `Request` body in place of a `&[]`
@@ -486,38 +588,261 @@ impl AsRef<[u8]> for Empty {
 }
 
 /// Message parsing error.
+///
+/// New errors might be added in the future, so matching on a `ParseError` requires a wildcard
+/// arm:
+///
+/// ```rust
+/// fn needs_more_data(err: &rtsp_types::ParseError) -> bool {
+///     match err {
+///         rtsp_types::ParseError::Incomplete(_) => true,
+///         _ => false,
+///     }
+/// }
+///
+/// assert!(needs_more_data(&rtsp_types::ParseError::Incomplete(None)));
+/// assert!(!needs_more_data(&rtsp_types::ParseError::LimitExceeded));
+/// ```
 // TODO: Distinguish more errors and provide more information!
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum ParseError {
     /// Parsing failed irrecoverably.
     Error,
     /// Message was not complete and more data is required.
     Incomplete(Option<NonZeroUsize>),
+    /// A limit configured via [`ParseOptions`] was exceeded.
+    LimitExceeded,
+    /// The request URI of an otherwise valid request could not be parsed.
+    InvalidUri(url::ParseError),
+    /// A line in the header block of a request or response is not a valid header.
+    InvalidHeader,
+    /// The body of an otherwise valid message is not valid UTF-8.
+    ///
+    /// Contains the number of bytes of the whole message so that it can be skipped, or parsed
+    /// again without requiring UTF-8, e.g. interleaved binary data.
+    InvalidUtf8(usize),
 }
 
-impl std::error::Error for ParseError {}
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseError::InvalidUri(ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 impl std::fmt::Display for ParseError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
         match *self {
             ParseError::Error => write!(f, "Parse Error"),
             ParseError::Incomplete(needed) => write!(f, "Incomplete message: {:?}", needed),
+            ParseError::LimitExceeded => write!(f, "Limit exceeded"),
+            ParseError::InvalidUri(err) => write!(f, "Invalid request URI: {}", err),
+            ParseError::InvalidHeader => write!(f, "Invalid header"),
+            ParseError::InvalidUtf8(len) => write!(f, "Invalid UTF-8 body in {} bytes", len),
+        }
+    }
+}
+
+/// Semantic message validation error.
+///
+/// See [`Message::validate`](enum.Message.html#method.validate).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationError {
+    /// The required `CSeq` header is missing.
+    MissingCSeq,
+    /// A required header could not be parsed.
+    InvalidHeader(headers::HeaderParseError),
+    /// The `Content-Length` header does not match the length of the body.
+    ContentLengthMismatch,
+}
+
+impl std::error::Error for ValidationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ValidationError::MissingCSeq | ValidationError::ContentLengthMismatch => None,
+            ValidationError::InvalidHeader(ref err) => Some(err),
+        }
+    }
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+        match *self {
+            ValidationError::MissingCSeq => write!(f, "Missing CSeq header"),
+            ValidationError::InvalidHeader(ref err) => write!(f, "Invalid header: {err}"),
+            ValidationError::ContentLengthMismatch => {
+                write!(f, "Content-Length does not match body length")
+            }
         }
     }
 }
 
+/// Options for parsing messages.
+///
+/// See [`Message::parse_with_options`](enum.Message.html#method.parse_with_options).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    pub(crate) max_start_line_len: usize,
+    pub(crate) preserve_raw_headers: bool,
+    pub(crate) sdp_body_without_content_length: bool,
+    pub(crate) accept_lf_line_endings: bool,
+}
+
+impl ParseOptions {
+    /// Default maximum length of the request or status line.
+    pub const DEFAULT_MAX_START_LINE_LEN: usize = 8192;
+
+    /// Creates new parse options with the default values.
+    pub fn new() -> Self {
+        ParseOptions {
+            max_start_line_len: Self::DEFAULT_MAX_START_LINE_LEN,
+            preserve_raw_headers: false,
+            sdp_body_without_content_length: false,
+            accept_lf_line_endings: false,
+        }
+    }
+
+    /// Set the maximum length of the request or status line, excluding the CRLF.
+    ///
+    /// If the line is longer then parsing fails with
+    /// [`ParseError::LimitExceeded`](enum.ParseError.html#variant.LimitExceeded), even if the
+    /// line is not complete yet.
+    pub fn max_start_line_len(mut self, len: usize) -> Self {
+        self.max_start_line_len = len;
+        self
+    }
+
+    /// Keep the raw header values as received in addition to the normalized values.
+    ///
+    /// This is disabled by default. See
+    /// [`Headers::raw_value`](headers/struct.Headers.html#method.raw_value).
+    pub fn preserve_raw_headers(mut self, preserve: bool) -> Self {
+        self.preserve_raw_headers = preserve;
+        self
+    }
+
+    /// Accept SDP bodies of messages without `Content-Length` header.
+    ///
+    /// **This is not conformant to the RTSP specification**, which requires a `Content-Length`
+    /// header for every message with a body. Some implementations omit it for the SDP body of
+    /// e.g. `ANNOUNCE` requests and rely on the body ending at the next message.
+    ///
+    /// If enabled, requests and responses with an `application/sdp` `Content-Type` but without
+    /// `Content-Length` header take all following lines as body until a line that looks like the
+    /// start of a new message or an empty line. If no such line is available yet then the whole
+    /// remaining data is taken as body as long as it ends with a complete line, otherwise
+    /// parsing fails with [`ParseError::Incomplete`](enum.ParseError.html#variant.Incomplete).
+    ///
+    /// This is only a heuristic and can misdetect the end of the body, e.g. if the SDP is split
+    /// over multiple reads. It is disabled by default.
+    pub fn sdp_body_without_content_length(mut self, enable: bool) -> Self {
+        self.sdp_body_without_content_length = enable;
+        self
+    }
+
+    /// Accept bare LF (`\n`) line endings in addition to CRLF.
+    ///
+    /// **This is not conformant to the RTSP specification**, which requires CRLF line endings.
+    /// Some implementations only send LF, and this also allows parsing messages that were
+    /// written with [`LineEnding::Lf`]. The body is not affected.
+    ///
+    /// This is disabled by default.
+    pub fn accept_lf_line_endings(mut self, accept: bool) -> Self {
+        self.accept_lf_line_endings = accept;
+        self
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Line ending for serializing messages.
+///
+/// See [`WriteOptions::line_ending`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineEnding {
+    /// CRLF (`\r\n`) as required by the RTSP specification.
+    #[default]
+    CrLf,
+    /// LF (`\n`).
+    ///
+    /// **This is not conformant to the RTSP specification** and should only be used for
+    /// interoperability testing, e.g. against servers that only handle LF line endings. Such
+    /// messages can only be parsed with [`ParseOptions::accept_lf_line_endings`].
+    Lf,
+}
+
+impl LineEnding {
+    /// The line ending as string.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LineEnding::CrLf => "\r\n",
+            LineEnding::Lf => "\n",
+        }
+    }
+}
+
+/// Options for serializing messages.
+///
+/// See [`Message::write_with_options`](enum.Message.html#method.write_with_options).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WriteOptions {
+    pub(crate) line_ending: LineEnding,
+    pub(crate) canonical_header_order: bool,
+}
+
+impl WriteOptions {
+    /// Creates new write options with the default values.
+    pub fn new() -> Self {
+        WriteOptions::default()
+    }
+
+    /// Set the line ending used after the request or status line and the headers.
+    ///
+    /// Defaults to [`LineEnding::CrLf`]. The body is written unchanged.
+    pub fn line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    /// Write the headers in canonical RTSP order.
+    ///
+    /// If enabled, the `CSeq` header is written first and all other headers case-insensitive
+    /// alphabetically afterwards, see
+    /// [`Headers::sorted_canonical`](headers/struct.Headers.html#method.sorted_canonical). This
+    /// gives deterministic output for servers that expect `CSeq` early in the header block, even
+    /// for borrowed messages that keep the headers in the order they were received.
+    ///
+    /// Defaults to `false`.
+    pub fn canonical_header_order(mut self, canonical_header_order: bool) -> Self {
+        self.canonical_header_order = canonical_header_order;
+        self
+    }
+}
+
 /// Serialization write error.
 // TODO: Distinguish more errors and provide more information!
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum WriteError {
     /// Error reported by the underlying IO type
     IoError(std::io::Error),
+    /// The provided buffer is too small to hold the serialized message
+    BufferTooSmall,
 }
 
 impl std::error::Error for WriteError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             WriteError::IoError(ref err) => Some(err),
+            WriteError::BufferTooSmall => None,
         }
     }
 }
@@ -526,6 +851,7 @@ impl std::fmt::Display for WriteError {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
         match *self {
             WriteError::IoError(ref error) => write!(f, "Write IO error: {error}"),
+            WriteError::BufferTooSmall => write!(f, "Buffer too small"),
         }
     }
 }
@@ -535,3 +861,21 @@ impl From<std::io::Error> for WriteError {
         WriteError::IoError(v)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_code_from_str() {
+        use std::convert::TryFrom;
+
+        assert_eq!("200".parse::<StatusCode>(), Ok(StatusCode::Ok));
+        assert_eq!(StatusCode::try_from("454"), Ok(StatusCode::SessionNotFound));
+        assert_eq!(StatusCode::try_from("599"), Ok(StatusCode::Extension(599)));
+
+        for s in ["abc", "", "20", "2000", "+20", " 200", "099", "600", "999"] {
+            assert_eq!(StatusCode::try_from(s), Err(StatusCodeParseError), "{}", s);
+        }
+    }
+}
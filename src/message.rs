@@ -24,6 +24,166 @@ pub enum Message<Body> {
     Data(Data<Body>),
 }
 
+/// Kind of an RTSP message frame.
+///
+/// Only data frames use the RTSP-over-TCP interleaved framing. Requests and responses are always
+/// sent as plain RTSP messages, even if they are mixed with data frames on the same connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FrameKind {
+    /// Request message
+    Request,
+    /// Response message
+    Response,
+    /// Interleaved data message
+    Data,
+    /// Only empty lines (CRLF) without a following complete message
+    ///
+    /// Some implementations send these as keep-alive. This is only returned by
+    /// [`Message::parse_frame`] and never corresponds to a message.
+    KeepAlive,
+}
+
+/// Information about a parsed message frame.
+///
+/// This is returned by [`Message::parse_frame`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Frame {
+    /// Kind of the frame.
+    pub kind: FrameKind,
+    /// Byte range of the whole frame in the input data.
+    pub span: std::ops::Range<usize>,
+    /// Byte range of the body in the input data.
+    pub body: std::ops::Range<usize>,
+    /// Number of empty lines (CRLF) before the frame.
+    ///
+    /// Some implementations send these as keep-alive. They are consumed together with the frame,
+    /// or form a [`FrameKind::KeepAlive`] frame on their own if no complete message follows.
+    pub keep_alives: usize,
+}
+
+/// Start line and headers of a request or response.
+///
+/// This is returned by [`Message::parse_head`] and has an empty body.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Head {
+    /// Request head
+    Request(Request<Empty>),
+    /// Response head
+    Response(Response<Empty>),
+}
+
+impl Head {
+    /// Gets a typed header from the head.
+    pub fn typed_header<H: TypedHeader>(&self) -> Result<Option<H>, headers::HeaderParseError> {
+        H::from_headers(self)
+    }
+
+    /// Gets the value of the `Content-Length` header.
+    ///
+    /// Returns `None` if the header does not exist or is invalid.
+    pub fn content_length(&self) -> Option<u64> {
+        match self {
+            Head::Request(request) => request.content_length(),
+            Head::Response(response) => response.content_length(),
+        }
+    }
+
+    /// Gets the value of the `Content-Type` header.
+    ///
+    /// Returns `None` if the header does not exist or is invalid.
+    pub fn content_type(&self) -> Option<headers::ContentType> {
+        match self {
+            Head::Request(request) => request.content_type(),
+            Head::Response(response) => response.content_type(),
+        }
+    }
+}
+
+impl AsRef<Headers> for Head {
+    fn as_ref(&self) -> &Headers {
+        match self {
+            Head::Request(request) => request.as_ref(),
+            Head::Response(response) => response.as_ref(),
+        }
+    }
+}
+
+impl<Body> Message<Body> {
+    /// Checks if the message is semantically valid.
+    ///
+    /// See [`Request::validate`](struct.Request.html#method.validate) and
+    /// [`Response::validate`](struct.Response.html#method.validate). Data messages are always
+    /// valid.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            Message::Request(request) => request.validate(),
+            Message::Response(response) => response.validate(),
+            Message::Data(_) => Ok(()),
+        }
+    }
+
+    /// Kind of this message.
+    pub fn frame_kind(&self) -> FrameKind {
+        match self {
+            Message::Request(_) => FrameKind::Request,
+            Message::Response(_) => FrameKind::Response,
+            Message::Data(_) => FrameKind::Data,
+        }
+    }
+
+    /// Returns `true` if this is a request message.
+    pub fn is_request(&self) -> bool {
+        matches!(self, Message::Request(_))
+    }
+
+    /// Returns `true` if this is a response message.
+    pub fn is_response(&self) -> bool {
+        matches!(self, Message::Response(_))
+    }
+
+    /// Returns `true` if this is a data message.
+    pub fn is_data(&self) -> bool {
+        matches!(self, Message::Data(_))
+    }
+
+    /// Summarizes the message and all typed headers that can be recognized.
+    ///
+    /// Headers that fail to parse are collected in
+    /// [`MessageSummary::errors`](struct.MessageSummary.html#structfield.errors) instead of
+    /// failing. This is mostly useful for debugging and logging.
+    pub fn describe(&self) -> MessageSummary {
+        MessageSummary::new(self)
+    }
+
+    // Body API
+    /// Convert the message into its body.
+    pub fn into_body(self) -> Body {
+        match self {
+            Message::Request(request) => request.into_body(),
+            Message::Response(response) => response.into_body(),
+            Message::Data(data) => data.into_body(),
+        }
+    }
+
+    /// Modify the body of the message with a closure.
+    ///
+    /// For requests and responses this replaces the `Content-Length` header of the message with
+    /// the length of the new body, see [`Request::map_body`](struct.Request.html#method.map_body).
+    pub fn map_body<NewBody: AsRef<[u8]>, F: FnOnce(Body) -> NewBody>(
+        self,
+        func: F,
+    ) -> Message<NewBody> {
+        match self {
+            Message::Request(request) => Message::Request(request.map_body(func)),
+            Message::Response(response) => Message::Response(response.map_body(func)),
+            Message::Data(data) => Message::Data(data.map_body(func)),
+        }
+    }
+}
+
 impl<Body> From<Request<Body>> for Message<Body> {
     fn from(v: Request<Body>) -> Self {
         Message::Request(v)
@@ -43,18 +203,11 @@ impl<Body> From<Data<Body>> for Message<Body> {
 }
 
 impl<Body: AsRef<[u8]>> Message<Body> {
-    pub(crate) fn borrow(&self) -> MessageRef {
-        match self {
-            Message::Request(request) => MessageRef::Request(request.borrow()),
-            Message::Response(response) => MessageRef::Response(response.borrow()),
-            Message::Data(data) => MessageRef::Data(data.borrow()),
-        }
-    }
-
     /// Serialize the message to any `std::io::Write`.
     ///
     /// Resuming writing after `std::io::ErrorKind::WouldBlock` is not supported. Any previously
-    /// written data will have to be discarded for resuming.
+    /// written data will have to be discarded for resuming. Use
+    /// [`MessageWriter`](struct.MessageWriter.html) for resumable writing instead.
     ///
     /// ## Serializing an RTSP message
     ///
@@ -82,12 +235,107 @@ impl<Body: AsRef<[u8]>> Message<Body> {
     ///  );
     /// ```
     pub fn write<'b, W: std::io::Write + 'b>(&self, w: &'b mut W) -> Result<(), WriteError> {
-        self.borrow().write(w)
+        self.write_with_options(w, &WriteOptions::default())
+    }
+
+    /// Serialize the message to any `std::io::Write` with the given options.
+    ///
+    /// This works the same as [`Message::write`](enum.Message.html#method.write) but allows
+    /// e.g. selecting a different line ending.
+    ///
+    /// ## Serializing with LF line endings
+    ///
+    /// ```rust
+    /// let request: rtsp_types::Message<Vec<u8>> = rtsp_types::Request::builder(
+    ///         rtsp_types::Method::Options,
+    ///         rtsp_types::Version::V2_0
+    ///     )
+    ///     .header(rtsp_types::headers::CSEQ, "1")
+    ///     .build(Vec::new())
+    ///     .into();
+    ///
+    /// let options = rtsp_types::WriteOptions::new().line_ending(rtsp_types::LineEnding::Lf);
+    /// let mut data = Vec::new();
+    /// request.write_with_options(&mut data, &options).expect("Failed to serialize request");
+    ///
+    /// assert_eq!(data, b"OPTIONS * RTSP/2.0\nCSeq: 1\n\n");
+    /// ```
+    pub fn write_with_options<'b, W: std::io::Write + 'b>(
+        &self,
+        w: &'b mut W,
+        options: &WriteOptions,
+    ) -> Result<(), WriteError> {
+        match self {
+            Message::Request(request) => request.write_with_options(w, options),
+            Message::Response(response) => response.write_with_options(w, options),
+            Message::Data(data) => data.write(w),
+        }
     }
 
     /// Calculate the number of bytes needed to serialize the message.
+    ///
+    /// This is exactly the number of bytes written by [`write`](#method.write).
     pub fn write_len(&self) -> u64 {
-        self.borrow().write_len()
+        self.write_len_with_options(&WriteOptions::default())
+    }
+
+    /// Calculate the number of bytes needed to serialize the message with the given options.
+    ///
+    /// This is exactly the number of bytes written by
+    /// [`write_with_options`](#method.write_with_options).
+    pub fn write_len_with_options(&self, options: &WriteOptions) -> u64 {
+        match self {
+            Message::Request(request) => request.write_len_with_options(options),
+            Message::Response(response) => response.write_len_with_options(options),
+            Message::Data(data) => data.write_len(),
+        }
+    }
+
+    /// Serialize the message into a caller-provided buffer.
+    ///
+    /// Returns the number of bytes written, or [`WriteError::BufferTooSmall`] if the message does
+    /// not fit into `buf`. In that case the contents of `buf` are left untouched. See
+    /// [`Message::write_len`] for the required size.
+    pub fn write_to_slice(&self, buf: &mut [u8]) -> Result<usize, WriteError> {
+        self.write_to_slice_with_options(buf, &WriteOptions::default())
+    }
+
+    /// Serialize the message into a caller-provided buffer with the given options.
+    ///
+    /// This works the same as [`Message::write_to_slice`] but allows e.g. selecting a different
+    /// line ending. See [`Message::write_len_with_options`] for the required size.
+    pub fn write_to_slice_with_options(
+        &self,
+        buf: &mut [u8],
+        options: &WriteOptions,
+    ) -> Result<usize, WriteError> {
+        let len = self.write_len_with_options(options);
+        if len > buf.len() as u64 {
+            return Err(WriteError::BufferTooSmall);
+        }
+
+        match self {
+            Message::Request(request) => {
+                serializer::write_to_slice(request.serializer(options), buf)
+            }
+            Message::Response(response) => {
+                serializer::write_to_slice(response.serializer(options), buf)
+            }
+            Message::Data(data) => serializer::write_to_slice(serializer::data(data.borrow()), buf),
+        }
+    }
+
+    /// Check if the message fits into the 16 bit length field of an interleaved data message.
+    ///
+    /// For data messages this checks the length of the body, for requests and responses the
+    /// length of the whole serialized message.
+    pub fn fits_interleaved(&self) -> bool {
+        let len = match self {
+            Message::Data(data) => data.len() as u64,
+            _ => self.write_len(),
+        };
+
+        len <= u16::MAX as u64
     }
 }
 
@@ -108,6 +356,16 @@ impl<'a, T: From<&'a [u8]>> Message<T> {
     /// then the message can't be parsed and the caller can try skipping over some data until
     /// parsing succeeds again.
     ///
+    /// If a line in the header block of a request or response is not a valid header then this
+    /// fails with [`ParseError::InvalidHeader`](enum.ParseError.html#variant.InvalidHeader).
+    ///
+    /// If the request URI of a request can't be parsed then this fails with
+    /// [`ParseError::InvalidUri`](enum.ParseError.html#variant.InvalidUri), which contains the
+    /// reason why the URI was rejected.
+    ///
+    /// The default [`ParseOptions`](struct.ParseOptions.html) are used for limiting the size of
+    /// the message, see [`Message::parse_with_options`](enum.Message.html#method.parse_with_options).
+    ///
     /// ## Parsing an RTSP message
     ///
     /// ```rust
@@ -132,7 +390,248 @@ impl<'a, T: From<&'a [u8]>> Message<T> {
         let buf = buf.as_ref();
         let (msg, consumed) = MessageRef::parse(buf)?;
 
-        Ok((msg.to_owned()?, consumed))
+        Ok((msg.to_owned(&ParseOptions::default())?, consumed))
+    }
+
+    /// Try parse a message from a `&[u8]` with the given options and also return how many bytes
+    /// were consumed.
+    ///
+    /// This works the same as [`Message::parse`](enum.Message.html#method.parse) but fails with
+    /// [`ParseError::LimitExceeded`](enum.ParseError.html#variant.LimitExceeded) if the message
+    /// exceeds any of the limits of `options`.
+    pub fn parse_with_options<B: AsRef<[u8]> + 'a + ?Sized>(
+        buf: &'a B,
+        options: &ParseOptions,
+    ) -> Result<(Self, usize), ParseError> {
+        let buf = buf.as_ref();
+        let (msg, consumed) = MessageRef::parse_with_options(buf, options)?;
+
+        Ok((msg.to_owned(options)?, consumed))
+    }
+
+    /// Try parse a message from a `&[u8]` and also return the remaining, unparsed data.
+    ///
+    /// This works the same as [`Message::parse`](enum.Message.html#method.parse) but instead of
+    /// the number of consumed bytes it returns the data after the message, which can directly be
+    /// passed to the next call when parsing multiple messages.
+    ///
+    /// ## Parsing multiple RTSP messages
+    ///
+    /// ```rust
+    /// let mut data: &[u8] = b"$\x01\x00\x04abcdOPTIONS * RTSP/2.0\r\nCSeq: 1\r\n\r\n";
+    ///
+    /// let mut messages = Vec::new();
+    /// while !data.is_empty() {
+    ///     let (message, remaining) = rtsp_types::Message::<Vec<u8>>::parse_remaining(data)
+    ///         .expect("Failed to parse data");
+    ///     messages.push(message);
+    ///     data = remaining;
+    /// }
+    ///
+    /// assert_eq!(messages.len(), 2);
+    /// ```
+    pub fn parse_remaining<B: AsRef<[u8]> + 'a + ?Sized>(
+        buf: &'a B,
+    ) -> Result<(Self, &'a [u8]), ParseError> {
+        let buf = buf.as_ref();
+        let (msg, consumed) = Self::parse(buf)?;
+
+        Ok((msg, &buf[consumed..]))
+    }
+
+    /// Try parse a message from a `&[u8]` and also return information about the parsed frame.
+    ///
+    /// This works the same as [`Message::parse`](enum.Message.html#method.parse) but instead of
+    /// only the number of consumed bytes it returns the kind of the frame, the byte ranges of
+    /// the whole frame and its body in the input data and the number of keep-alive CRLFs before
+    /// the frame. The end of the frame's span is the number of bytes that were consumed.
+    ///
+    /// Keep-alive CRLFs that are not followed by a complete message are returned on their own as
+    /// a [`FrameKind::KeepAlive`] frame without message. This allows e.g. a server to reset its
+    /// idle timer as soon as a keep-alive arrives.
+    ///
+    /// ## Demuxing interleaved data and RTSP messages
+    ///
+    /// ```rust
+    /// let data = b"$\x01\x00\x04abcdOPTIONS * RTSP/2.0\r\nCSeq: 1\r\n\r\n\r\n";
+    ///
+    /// let (_, frame) = rtsp_types::Message::<Vec<u8>>::parse_frame(data).unwrap();
+    /// assert_eq!(frame.kind, rtsp_types::FrameKind::Data);
+    /// assert_eq!(&data[frame.body.clone()], b"abcd");
+    ///
+    /// let data = &data[frame.span.end..];
+    /// let (_, frame) = rtsp_types::Message::<Vec<u8>>::parse_frame(data).unwrap();
+    /// assert_eq!(frame.kind, rtsp_types::FrameKind::Request);
+    ///
+    /// let data = &data[frame.span.end..];
+    /// let (message, frame) = rtsp_types::Message::<Vec<u8>>::parse_frame(data).unwrap();
+    /// assert_eq!(frame.kind, rtsp_types::FrameKind::KeepAlive);
+    /// assert!(message.is_none());
+    /// assert_eq!(frame.span.end, data.len());
+    /// ```
+    pub fn parse_frame<B: AsRef<[u8]> + 'a + ?Sized>(
+        buf: &'a B,
+    ) -> Result<(Option<Self>, Frame), ParseError> {
+        let buf = buf.as_ref();
+
+        let keep_alives = buf
+            .chunks_exact(2)
+            .take_while(|chunk| *chunk == b"\r\n")
+            .count();
+
+        let (msg, consumed) = match MessageRef::parse(buf) {
+            Err(ParseError::Incomplete(_)) if keep_alives > 0 => {
+                let len = 2 * keep_alives;
+                let frame = Frame {
+                    kind: FrameKind::KeepAlive,
+                    span: 0..len,
+                    body: len..len,
+                    keep_alives,
+                };
+
+                return Ok((None, frame));
+            }
+            res => res?,
+        };
+
+        let (kind, body_len) = match msg {
+            MessageRef::Request(ref request) => (FrameKind::Request, request.body.len()),
+            MessageRef::Response(ref response) => (FrameKind::Response, response.body.len()),
+            MessageRef::Data(ref data) => (FrameKind::Data, data.body.len()),
+        };
+
+        let frame = Frame {
+            kind,
+            span: (2 * keep_alives)..consumed,
+            body: (consumed - body_len)..consumed,
+            keep_alives,
+        };
+
+        Ok((Some(msg.to_owned(&ParseOptions::default())?), frame))
+    }
+}
+
+impl Message<Empty> {
+    /// Try parse only the start line and headers of a request or response from a `&[u8]` and
+    /// also return the offset where the body begins.
+    ///
+    /// In contrast to [`Message::parse`](enum.Message.html#method.parse) the body does not have
+    /// to be available yet. The caller can inspect e.g. the `Content-Type` and `Content-Length`
+    /// headers of the head and then decide how to handle the body. Interleaved data messages have
+    /// no head and fail with [`ParseError::Error`](enum.ParseError.html#variant.Error).
+    ///
+    /// ## Deferring the body of a message
+    ///
+    /// ```rust
+    /// let data = b"ANNOUNCE rtsp://example.com/media.mp4 RTSP/1.0\r\n\
+    ///              CSeq: 7\r\n\
+    ///              Content-Type: application/sdp\r\n\
+    ///              Content-Length: 1024\r\n\
+    ///              \r\n\
+    ///              v=0\r\n";
+    ///
+    /// let (head, body_offset) = rtsp_types::Message::parse_head(data).unwrap();
+    /// assert_eq!(head.content_length(), Some(1024));
+    /// assert_eq!(&data[body_offset..], b"v=0\r\n");
+    /// ```
+    pub fn parse_head<B: AsRef<[u8]> + ?Sized>(buf: &B) -> Result<(Head, usize), ParseError> {
+        Self::parse_head_with_options(buf, &ParseOptions::default())
+    }
+
+    /// Try parse only the start line and headers of a request or response from a `&[u8]` with
+    /// the given options and also return the offset where the body begins.
+    ///
+    /// This works the same as [`Message::parse_head`](enum.Message.html#method.parse_head).
+    /// [`ParseOptions::sdp_body_without_content_length`] has no effect as the body is not
+    /// parsed.
+    pub fn parse_head_with_options<B: AsRef<[u8]> + ?Sized>(
+        buf: &B,
+        options: &ParseOptions,
+    ) -> Result<(Head, usize), ParseError> {
+        let buf = buf.as_ref();
+        let (head, consumed) = MessageRef::parse_head_with_options(buf, options)?;
+
+        // Keep the headers as is, especially the `Content-Length` header
+        let head = match head.to_owned::<&[u8]>(options)? {
+            Message::Request(request) => Head::Request(Request {
+                method: request.method,
+                request_uri: request.request_uri,
+                version: request.version,
+                headers: request.headers,
+                body: Empty,
+            }),
+            Message::Response(response) => Head::Response(Response {
+                version: response.version,
+                status: response.status,
+                reason_phrase: response.reason_phrase,
+                headers: response.headers,
+                body: Empty,
+            }),
+            Message::Data(_) => unreachable!(),
+        };
+
+        Ok((head, consumed))
+    }
+}
+
+impl<'a> Message<&'a str> {
+    /// Try parse a message with a UTF-8 body from a `&[u8]` and also return how many bytes were
+    /// consumed.
+    ///
+    /// This works the same as [`Message::parse`](enum.Message.html#method.parse) but borrows the
+    /// body as a `&str`, which is convenient for text bodies like `text/parameters` or SDP. If
+    /// the body is not valid UTF-8, e.g. for interleaved binary data, then
+    /// [`ParseError::InvalidUtf8`](enum.ParseError.html#variant.InvalidUtf8) with the length of
+    /// the message is returned so that the message can be skipped or parsed again with
+    /// [`Message::parse`](enum.Message.html#method.parse).
+    ///
+    /// ## Handling interleaved binary data
+    ///
+    /// ```rust
+    /// let data = b"$\x00\x00\x02\xff\xfeRTSP/2.0 200 OK\r\nCSeq: 1\r\n\r\n";
+    ///
+    /// let consumed = match rtsp_types::Message::<&str>::parse_utf8(data) {
+    ///     Err(rtsp_types::ParseError::InvalidUtf8(consumed)) => {
+    ///         let (message, _) = rtsp_types::Message::<&[u8]>::parse(&data[..consumed]).unwrap();
+    ///         assert!(message.is_data());
+    ///         consumed
+    ///     }
+    ///     _ => unreachable!(),
+    /// };
+    ///
+    /// let (message, _) = rtsp_types::Message::<&str>::parse_utf8(&data[consumed..]).unwrap();
+    /// assert!(message.is_response());
+    /// ```
+    pub fn parse_utf8<B: AsRef<[u8]> + 'a + ?Sized>(
+        buf: &'a B,
+    ) -> Result<(Self, usize), ParseError> {
+        let (msg, consumed) = Message::<&'a [u8]>::parse(buf)?;
+        let from_utf8 = |body: &'a [u8]| {
+            std::str::from_utf8(body).map_err(|_| ParseError::InvalidUtf8(consumed))
+        };
+
+        let msg = match msg {
+            Message::Request(request) => Message::Request(Request {
+                method: request.method,
+                request_uri: request.request_uri,
+                version: request.version,
+                headers: request.headers,
+                body: from_utf8(request.body)?,
+            }),
+            Message::Response(response) => Message::Response(Response {
+                version: response.version,
+                status: response.status,
+                reason_phrase: response.reason_phrase,
+                headers: response.headers,
+                body: from_utf8(response.body)?,
+            }),
+            Message::Data(data) => Message::Data(Data {
+                channel_id: data.channel_id,
+                body: from_utf8(data.body)?,
+            }),
+        };
+
+        Ok((msg, consumed))
     }
 }
 
@@ -140,8 +639,11 @@ impl<'a, T: From<&'a [u8]>> Message<T> {
 ///
 /// See [RFC 7826 section 13](https://tools.ietf.org/html/rfc7826#section-13) for the details about
 /// each method.
+///
+/// New methods might be added in the future, so matching on a `Method` requires a wildcard arm.
 #[derive(Debug, PartialEq, Eq, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
 pub enum Method {
     /// Describe
     Describe,
@@ -172,7 +674,7 @@ pub enum Method {
 }
 
 impl Method {
-    pub(crate) fn borrow(&self) -> MethodRef {
+    pub(crate) fn borrow(&self) -> MethodRef<'_> {
         match self {
             Method::Describe => MethodRef::Describe,
             Method::GetParameter => MethodRef::GetParameter,
@@ -189,6 +691,33 @@ impl Method {
             Method::Extension(s) => MethodRef::Extension(s),
         }
     }
+
+    /// Parses a method from a `&str`, ignoring the case of known methods.
+    ///
+    /// Methods are case-sensitive but some servers list e.g. `Get_Parameter` in the `Public` or
+    /// `Allow` headers. Unknown methods are returned as [`Method::Extension`] unchanged.
+    pub fn parse_lenient(v: &str) -> Self {
+        const METHODS: [Method; 12] = [
+            Method::Describe,
+            Method::GetParameter,
+            Method::Options,
+            Method::Pause,
+            Method::Play,
+            Method::PlayNotify,
+            Method::Redirect,
+            Method::Setup,
+            Method::SetParameter,
+            Method::Announce,
+            Method::Record,
+            Method::Teardown,
+        ];
+
+        METHODS
+            .iter()
+            .find(|method| <&str>::from(*method).eq_ignore_ascii_case(v))
+            .cloned()
+            .unwrap_or_else(|| Method::Extension(String::from(v)))
+    }
 }
 
 /// Parses a method from a `&str`.
@@ -285,45 +814,91 @@ impl Request<Empty> {
 }
 
 impl<Body> Request<Body> {
-    pub(crate) fn borrow(&self) -> RequestRef
+    // Serializes the headers directly from the map without collecting them first.
+    fn serializer<'a, W: std::io::Write + 'a>(
+        &'a self,
+        options: &WriteOptions,
+    ) -> impl cookie_factory::SerializeFn<W> + 'a
     where
         Body: AsRef<[u8]>,
     {
-        let headers = self
-            .headers
-            .iter()
-            .map(|(name, value)| HeaderRef {
-                name: name.as_str(),
-                value: value.as_str(),
-            })
-            .collect();
-
-        RequestRef {
-            method: self.method.borrow(),
-            request_uri: self.request_uri.as_ref().map(|u| u.as_str()),
-            version: self.version,
-            headers,
-            body: self.body.as_ref(),
-        }
+        serializer::request(
+            RequestLine {
+                method: self.method.borrow(),
+                request_uri: self.request_uri.as_ref().map(|u| u.as_str()),
+                version: self.version,
+            },
+            self.headers
+                .iter_ordered(options.canonical_header_order)
+                .map(|(name, value)| HeaderRef {
+                    name: name.as_str(),
+                    value: value.as_str(),
+                }),
+            self.body.as_ref(),
+            options.line_ending,
+        )
     }
 
     /// Serialize the request to any `std::io::Write`.
     ///
     /// Resuming writing after `std::io::ErrorKind::WouldBlock` is not supported. Any previously
-    /// written data will have to be discarded for resuming.
+    /// written data will have to be discarded for resuming. Use
+    /// [`MessageWriter`](struct.MessageWriter.html) for resumable writing instead.
     pub fn write<'b, W: std::io::Write + 'b>(&self, w: &'b mut W) -> Result<(), WriteError>
     where
         Body: AsRef<[u8]>,
     {
-        self.borrow().write(w)
+        self.write_with_options(w, &WriteOptions::default())
+    }
+
+    /// Serialize the request to any `std::io::Write` with the given options.
+    ///
+    /// See [`Message::write_with_options`](enum.Message.html#method.write_with_options).
+    pub fn write_with_options<'b, W: std::io::Write + 'b>(
+        &self,
+        w: &'b mut W,
+        options: &WriteOptions,
+    ) -> Result<(), WriteError>
+    where
+        Body: AsRef<[u8]>,
+    {
+        serializer::write(self.serializer(options), w)
     }
 
     /// Calculate the number of bytes needed to serialize the request.
+    ///
+    /// This is exactly the number of bytes written by [`write`](#method.write).
     pub fn write_len(&self) -> u64
     where
         Body: AsRef<[u8]>,
     {
-        self.borrow().write_len()
+        self.write_len_with_options(&WriteOptions::default())
+    }
+
+    /// Calculate the number of bytes needed to serialize the request with the given options.
+    ///
+    /// This is exactly the number of bytes written by
+    /// [`write_with_options`](#method.write_with_options).
+    pub fn write_len_with_options(&self, options: &WriteOptions) -> u64
+    where
+        Body: AsRef<[u8]>,
+    {
+        serializer::write_len(self.serializer(options))
+    }
+
+    /// Serialize the request to any `std::fmt::Write`, e.g. a `String`.
+    ///
+    /// This is mostly useful for logging requests without a body or with a text body. If the body
+    /// is not valid UTF-8 then `fmt::Error` is returned and nothing is written.
+    pub fn write_str<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result
+    where
+        Body: AsRef<[u8]>,
+    {
+        let mut buf = Vec::with_capacity(self.write_len() as usize);
+        self.write(&mut buf).map_err(|_| std::fmt::Error)?;
+        let s = std::str::from_utf8(&buf).map_err(|_| std::fmt::Error)?;
+
+        w.write_str(s)
     }
 
     // Accessors
@@ -485,6 +1060,95 @@ impl<Body> Request<Body> {
         self.headers.get_typed()
     }
 
+    /// Gets a typed RTSP header value or its default value if it does not exist.
+    pub fn typed_header_or<H: TypedHeader + Default>(
+        &self,
+    ) -> Result<H, headers::HeaderParseError> {
+        self.typed_header_or_else(H::default)
+    }
+
+    /// Gets a typed RTSP header value or computes it from a closure if it does not exist.
+    pub fn typed_header_or_else<H: TypedHeader, F: FnOnce() -> H>(
+        &self,
+        func: F,
+    ) -> Result<H, headers::HeaderParseError> {
+        Ok(self.headers.get_typed()?.unwrap_or_else(func))
+    }
+
+    /// Gets the identifier of the `Pipelined-Requests` header if it exists.
+    ///
+    /// This allows correlating the `Session` of a response with the set of pipelined requests it
+    /// belongs to.
+    pub fn pipelined_id(&self) -> Result<Option<u32>, headers::HeaderParseError> {
+        Ok(self
+            .typed_header::<headers::PipelinedRequests>()?
+            .map(u32::from))
+    }
+
+    /// Gets the value of the `CSeq` header.
+    ///
+    /// Returns `None` if the header does not exist or is invalid. See
+    /// [`validate`](#method.validate) for distinguishing both cases.
+    pub fn cseq(&self) -> Option<u32> {
+        self.typed_header::<headers::CSeq>()
+            .ok()
+            .flatten()
+            .map(u32::from)
+    }
+
+    /// Checks if the message is semantically valid.
+    ///
+    /// Parsing only checks that the message is well-formed. This additionally checks that the
+    /// `CSeq` header, which is required on every request and response, exists and is valid.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        match self.typed_header::<headers::CSeq>() {
+            Ok(Some(_)) => Ok(()),
+            Ok(None) => Err(ValidationError::MissingCSeq),
+            Err(err) => Err(ValidationError::InvalidHeader(err)),
+        }
+    }
+
+    /// Gets the value of the `Content-Length` header.
+    ///
+    /// Returns `None` if the header does not exist or is invalid. See
+    /// [`checked_content_length`](#method.checked_content_length) for also checking it against
+    /// the body.
+    pub fn content_length(&self) -> Option<u64> {
+        self.typed_header::<headers::ContentLength>()
+            .ok()
+            .flatten()
+            .map(u64::from)
+    }
+
+    /// Gets the value of the `Content-Length` header and checks it against the length of the
+    /// body.
+    ///
+    /// Returns `Ok(None)` if the header does not exist and the body is empty. Fails if the header
+    /// is invalid, or if it does not match the length of the body, including a missing header
+    /// with a non-empty body.
+    pub fn checked_content_length(&self) -> Result<Option<u64>, ValidationError>
+    where
+        Body: AsRef<[u8]>,
+    {
+        let content_length = self
+            .typed_header::<headers::ContentLength>()
+            .map_err(ValidationError::InvalidHeader)?
+            .map(u64::from);
+
+        if content_length.unwrap_or(0) != self.body.as_ref().len() as u64 {
+            return Err(ValidationError::ContentLengthMismatch);
+        }
+
+        Ok(content_length)
+    }
+
+    /// Gets the value of the `Content-Type` header.
+    ///
+    /// Returns `None` if the header does not exist or is invalid.
+    pub fn content_type(&self) -> Option<headers::ContentType> {
+        self.typed_header::<headers::ContentType>().ok().flatten()
+    }
+
     /// Gets a mutable reference to an RTSP header value if it exists.
     pub fn header_mut(&mut self, name: &HeaderName) -> Option<&mut HeaderValue> {
         self.headers.get_mut(name)
@@ -495,6 +1159,17 @@ impl<Body> Request<Body> {
         self.headers.iter()
     }
 
+    /// Iterator over all headers as borrowed names and values.
+    ///
+    /// Unlike [`headers`](Self::headers) this yields plain string slices, e.g. for serializing
+    /// the headers without any allocations.
+    pub fn header_refs(&self) -> impl Iterator<Item = HeaderRef<'_>> {
+        self.headers.iter().map(|(name, value)| HeaderRef {
+            name: name.as_str(),
+            value: value.as_str(),
+        })
+    }
+
     /// Iterator over all header names.
     pub fn header_names(&self) -> impl Iterator<Item = &HeaderName> {
         self.headers.names()
@@ -504,6 +1179,17 @@ impl<Body> Request<Body> {
     pub fn header_values(&self) -> impl Iterator<Item = &HeaderValue> {
         self.headers.values()
     }
+
+    /// Check if the request carries credentials.
+    ///
+    /// This is the case if an `Authorization` or `Proxy-Authorization` header is present.
+    pub fn has_credentials(&self) -> bool {
+        self.headers.get(&crate::headers::AUTHORIZATION).is_some()
+            || self
+                .headers
+                .get(&crate::headers::PROXY_AUTHORIZATION)
+                .is_some()
+    }
 }
 
 impl<Body> AsRef<Headers> for Request<Body> {
@@ -559,6 +1245,15 @@ impl RequestBuilder {
         self
     }
 
+    /// Set the `Session` header of the request.
+    ///
+    /// This is useful for control requests like `PLAY`, `PAUSE` or `TEARDOWN` on an already
+    /// established session. The session timeout is only meaningful in responses and is not
+    /// included in the request.
+    pub fn session(self, session: &crate::headers::Session) -> Self {
+        self.typed_header(&crate::headers::Session::from(session.0.clone()))
+    }
+
     /// Build a request with an empty body.
     pub fn empty(self) -> Request<Empty> {
         self.0
@@ -630,48 +1325,101 @@ impl Response<Empty> {
     pub fn builder(version: Version, status: StatusCode) -> ResponseBuilder {
         ResponseBuilder::new(version, status)
     }
+
+    /// Create a new empty `Response` for a given RTSP version and status code.
+    ///
+    /// The response has the default reason phrase of the status code and no headers.
+    pub fn from_status(version: Version, status: StatusCode) -> Response<Empty> {
+        ResponseBuilder::new(version, status).empty()
+    }
 }
 
 impl<Body> Response<Body> {
-    pub(crate) fn borrow(&self) -> ResponseRef
+    // Serializes the headers directly from the map without collecting them first.
+    fn serializer<'a, W: std::io::Write + 'a>(
+        &'a self,
+        options: &WriteOptions,
+    ) -> impl cookie_factory::SerializeFn<W> + 'a
     where
         Body: AsRef<[u8]>,
     {
-        let headers = self
-            .headers
-            .iter()
-            .map(|(name, value)| HeaderRef {
-                name: name.as_str(),
-                value: value.as_str(),
-            })
-            .collect();
-
-        ResponseRef {
-            version: self.version,
-            status: self.status,
-            reason_phrase: &self.reason_phrase,
-            headers,
-            body: self.body.as_ref(),
-        }
+        serializer::response(
+            StatusLine {
+                version: self.version,
+                status: self.status,
+                reason_phrase: &self.reason_phrase,
+            },
+            self.headers
+                .iter_ordered(options.canonical_header_order)
+                .map(|(name, value)| HeaderRef {
+                    name: name.as_str(),
+                    value: value.as_str(),
+                }),
+            self.body.as_ref(),
+            options.line_ending,
+        )
     }
 
     /// Serialize the response to any `std::io::Write`.
     ///
     /// Resuming writing after `std::io::ErrorKind::WouldBlock` is not supported. Any previously
-    /// written data will have to be discarded for resuming.
+    /// written data will have to be discarded for resuming. Use
+    /// [`MessageWriter`](struct.MessageWriter.html) for resumable writing instead.
     pub fn write<'b, W: std::io::Write + 'b>(&self, w: &'b mut W) -> Result<(), WriteError>
     where
         Body: AsRef<[u8]>,
     {
-        self.borrow().write(w)
+        self.write_with_options(w, &WriteOptions::default())
+    }
+
+    /// Serialize the response to any `std::io::Write` with the given options.
+    ///
+    /// See [`Message::write_with_options`](enum.Message.html#method.write_with_options).
+    pub fn write_with_options<'b, W: std::io::Write + 'b>(
+        &self,
+        w: &'b mut W,
+        options: &WriteOptions,
+    ) -> Result<(), WriteError>
+    where
+        Body: AsRef<[u8]>,
+    {
+        serializer::write(self.serializer(options), w)
     }
 
     /// Calculate the number of bytes needed to serialize the response.
+    ///
+    /// This is exactly the number of bytes written by [`write`](#method.write).
     pub fn write_len(&self) -> u64
     where
         Body: AsRef<[u8]>,
     {
-        self.borrow().write_len()
+        self.write_len_with_options(&WriteOptions::default())
+    }
+
+    /// Calculate the number of bytes needed to serialize the response with the given options.
+    ///
+    /// This is exactly the number of bytes written by
+    /// [`write_with_options`](#method.write_with_options).
+    pub fn write_len_with_options(&self, options: &WriteOptions) -> u64
+    where
+        Body: AsRef<[u8]>,
+    {
+        serializer::write_len(self.serializer(options))
+    }
+
+    /// Serialize the response to any `std::fmt::Write`, e.g. a `String`.
+    ///
+    /// This is mostly useful for logging responses without a body or with a text body. If the body
+    /// is not valid UTF-8 then `fmt::Error` is returned and nothing is written.
+    pub fn write_str<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result
+    where
+        Body: AsRef<[u8]>,
+    {
+        let mut buf = Vec::with_capacity(self.write_len() as usize);
+        self.write(&mut buf).map_err(|_| std::fmt::Error)?;
+        let s = std::str::from_utf8(&buf).map_err(|_| std::fmt::Error)?;
+
+        w.write_str(s)
     }
 
     // Accessors
@@ -691,10 +1439,27 @@ impl<Body> Response<Body> {
     }
 
     /// Set the status code of the response.
+    ///
+    /// This keeps the current reason phrase. Use
+    /// [`set_status_with_reason`](#method.set_status_with_reason) to also change it.
     pub fn set_status(&mut self, status: StatusCode) {
         self.status = status;
     }
 
+    /// Set the status code and reason phrase of the response.
+    ///
+    /// If `reason_phrase` is `None` then the default reason phrase for the status code is used.
+    pub fn set_status_with_reason<S: Into<String>>(
+        &mut self,
+        status: StatusCode,
+        reason_phrase: Option<S>,
+    ) {
+        self.status = status;
+        self.reason_phrase = reason_phrase
+            .map(Into::into)
+            .unwrap_or_else(|| status.to_string());
+    }
+
     /// Get the reason phrase of the response.
     pub fn reason_phrase(&self) -> &str {
         self.reason_phrase.as_str()
@@ -787,6 +1552,19 @@ impl<Body> Response<Body> {
         }
     }
 
+    /// Removes the `Content-Length` header, even if the body is not empty.
+    ///
+    /// This is for responses where the header must be omitted entirely instead of being
+    /// `Content-Length: 0`, see
+    /// [`ResponseBuilder::always_content_length`](struct.ResponseBuilder.html#method.always_content_length).
+    /// Note that a non-empty body without `Content-Length` can't be parsed correctly by the
+    /// receiver unless it knows the body length by other means.
+    pub fn without_content_length(mut self) -> Self {
+        self.headers.remove(&crate::headers::CONTENT_LENGTH);
+
+        self
+    }
+
     // Header API
     /// Appends a value to an existing RTSP header or inserts it.
     ///
@@ -833,6 +1611,95 @@ impl<Body> Response<Body> {
         self.headers.get_typed()
     }
 
+    /// Gets a typed RTSP header value or its default value if it does not exist.
+    pub fn typed_header_or<H: TypedHeader + Default>(
+        &self,
+    ) -> Result<H, headers::HeaderParseError> {
+        self.typed_header_or_else(H::default)
+    }
+
+    /// Gets a typed RTSP header value or computes it from a closure if it does not exist.
+    pub fn typed_header_or_else<H: TypedHeader, F: FnOnce() -> H>(
+        &self,
+        func: F,
+    ) -> Result<H, headers::HeaderParseError> {
+        Ok(self.headers.get_typed()?.unwrap_or_else(func))
+    }
+
+    /// Gets the identifier of the `Pipelined-Requests` header if it exists.
+    ///
+    /// This allows correlating the `Session` of a response with the set of pipelined requests it
+    /// belongs to.
+    pub fn pipelined_id(&self) -> Result<Option<u32>, headers::HeaderParseError> {
+        Ok(self
+            .typed_header::<headers::PipelinedRequests>()?
+            .map(u32::from))
+    }
+
+    /// Gets the value of the `CSeq` header.
+    ///
+    /// Returns `None` if the header does not exist or is invalid. See
+    /// [`validate`](#method.validate) for distinguishing both cases.
+    pub fn cseq(&self) -> Option<u32> {
+        self.typed_header::<headers::CSeq>()
+            .ok()
+            .flatten()
+            .map(u32::from)
+    }
+
+    /// Checks if the message is semantically valid.
+    ///
+    /// Parsing only checks that the message is well-formed. This additionally checks that the
+    /// `CSeq` header, which is required on every request and response, exists and is valid.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        match self.typed_header::<headers::CSeq>() {
+            Ok(Some(_)) => Ok(()),
+            Ok(None) => Err(ValidationError::MissingCSeq),
+            Err(err) => Err(ValidationError::InvalidHeader(err)),
+        }
+    }
+
+    /// Gets the value of the `Content-Length` header.
+    ///
+    /// Returns `None` if the header does not exist or is invalid. See
+    /// [`checked_content_length`](#method.checked_content_length) for also checking it against
+    /// the body.
+    pub fn content_length(&self) -> Option<u64> {
+        self.typed_header::<headers::ContentLength>()
+            .ok()
+            .flatten()
+            .map(u64::from)
+    }
+
+    /// Gets the value of the `Content-Length` header and checks it against the length of the
+    /// body.
+    ///
+    /// Returns `Ok(None)` if the header does not exist and the body is empty. Fails if the header
+    /// is invalid, or if it does not match the length of the body, including a missing header
+    /// with a non-empty body.
+    pub fn checked_content_length(&self) -> Result<Option<u64>, ValidationError>
+    where
+        Body: AsRef<[u8]>,
+    {
+        let content_length = self
+            .typed_header::<headers::ContentLength>()
+            .map_err(ValidationError::InvalidHeader)?
+            .map(u64::from);
+
+        if content_length.unwrap_or(0) != self.body.as_ref().len() as u64 {
+            return Err(ValidationError::ContentLengthMismatch);
+        }
+
+        Ok(content_length)
+    }
+
+    /// Gets the value of the `Content-Type` header.
+    ///
+    /// Returns `None` if the header does not exist or is invalid.
+    pub fn content_type(&self) -> Option<headers::ContentType> {
+        self.typed_header::<headers::ContentType>().ok().flatten()
+    }
+
     /// Gets a mutable reference to an RTSP header value if it exists.
     pub fn header_mut(&mut self, name: &HeaderName) -> Option<&mut HeaderValue> {
         self.headers.get_mut(name)
@@ -843,6 +1710,17 @@ impl<Body> Response<Body> {
         self.headers.iter()
     }
 
+    /// Iterator over all headers as borrowed names and values.
+    ///
+    /// Unlike [`headers`](Self::headers) this yields plain string slices, e.g. for serializing
+    /// the headers without any allocations.
+    pub fn header_refs(&self) -> impl Iterator<Item = HeaderRef<'_>> {
+        self.headers.iter().map(|(name, value)| HeaderRef {
+            name: name.as_str(),
+            value: value.as_str(),
+        })
+    }
+
     /// Iterator over all header names.
     pub fn header_names(&self) -> impl Iterator<Item = &HeaderName> {
         self.headers.names()
@@ -852,6 +1730,52 @@ impl<Body> Response<Body> {
     pub fn header_values(&self) -> impl Iterator<Item = &HeaderValue> {
         self.headers.values()
     }
+
+    /// Check if the response requires the client to authenticate.
+    ///
+    /// This is the case for a `401 Unauthorized` response with a `WWW-Authenticate` header, or a
+    /// `407 Proxy Authentication Required` response with a `Proxy-Authenticate` header.
+    pub fn is_unauthorized(&self) -> bool {
+        let challenge = match self.status {
+            StatusCode::Unauthorized => crate::headers::WWW_AUTHENTICATE,
+            StatusCode::ProxyAuthenticationRequired => crate::headers::PROXY_AUTHENTICATE,
+            _ => return false,
+        };
+
+        self.headers.get(&challenge).is_some()
+    }
+
+    /// Resolves the `Content-Location` header against the `Content-Base` header.
+    ///
+    /// Absolute `Content-Location` URIs are returned as is. Returns `None` if there is no valid
+    /// `Content-Location` header or if it is relative and there is no valid `Content-Base` header.
+    /// Use [`resolved_content_location_with`](#method.resolved_content_location_with) to fall back
+    /// to the request URI instead.
+    pub fn resolved_content_location(&self) -> Option<Url> {
+        self.resolve_content_location(None)
+    }
+
+    /// Resolves the `Content-Location` header against the `Content-Base` header or, if there is no
+    /// valid `Content-Base` header, against the URI of the corresponding request.
+    pub fn resolved_content_location_with(&self, request_uri: &Url) -> Option<Url> {
+        self.resolve_content_location(Some(request_uri))
+    }
+
+    fn resolve_content_location(&self, request_uri: Option<&Url>) -> Option<Url> {
+        let location = self
+            .typed_header::<crate::headers::ContentLocation>()
+            .ok()
+            .flatten()?;
+        let content_base = self
+            .typed_header::<crate::headers::ContentBase>()
+            .ok()
+            .flatten();
+
+        match content_base.as_deref().or(request_uri) {
+            Some(base) => location.resolve(base).ok(),
+            None => location.as_url().cloned(),
+        }
+    }
 }
 
 impl<Body> AsRef<Headers> for Response<Body> {
@@ -870,7 +1794,7 @@ impl<Body> AsMut<Headers> for Response<Body> {
 ///
 /// See [`Response::builder`](struct.Response.html#method.builder) for details.
 #[derive(Debug, PartialEq, Eq, Clone)]
-pub struct ResponseBuilder(Response<Empty>, Option<String>);
+pub struct ResponseBuilder(Response<Empty>, Option<String>, Option<String>, bool);
 
 impl ResponseBuilder {
     fn new(version: Version, status: StatusCode) -> Self {
@@ -882,7 +1806,7 @@ impl ResponseBuilder {
             body: Empty,
         };
 
-        Self(response, None)
+        Self(response, None, None, false)
     }
 
     /// Set the reason phrase of the response.
@@ -896,6 +1820,33 @@ impl ResponseBuilder {
         self
     }
 
+    /// Set the provider for the default reason phrase of the response.
+    ///
+    /// This is only used if no reason phrase is set via
+    /// [`reason_phrase`](#method.reason_phrase). If the provider has no reason phrase for the
+    /// status code then the built-in default reason phrase is used.
+    pub fn reason_phrases<R: ReasonPhrases + ?Sized>(mut self, phrases: &R) -> Self {
+        self.2 = phrases.reason_phrase(self.0.status).map(String::from);
+
+        self
+    }
+
+    /// Always insert the `Content-Length` header, i.e. also `Content-Length: 0` for an empty body.
+    ///
+    /// By default the header is only inserted for non-empty bodies. Some clients expect the header
+    /// on every response that could have a body. The three possible states are:
+    ///
+    /// * omitted: the default for empty bodies, or via
+    ///   [`Response::without_content_length`](struct.Response.html#method.without_content_length)
+    ///   for any body,
+    /// * zero: an empty body built with this option enabled,
+    /// * present: the default for non-empty bodies.
+    pub fn always_content_length(mut self, always: bool) -> Self {
+        self.3 = always;
+
+        self
+    }
+
     /// Append a header to the response.
     pub fn header<V: Into<HeaderValue>>(mut self, name: HeaderName, value: V) -> Self {
         let value = value.into();
@@ -913,23 +1864,45 @@ impl ResponseBuilder {
     }
 
     /// Build a response with an empty body.
+    ///
+    /// This inserts `Content-Length: 0` if enabled via
+    /// [`always_content_length`](#method.always_content_length).
     pub fn empty(self) -> Response<Empty> {
-        let ResponseBuilder(mut response, reason_phrase) = self;
+        let ResponseBuilder(
+            mut response,
+            reason_phrase,
+            default_reason_phrase,
+            always_content_length,
+        ) = self;
+
+        if always_content_length {
+            response
+                .headers
+                .insert(crate::headers::CONTENT_LENGTH, HeaderValue::from("0"));
+        }
 
-        response.reason_phrase = reason_phrase.unwrap_or_else(|| response.status.to_string());
+        response.reason_phrase = reason_phrase
+            .or(default_reason_phrase)
+            .unwrap_or_else(|| response.status.to_string());
 
         response
     }
 
     /// Build a response with a provided body.
     ///
-    /// This inserts the `Content-Length` header with the length of the body if it is not empty.
+    /// This inserts the `Content-Length` header with the length of the body if it is not empty, or
+    /// always if enabled via [`always_content_length`](#method.always_content_length).
     pub fn build<Body: AsRef<[u8]>>(self, body: Body) -> Response<Body> {
-        let ResponseBuilder(mut response, reason_phrase) = self;
+        let ResponseBuilder(
+            mut response,
+            reason_phrase,
+            default_reason_phrase,
+            always_content_length,
+        ) = self;
 
         {
             let body = body.as_ref();
-            if !body.is_empty() {
+            if always_content_length || !body.is_empty() {
                 response.headers.insert(
                     crate::headers::CONTENT_LENGTH,
                     HeaderValue::from(format!("{}", body.len())),
@@ -937,7 +1910,9 @@ impl ResponseBuilder {
             }
         }
 
-        let reason_phrase = reason_phrase.unwrap_or_else(|| response.status.to_string());
+        let reason_phrase = reason_phrase
+            .or(default_reason_phrase)
+            .unwrap_or_else(|| response.status.to_string());
 
         Response {
             version: response.version,
@@ -949,6 +1924,20 @@ impl ResponseBuilder {
     }
 }
 
+/// Create an RTSP 2.0 response builder for a status code.
+impl From<StatusCode> for ResponseBuilder {
+    fn from(status: StatusCode) -> Self {
+        ResponseBuilder::new(Version::V2_0, status)
+    }
+}
+
+/// Create an RTSP 2.0 response builder for a status code and reason phrase.
+impl<'a> From<(StatusCode, &'a str)> for ResponseBuilder {
+    fn from((status, reason_phrase): (StatusCode, &'a str)) -> Self {
+        ResponseBuilder::new(Version::V2_0, status).reason_phrase(reason_phrase)
+    }
+}
+
 /// RTSP data message.
 ///
 /// See [RFC 7826 section 14](https://tools.ietf.org/html/rfc7826#section-14) for details about the
@@ -967,7 +1956,7 @@ impl<BodyA, BodyB: PartialEq<BodyA>> PartialEq<Data<BodyA>> for Data<BodyB> {
 }
 
 impl<Body> Data<Body> {
-    pub(crate) fn borrow(&self) -> DataRef
+    pub(crate) fn borrow(&self) -> DataRef<'_>
     where
         Body: AsRef<[u8]>,
     {
@@ -985,7 +1974,8 @@ impl<Body> Data<Body> {
     /// Serialize the data to any `std::io::Write`.
     ///
     /// Resuming writing after `std::io::ErrorKind::WouldBlock` is not supported. Any previously
-    /// written data will have to be discarded for resuming.
+    /// written data will have to be discarded for resuming. Use
+    /// [`MessageWriter`](struct.MessageWriter.html) for resumable writing instead.
     pub fn write<'b, W: std::io::Write + 'b>(&self, w: &'b mut W) -> Result<(), WriteError>
     where
         Body: AsRef<[u8]>,
@@ -994,6 +1984,8 @@ impl<Body> Data<Body> {
     }
 
     /// Calculate the number of bytes needed to serialize the data.
+    ///
+    /// This is exactly the number of bytes written by [`write`](#method.write).
     pub fn write_len(&self) -> u64
     where
         Body: AsRef<[u8]>,
@@ -1059,11 +2051,52 @@ impl<Body> Data<Body> {
     }
 }
 
+impl<'a, T: From<&'a [u8]>> Data<T> {
+    /// Try parse a data message from a `&[u8]` and also return how many bytes were consumed.
+    ///
+    /// This works the same as [`Message::parse`](enum.Message.html#method.parse) but only
+    /// accepts interleaved data messages.
+    ///
+    /// ## Parsing an RTSP data message
+    ///
+    /// ```rust
+    /// let data = b"$\x01\x00\x04abcd";
+    ///
+    /// let (data_message, consumed): (rtsp_types::Data<Vec<u8>>, _) =
+    ///     rtsp_types::Data::parse(data).expect("Failed to parse data");
+    ///
+    /// assert_eq!(consumed, data.len());
+    /// assert_eq!(data_message.channel_id(), 1);
+    /// assert_eq!(data_message.as_slice(), b"abcd");
+    /// ```
+    pub fn parse<B: AsRef<[u8]> + 'a + ?Sized>(buf: &'a B) -> Result<(Self, usize), ParseError> {
+        let buf = buf.as_ref();
+        let (data, consumed) = DataRef::parse(buf)?;
+
+        Ok((data.to_owned(), consumed))
+    }
+}
+
 impl Data<Vec<u8>> {
     /// Create a new data message from a `Vec<u8>`.
     pub fn from_vec(channel_id: u8, body: Vec<u8>) -> Self {
         Self { channel_id, body }
     }
+
+    /// Calculate the number of bytes needed to serialize a data message with a body of the given
+    /// length.
+    ///
+    /// This is the same as [`Data::write_len`] but allows pre-sizing buffers without constructing
+    /// a data message first.
+    ///
+    /// ```rust
+    /// let data = rtsp_types::Data::new(1, &b"abcd"[..]);
+    /// assert_eq!(rtsp_types::Data::frame_len(4) as u64, data.write_len());
+    /// ```
+    pub fn frame_len(body_len: usize) -> usize {
+        // `$`, channel id and 16 bit body length
+        4 + body_len
+    }
 }
 
 impl<Body: AsRef<[u8]>> AsRef<[u8]> for Data<Body> {
@@ -1071,3 +2104,753 @@ impl<Body: AsRef<[u8]>> AsRef<[u8]> for Data<Body> {
         self.body.as_ref()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auth_predicates() {
+        let response = Response::builder(Version::V1_0, StatusCode::Unauthorized)
+            .header(
+                crate::headers::WWW_AUTHENTICATE,
+                "Digest realm=\"LIVE555 Streaming Media\", nonce=\"a5b5fbcd0f8e3c5c\"",
+            )
+            .empty();
+        assert!(response.is_unauthorized());
+
+        let response = Response::builder(Version::V1_0, StatusCode::ProxyAuthenticationRequired)
+            .header(crate::headers::PROXY_AUTHENTICATE, "Basic realm=\"proxy\"")
+            .empty();
+        assert!(response.is_unauthorized());
+
+        // The challenge has to be in the header matching the status code
+        let response = Response::builder(Version::V1_0, StatusCode::ProxyAuthenticationRequired)
+            .header(crate::headers::WWW_AUTHENTICATE, "Basic realm=\"proxy\"")
+            .empty();
+        assert!(!response.is_unauthorized());
+
+        let response = Response::builder(Version::V1_0, StatusCode::Unauthorized).empty();
+        assert!(!response.is_unauthorized());
+
+        let response = Response::builder(Version::V1_0, StatusCode::Ok)
+            .header(crate::headers::WWW_AUTHENTICATE, "Basic realm=\"example\"")
+            .empty();
+        assert!(!response.is_unauthorized());
+
+        let request = Request::builder(Method::Describe, Version::V1_0)
+            .header(
+                crate::headers::AUTHORIZATION,
+                "Basic QWxhZGRpbjpvcGVuIHNlc2FtZQ==",
+            )
+            .empty();
+        assert!(request.has_credentials());
+
+        let request = Request::builder(Method::Describe, Version::V1_0)
+            .header(
+                crate::headers::PROXY_AUTHORIZATION,
+                "Basic QWxhZGRpbjpvcGVuIHNlc2FtZQ==",
+            )
+            .empty();
+        assert!(request.has_credentials());
+
+        let request = Request::builder(Method::Describe, Version::V1_0).empty();
+        assert!(!request.has_credentials());
+    }
+
+    #[test]
+    fn test_resolved_content_location() {
+        let request_uri = Url::parse("rtsp://example.com/media/stream").unwrap();
+
+        let response = Response::builder(Version::V1_0, StatusCode::Ok)
+            .header(crate::headers::CONTENT_BASE, "rtsp://example.com/media/")
+            .header(crate::headers::CONTENT_LOCATION, "video/track1")
+            .empty();
+        assert_eq!(
+            response.resolved_content_location().unwrap().as_str(),
+            "rtsp://example.com/media/video/track1"
+        );
+        // Content-Base takes precedence over the request URI
+        assert_eq!(
+            response
+                .resolved_content_location_with(&Url::parse("rtsp://other.com/").unwrap())
+                .unwrap()
+                .as_str(),
+            "rtsp://example.com/media/video/track1"
+        );
+
+        let response = Response::builder(Version::V1_0, StatusCode::Ok)
+            .header(crate::headers::CONTENT_BASE, "rtsp://example.com/media/")
+            .header(crate::headers::CONTENT_LOCATION, "/other/stream")
+            .empty();
+        assert_eq!(
+            response.resolved_content_location().unwrap().as_str(),
+            "rtsp://example.com/other/stream"
+        );
+
+        let response = Response::builder(Version::V1_0, StatusCode::Ok)
+            .header(crate::headers::CONTENT_BASE, "rtsp://example.com/media/")
+            .header(crate::headers::CONTENT_LOCATION, "rtsp://other.com/stream")
+            .empty();
+        assert_eq!(
+            response.resolved_content_location().unwrap().as_str(),
+            "rtsp://other.com/stream"
+        );
+
+        // Without Content-Base only the request URI can be used
+        let response = Response::builder(Version::V1_0, StatusCode::Ok)
+            .header(crate::headers::CONTENT_LOCATION, "stream2")
+            .empty();
+        assert_eq!(response.resolved_content_location(), None);
+        assert_eq!(
+            response
+                .resolved_content_location_with(&request_uri)
+                .unwrap()
+                .as_str(),
+            "rtsp://example.com/media/stream2"
+        );
+
+        let response = Response::builder(Version::V1_0, StatusCode::Ok)
+            .header(crate::headers::CONTENT_BASE, "rtsp://example.com/media/")
+            .empty();
+        assert_eq!(response.resolved_content_location(), None);
+        assert_eq!(response.resolved_content_location_with(&request_uri), None);
+    }
+
+    #[test]
+    fn test_fits_interleaved() {
+        let request = |body_len: usize| -> Message<Vec<u8>> {
+            Request::builder(Method::SetParameter, Version::V2_0)
+                .header(crate::headers::CSEQ, "1")
+                .build(vec![0u8; body_len])
+                .into()
+        };
+
+        let small = request(1024);
+        assert!(small.fits_interleaved());
+
+        let exact = (0..u16::MAX as usize)
+            .rev()
+            .map(request)
+            .find(|m| m.write_len() <= u16::MAX as u64)
+            .unwrap();
+        assert_eq!(exact.write_len(), u16::MAX as u64);
+        assert!(exact.fits_interleaved());
+
+        let large = request(u16::MAX as usize);
+        assert!(!large.fits_interleaved());
+
+        let data: Message<Vec<u8>> = Data::new(0, vec![0u8; u16::MAX as usize]).into();
+        assert!(data.fits_interleaved());
+        let data: Message<Vec<u8>> = Data::new(0, vec![0u8; u16::MAX as usize + 1]).into();
+        assert!(!data.fits_interleaved());
+    }
+
+    #[test]
+    fn test_response_set_status() {
+        let (message, _): (Message<Vec<u8>>, _) =
+            Message::parse(b"RTSP/2.0 200 All Good\r\nCSeq: 1\r\n\r\n").unwrap();
+        let mut response = match message {
+            Message::Response(response) => response,
+            _ => unreachable!(),
+        };
+
+        response.set_status(StatusCode::MovedPermanently);
+        assert_eq!(response.status(), StatusCode::MovedPermanently);
+        assert_eq!(response.reason_phrase(), "All Good");
+
+        let mut v = vec![];
+        response.write(&mut v).unwrap();
+        assert_eq!(v, &b"RTSP/2.0 301 All Good\r\nCSeq: 1\r\n\r\n"[..]);
+
+        response.set_status_with_reason(StatusCode::NotFound, Some("Gone Fishing"));
+        assert_eq!(response.status(), StatusCode::NotFound);
+        assert_eq!(response.reason_phrase(), "Gone Fishing");
+
+        response.set_status_with_reason::<String>(StatusCode::BadRequest, None);
+        assert_eq!(response.status(), StatusCode::BadRequest);
+        assert_eq!(response.reason_phrase(), "Bad Request");
+    }
+
+    #[test]
+    fn test_response_reason_phrases() {
+        struct Custom;
+
+        impl ReasonPhrases for Custom {
+            fn reason_phrase(&self, status: StatusCode) -> Option<&str> {
+                match status {
+                    StatusCode::Ok => Some("Alles gut"),
+                    StatusCode::SessionNotFound => Some("Sitzung nicht gefunden"),
+                    _ => None,
+                }
+            }
+        }
+
+        let response = Response::builder(Version::V2_0, StatusCode::SessionNotFound)
+            .reason_phrases(&Custom)
+            .header(crate::headers::CSEQ, "1")
+            .empty();
+        assert_eq!(response.reason_phrase(), "Sitzung nicht gefunden");
+
+        let mut v = vec![];
+        response.write(&mut v).unwrap();
+        assert_eq!(
+            v,
+            &b"RTSP/2.0 454 Sitzung nicht gefunden\r\nCSeq: 1\r\n\r\n"[..]
+        );
+
+        let response = Response::builder(Version::V2_0, StatusCode::Ok)
+            .reason_phrases(&Custom)
+            .build(&b"abc"[..]);
+        assert_eq!(response.reason_phrase(), "Alles gut");
+
+        let response = Response::builder(Version::V2_0, StatusCode::NotFound)
+            .reason_phrases(&Custom)
+            .empty();
+        assert_eq!(response.reason_phrase(), "Not Found");
+
+        let response = Response::builder(Version::V2_0, StatusCode::Ok)
+            .reason_phrase("Fine")
+            .reason_phrases(&Custom)
+            .empty();
+        assert_eq!(response.reason_phrase(), "Fine");
+    }
+
+    #[test]
+    fn test_response_from_status() {
+        let response = Response::from_status(Version::V1_0, StatusCode::NotFound);
+        assert_eq!(response.version(), Version::V1_0);
+        assert_eq!(response.status(), StatusCode::NotFound);
+        assert_eq!(response.reason_phrase(), "Not Found");
+        assert_eq!(response.headers().count(), 0);
+
+        let builder: ResponseBuilder = StatusCode::SessionNotFound.into();
+        let response = builder.header(crate::headers::CSEQ, "1").empty();
+        assert_eq!(response.version(), Version::V2_0);
+        assert_eq!(response.status(), StatusCode::SessionNotFound);
+        assert_eq!(response.reason_phrase(), "Session Not Found");
+
+        let mut v = vec![];
+        response.write(&mut v).unwrap();
+        assert_eq!(v, &b"RTSP/2.0 454 Session Not Found\r\nCSeq: 1\r\n\r\n"[..]);
+
+        let response = ResponseBuilder::from((StatusCode::Ok, "All Good")).empty();
+        assert_eq!(response.status(), StatusCode::Ok);
+        assert_eq!(response.reason_phrase(), "All Good");
+    }
+
+    #[test]
+    fn test_data_frame_len() {
+        for len in [0, 1, 10, 1500, u16::MAX as usize] {
+            let data = Data::new(1, vec![0u8; len]);
+            assert_eq!(Data::frame_len(len) as u64, data.write_len(), "{}", len);
+
+            let mut v = Vec::with_capacity(Data::frame_len(len));
+            data.write(&mut v).unwrap();
+            assert_eq!(v.len(), Data::frame_len(len), "{}", len);
+
+            let message: Message<Vec<u8>> = data.into();
+            assert_eq!(message.write_len(), Data::frame_len(len) as u64, "{}", len);
+        }
+    }
+
+    #[test]
+    fn test_write_to_slice() {
+        let message: Message<Vec<u8>> = Request::builder(Method::Options, Version::V2_0)
+            .request_uri(url::Url::parse("rtsp://example.com/test").unwrap())
+            .header(crate::headers::CSEQ, "1")
+            .build(Vec::new())
+            .into();
+
+        let mut expected = vec![];
+        message.write(&mut expected).unwrap();
+        assert_eq!(message.write_len(), expected.len() as u64);
+
+        let mut buf = vec![0u8; expected.len()];
+        assert_eq!(message.write_to_slice(&mut buf).unwrap(), expected.len());
+        assert_eq!(buf, expected);
+
+        let mut buf = vec![0u8; expected.len() - 1];
+        assert!(matches!(
+            message.write_to_slice(&mut buf),
+            Err(WriteError::BufferTooSmall)
+        ));
+        assert!(buf.iter().all(|b| *b == 0));
+    }
+
+    #[test]
+    fn test_write_len_matrix() {
+        fn check(message: Message<Vec<u8>>) {
+            let mut v = vec![];
+            message.write(&mut v).unwrap();
+            assert_eq!(message.write_len(), v.len() as u64, "{:?}", message);
+
+            let len = match message {
+                Message::Request(ref request) => request.write_len(),
+                Message::Response(ref response) => response.write_len(),
+                Message::Data(ref data) => data.write_len(),
+            };
+            assert_eq!(len, v.len() as u64, "{:?}", message);
+
+            let mut buf = vec![0u8; v.len()];
+            assert_eq!(message.write_to_slice(&mut buf).unwrap(), v.len());
+            assert_eq!(buf, v);
+
+            let options = WriteOptions::new()
+                .line_ending(LineEnding::Lf)
+                .canonical_header_order(true);
+            let mut v = vec![];
+            message.write_with_options(&mut v, &options).unwrap();
+            assert_eq!(
+                message.write_len_with_options(&options),
+                v.len() as u64,
+                "{:?}",
+                message
+            );
+
+            let len = match message {
+                Message::Request(ref request) => request.write_len_with_options(&options),
+                Message::Response(ref response) => response.write_len_with_options(&options),
+                Message::Data(ref data) => data.write_len(),
+            };
+            assert_eq!(len, v.len() as u64, "{:?}", message);
+
+            let mut buf = vec![0u8; v.len()];
+            assert_eq!(
+                message
+                    .write_to_slice_with_options(&mut buf, &options)
+                    .unwrap(),
+                v.len()
+            );
+            assert_eq!(buf, v);
+        }
+
+        let bodies = [Vec::new(), Vec::from(&b"abc"[..]), vec![0xffu8; 1000]];
+
+        for version in [Version::V1_0, Version::V2_0] {
+            for body in &bodies {
+                for method in [
+                    Method::Options,
+                    Method::SetParameter,
+                    Method::Extension(String::from("FOO_BAR")),
+                ] {
+                    for uri in [None, Some("rtsp://example.com/t\u{e9}st?query")] {
+                        let mut builder = Request::builder(method.clone(), version)
+                            .header(crate::headers::CSEQ, "1")
+                            .header(
+                                HeaderName::from_static_str("X-Unicode").unwrap(),
+                                "\u{fc}ber",
+                            );
+                        if let Some(uri) = uri {
+                            builder = builder.request_uri(url::Url::parse(uri).unwrap());
+                        }
+                        check(builder.build(body.clone()).into());
+                    }
+                }
+
+                for (status, reason) in [
+                    (StatusCode::Ok, None),
+                    (StatusCode::SessionNotFound, None),
+                    (StatusCode::Ok, Some("")),
+                    (StatusCode::Ok, Some("Sehr gr\u{fc}n")),
+                    (StatusCode::from(599), None),
+                ] {
+                    let mut builder =
+                        Response::builder(version, status).header(crate::headers::CSEQ, "1");
+                    if let Some(reason) = reason {
+                        builder = builder.reason_phrase(reason);
+                    }
+                    check(builder.build(body.clone()).into());
+                }
+
+                check(Data::new(0, body.clone()).into());
+            }
+        }
+
+        check(Data::new(255, vec![0u8; u16::MAX as usize]).into());
+    }
+
+    #[test]
+    fn test_write_line_ending() {
+        let options = WriteOptions::new().line_ending(LineEnding::Lf);
+
+        let request = Request::builder(Method::SetParameter, Version::V2_0)
+            .request_uri(url::Url::parse("rtsp://example.com/test").unwrap())
+            .header(crate::headers::CSEQ, "2")
+            .header(crate::headers::CONTENT_TYPE, "text/parameters")
+            .build(Vec::from(&b"foo: bar\r\nbaz: qux\r\n"[..]));
+
+        let mut v = vec![];
+        request.write_with_options(&mut v, &options).unwrap();
+        assert_eq!(
+            v,
+            &b"SET_PARAMETER rtsp://example.com/test RTSP/2.0\n\
+               Content-Length: 20\n\
+               Content-Type: text/parameters\n\
+               CSeq: 2\n\
+               \n\
+               foo: bar\r\nbaz: qux\r\n"[..]
+        );
+
+        // LF line endings are only parsed if explicitly accepted
+        assert!(Message::<Vec<u8>>::parse(&v).is_err());
+        let parse_options = ParseOptions::new().accept_lf_line_endings(true);
+        let (message, consumed) =
+            Message::<Vec<u8>>::parse_with_options(&v, &parse_options).unwrap();
+        assert_eq!(consumed, v.len());
+        assert_eq!(message, Message::from(request.clone()));
+
+        // Only the message head uses the selected line ending, the body is unchanged
+        let head_len = v.len() - request.body().len();
+        let mut crlf = String::from_utf8(v[..head_len].to_vec())
+            .unwrap()
+            .replace('\n', "\r\n")
+            .into_bytes();
+        crlf.extend_from_slice(&v[head_len..]);
+        let (message, consumed) = Message::<Vec<u8>>::parse(&crlf).unwrap();
+        assert_eq!(consumed, crlf.len());
+        assert_eq!(message, Message::from(request.clone()));
+
+        let mut v2 = vec![];
+        request.write(&mut v2).unwrap();
+        assert_eq!(v2, crlf);
+        let mut v2 = vec![];
+        request
+            .write_with_options(&mut v2, &WriteOptions::default())
+            .unwrap();
+        assert_eq!(v2, crlf);
+
+        let response = Response::builder(Version::V1_0, StatusCode::Ok)
+            .header(crate::headers::CSEQ, "2")
+            .build(Vec::new());
+        let mut v = vec![];
+        Message::from(response.clone())
+            .write_with_options(&mut v, &options)
+            .unwrap();
+        assert_eq!(v, &b"RTSP/1.0 200 Ok\nCSeq: 2\n\n"[..]);
+        let (message, consumed) =
+            Message::<Vec<u8>>::parse_with_options(&v, &parse_options).unwrap();
+        assert_eq!(consumed, v.len());
+        assert_eq!(message, Message::from(response));
+    }
+
+    #[test]
+    fn test_header_refs() {
+        let request = Request::builder(Method::Options, Version::V2_0)
+            .header(crate::headers::CSEQ, "1")
+            .header(crate::headers::SUPPORTED, "play.basic")
+            .empty();
+
+        assert!(request
+            .header_refs()
+            .map(|h| (h.name(), h.value()))
+            .eq(request
+                .headers()
+                .map(|(name, value)| (name.as_str(), value.as_str()))));
+
+        let response = Response::builder(Version::V2_0, StatusCode::Ok)
+            .header(crate::headers::CSEQ, "1")
+            .header(crate::headers::SESSION, "12345678")
+            .empty();
+
+        let headers = response.header_refs().collect::<Vec<_>>();
+        assert_eq!(headers.len(), 2);
+        assert!(headers.iter().map(|h| (h.name(), h.value())).eq(response
+            .headers()
+            .map(|(name, value)| (name.as_str(), value.as_str()))));
+    }
+
+    #[test]
+    fn test_write_canonical_header_order() {
+        let request = Request::builder(Method::Setup, Version::V2_0)
+            .request_uri(url::Url::parse("rtsp://example.com/test").unwrap())
+            .header(crate::headers::SESSION, "12345678")
+            .header(crate::headers::ACCEPT, "application/sdp")
+            .header(crate::headers::CSEQ, "3")
+            .header(crate::headers::CONTENT_TYPE, "text/parameters")
+            .empty();
+
+        assert_eq!(
+            request
+                .as_ref()
+                .sorted_canonical()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["CSeq", "Accept", "Content-Type", "Session"]
+        );
+
+        let mut v = vec![];
+        request.write(&mut v).unwrap();
+        assert_eq!(
+            v,
+            &b"SETUP rtsp://example.com/test RTSP/2.0\r\n\
+               Accept: application/sdp\r\n\
+               Content-Type: text/parameters\r\n\
+               CSeq: 3\r\n\
+               Session: 12345678\r\n\
+               \r\n"[..]
+        );
+
+        let options = WriteOptions::new().canonical_header_order(true);
+        let mut v = vec![];
+        Message::from(request)
+            .write_with_options(&mut v, &options)
+            .unwrap();
+        assert_eq!(
+            v,
+            &b"SETUP rtsp://example.com/test RTSP/2.0\r\n\
+               CSeq: 3\r\n\
+               Accept: application/sdp\r\n\
+               Content-Type: text/parameters\r\n\
+               Session: 12345678\r\n\
+               \r\n"[..]
+        );
+    }
+
+    #[test]
+    fn test_write_str() {
+        let request = Request::builder(Method::Options, Version::V1_0)
+            .request_uri(url::Url::parse("rtsp://example.com/media").unwrap())
+            .header(crate::headers::CSEQ, "1")
+            .empty();
+
+        let mut s = String::new();
+        request.write_str(&mut s).unwrap();
+        assert_eq!(
+            s,
+            "OPTIONS rtsp://example.com/media RTSP/1.0\r\nCSeq: 1\r\n\r\n"
+        );
+
+        let response = Response::builder(Version::V1_0, StatusCode::Ok)
+            .header(crate::headers::CSEQ, "2")
+            .build(Vec::from(&b"v=0\r\n"[..]));
+        let mut s = String::new();
+        response.write_str(&mut s).unwrap();
+        assert_eq!(
+            s,
+            "RTSP/1.0 200 Ok\r\nContent-Length: 5\r\nCSeq: 2\r\n\r\nv=0\r\n"
+        );
+
+        let request = Request::builder(Method::SetParameter, Version::V1_0)
+            .header(crate::headers::CSEQ, "3")
+            .build(vec![0xffu8, 0xfe]);
+        let mut s = String::new();
+        assert!(request.write_str(&mut s).is_err());
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn test_typed_header_or() {
+        use crate::headers::{
+            Accept, CSeq, Require, Supported, Unsupported, CSEQ, REQUIRE, SUPPORTED,
+        };
+
+        let request = Request::builder(Method::Options, Version::V2_0)
+            .header(SUPPORTED, "play.basic, play.scale")
+            .empty();
+
+        let supported = request.typed_header_or::<Supported>().unwrap();
+        assert_eq!(*supported, ["play.basic", "play.scale"]);
+        assert!(request.typed_header_or::<Accept>().unwrap().is_empty());
+        assert_eq!(
+            request.typed_header_or_else(|| CSeq::from(1)).unwrap(),
+            CSeq::from(1)
+        );
+
+        let response = Response::builder(Version::V2_0, StatusCode::Ok)
+            .header(CSEQ, "2")
+            .header(REQUIRE, "play.basic")
+            .empty();
+
+        assert_eq!(
+            response.typed_header_or_else(|| CSeq::from(1)).unwrap(),
+            CSeq::from(2)
+        );
+        assert!(response
+            .typed_header_or::<Require>()
+            .unwrap()
+            .contains_play_basic());
+        assert!(response
+            .typed_header_or::<Unsupported>()
+            .unwrap()
+            .is_empty());
+
+        let response = Response::builder(Version::V2_0, StatusCode::Ok)
+            .header(CSEQ, "foo")
+            .empty();
+        assert!(response.typed_header_or_else(|| CSeq::from(1)).is_err());
+    }
+
+    #[test]
+    fn test_content_length_accessors() {
+        use crate::headers::{HeaderParseError, CONTENT_LENGTH, CSEQ};
+
+        let mut request = Request::builder(Method::SetParameter, Version::V2_0)
+            .header(CSEQ, "1")
+            .build(&b"barparam: barstuff"[..]);
+        assert_eq!(request.content_length(), Some(18));
+        assert_eq!(request.checked_content_length(), Ok(Some(18)));
+
+        request.insert_header(CONTENT_LENGTH, "10");
+        assert_eq!(request.content_length(), Some(10));
+        assert_eq!(
+            request.checked_content_length(),
+            Err(ValidationError::ContentLengthMismatch)
+        );
+
+        request.remove_header(&CONTENT_LENGTH);
+        assert_eq!(request.content_length(), None);
+        assert_eq!(
+            request.checked_content_length(),
+            Err(ValidationError::ContentLengthMismatch)
+        );
+
+        let mut response = Response::builder(Version::V2_0, StatusCode::Ok)
+            .header(CSEQ, "1")
+            .empty();
+        assert_eq!(response.content_length(), None);
+        assert_eq!(response.checked_content_length(), Ok(None));
+
+        response.insert_header(CONTENT_LENGTH, "0");
+        assert_eq!(response.content_length(), Some(0));
+        assert_eq!(response.checked_content_length(), Ok(Some(0)));
+
+        response.insert_header(CONTENT_LENGTH, "abc");
+        assert_eq!(response.content_length(), None);
+        assert_eq!(
+            response.checked_content_length(),
+            Err(ValidationError::InvalidHeader(
+                HeaderParseError::for_header(CONTENT_LENGTH)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_content_type_accessor() {
+        use crate::headers::{ContentType, MediaType, CONTENT_TYPE, CSEQ};
+
+        let response = Response::builder(Version::V2_0, StatusCode::Ok)
+            .header(CSEQ, "1")
+            .header(CONTENT_TYPE, "application/sdp")
+            .build(&b"v=0\r\n"[..]);
+        assert_eq!(
+            response.content_type(),
+            Some(ContentType {
+                media_type: MediaType::Application,
+                media_subtype: String::from("sdp"),
+                params: Vec::new(),
+            })
+        );
+
+        let request = Request::builder(Method::Options, Version::V2_0)
+            .header(CSEQ, "1")
+            .empty();
+        assert_eq!(request.content_type(), None);
+
+        let request = Request::builder(Method::Options, Version::V2_0)
+            .header(CSEQ, "1")
+            .header(CONTENT_TYPE, "application")
+            .empty();
+        assert_eq!(request.content_type(), None);
+    }
+
+    #[test]
+    fn test_response_cseq() {
+        use crate::headers::{CSeq, HeaderParseError, CSEQ};
+
+        let (message, _): (Message<Vec<u8>>, _) =
+            Message::parse(b"RTSP/2.0 200 OK\r\nCSeq: 3\r\n\r\n").unwrap();
+        let response = match message {
+            Message::Response(ref response) => response,
+            _ => unreachable!(),
+        };
+        assert_eq!(response.cseq(), Some(3));
+        assert_eq!(response.validate(), Ok(()));
+        assert_eq!(message.validate(), Ok(()));
+
+        // Missing CSeq is accepted by the parser but flagged by validation
+        let (message, _): (Message<Vec<u8>>, _) =
+            Message::parse(b"RTSP/2.0 200 OK\r\nSession: 1234\r\n\r\n").unwrap();
+        let response = match message {
+            Message::Response(ref response) => response,
+            _ => unreachable!(),
+        };
+        assert_eq!(response.cseq(), None);
+        assert_eq!(response.validate(), Err(ValidationError::MissingCSeq));
+        assert_eq!(message.validate(), Err(ValidationError::MissingCSeq));
+
+        let response = Response::builder(Version::V2_0, StatusCode::Ok)
+            .header(CSEQ, "abc")
+            .empty();
+        assert_eq!(response.cseq(), None);
+        assert_eq!(
+            response.validate(),
+            Err(ValidationError::InvalidHeader(
+                HeaderParseError::for_header(CSEQ)
+            ))
+        );
+
+        let request = Request::builder(Method::Options, Version::V2_0)
+            .typed_header(&CSeq::from(5))
+            .empty();
+        assert_eq!(request.cseq(), Some(5));
+        assert_eq!(request.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_pipelined_id() {
+        use crate::headers::{PipelinedRequests, CSEQ, PIPELINED_REQUESTS, SESSION};
+
+        let request = Request::builder(Method::Setup, Version::V2_0)
+            .header(CSEQ, "1")
+            .header(PIPELINED_REQUESTS, "7709")
+            .empty();
+        assert_eq!(request.pipelined_id(), Ok(Some(7709)));
+
+        let response = Response::builder(Version::V2_0, StatusCode::Ok)
+            .header(CSEQ, "1")
+            .header(SESSION, "12345678")
+            .typed_header(&PipelinedRequests::from(
+                request.pipelined_id().unwrap().unwrap(),
+            ))
+            .empty();
+        assert_eq!(response.pipelined_id(), Ok(Some(7709)));
+        assert_eq!(
+            response.header(&PIPELINED_REQUESTS).unwrap().as_str(),
+            "7709"
+        );
+
+        let request = Request::builder(Method::Setup, Version::V2_0)
+            .header(CSEQ, "1")
+            .empty();
+        assert_eq!(request.pipelined_id(), Ok(None));
+
+        let response = Response::builder(Version::V2_0, StatusCode::Ok)
+            .header(PIPELINED_REQUESTS, "abc")
+            .empty();
+        assert!(response.pipelined_id().is_err());
+    }
+
+    #[test]
+    fn test_request_session() {
+        use crate::headers::{CSeq, Session, SESSION};
+
+        let session = Session::with_timeout("12345678".to_string(), 60);
+
+        let request = Request::builder(Method::Teardown, Version::V2_0)
+            .request_uri(Url::parse("rtsp://example.com/test").unwrap())
+            .typed_header(&CSeq::from(3))
+            .session(&session)
+            .empty();
+
+        assert_eq!(
+            request.header(&SESSION).map(|h| h.as_str()),
+            Some("12345678")
+        );
+        assert_eq!(
+            request.typed_header::<Session>(),
+            Ok(Some(Session("12345678".to_string(), None, Vec::new())))
+        );
+        assert_eq!(request.typed_header::<CSeq>(), Ok(Some(CSeq::from(3))));
+    }
+}
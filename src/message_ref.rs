@@ -15,10 +15,13 @@ pub enum MessageRef<'a> {
 }
 
 impl<'a> MessageRef<'a> {
-    pub fn to_owned<T: From<&'a [u8]>>(&self) -> Result<Message<T>, ParseError> {
+    pub fn to_owned<T: From<&'a [u8]>>(
+        &self,
+        options: &ParseOptions,
+    ) -> Result<Message<T>, ParseError> {
         let owned = match self {
-            MessageRef::Request(request) => Message::Request(request.to_owned()?),
-            MessageRef::Response(response) => Message::Response(response.to_owned()),
+            MessageRef::Request(request) => Message::Request(request.to_owned(options)?),
+            MessageRef::Response(response) => Message::Response(response.to_owned(options)),
             MessageRef::Data(data) => Message::Data(data.to_owned()),
         };
 
@@ -26,40 +29,60 @@ impl<'a> MessageRef<'a> {
     }
 
     pub fn parse(buf: &'a [u8]) -> Result<(Self, usize), ParseError> {
-        let (remainder, res) = match parser::message(buf) {
-            Ok(res) => res,
-            Err(nom::Err::Incomplete(needed)) => {
-                return Err(ParseError::Incomplete(match needed {
-                    nom::Needed::Size(needed_len) => Some(needed_len),
-                    _ => None,
-                }))
-            }
-            Err(_) => return Err(ParseError::Error),
-        };
+        Self::parse_with_options(buf, &ParseOptions::default())
+    }
+
+    pub fn parse_with_options(
+        buf: &'a [u8],
+        options: &ParseOptions,
+    ) -> Result<(Self, usize), ParseError> {
+        parser::check_start_line_len(
+            buf,
+            options.max_start_line_len,
+            options.accept_lf_line_endings,
+        )?;
+
+        let (mut remainder, mut res) =
+            parser::message(buf, options.accept_lf_line_endings).map_err(parse_error)?;
+
+        if options.sdp_body_without_content_length {
+            parser::sdp_body_without_content_length(&mut res, &mut remainder)?;
+        }
 
         let consumed = buf.len() - remainder.len();
 
         Ok((res, consumed))
     }
 
-    pub fn write<'b, W: std::io::Write + 'b>(self, w: &'b mut W) -> Result<(), WriteError>
-    where
-        'b: 'a,
-    {
-        match cookie_factory::gen_simple(serializer::message(self), w) {
-            Ok(_) => Ok(()),
-            Err(cookie_factory::GenError::IoError(io)) => Err(WriteError::IoError(io)),
-            // This case can't really happen with our serializer!
-            Err(err) => panic!("Failed to write message: {:?}", err),
-        }
+    /// Parses only the start line and headers, leaving the body empty.
+    ///
+    /// Interleaved data messages have no head and fail to parse.
+    pub fn parse_head_with_options(
+        buf: &'a [u8],
+        options: &ParseOptions,
+    ) -> Result<(Self, usize), ParseError> {
+        parser::check_start_line_len(
+            buf,
+            options.max_start_line_len,
+            options.accept_lf_line_endings,
+        )?;
+
+        let (remainder, res) =
+            parser::message_head(buf, options.accept_lf_line_endings).map_err(parse_error)?;
+        let consumed = buf.len() - remainder.len();
+
+        Ok((res, consumed))
     }
+}
 
-    pub fn write_len(&self) -> u64 {
-        match cookie_factory::gen(serializer::message(self.clone()), std::io::sink()) {
-            Ok((_w, pos)) => pos,
-            // This case can't really happen with our serializer!
-            Err(err) => panic!("Failed to calculate write length: {:?}", err),
-        }
+fn parse_error(err: nom::Err<parser::Error<&[u8]>>) -> ParseError {
+    match err {
+        nom::Err::Incomplete(needed) => ParseError::Incomplete(match needed {
+            nom::Needed::Size(needed_len) => Some(needed_len),
+            _ => None,
+        }),
+        nom::Err::Failure(parser::Error::InvalidHeader(_)) => ParseError::InvalidHeader,
+        _ => ParseError::Error,
     }
 }
 
@@ -152,40 +175,23 @@ pub struct RequestRef<'a> {
 }
 
 impl<'a> RequestRef<'a> {
-    pub fn to_owned<T: From<&'a [u8]>>(&self) -> Result<Request<T>, ParseError> {
+    pub fn to_owned<T: From<&'a [u8]>>(
+        &self,
+        options: &ParseOptions,
+    ) -> Result<Request<T>, ParseError> {
         Ok(Request {
             method: self.method.to_owned(),
             request_uri: self
                 .request_uri
                 .map(Url::parse)
                 .transpose()
-                .map_err(|_| ParseError::Error)?,
+                .map_err(ParseError::InvalidUri)?,
             version: self.version,
-            headers: Headers::from_headers_ref(&self.headers),
+            headers: Headers::from_headers_ref(&self.headers, options.preserve_raw_headers),
             body: self.body.into(),
         })
     }
 
-    pub fn write<'b, W: std::io::Write + 'b>(self, w: &'b mut W) -> Result<(), WriteError>
-    where
-        'b: 'a,
-    {
-        match cookie_factory::gen_simple(serializer::request(self), w) {
-            Ok(_) => Ok(()),
-            Err(cookie_factory::GenError::IoError(io)) => Err(WriteError::IoError(io)),
-            // This case can't really happen with our serializer!
-            Err(err) => panic!("Failed to write message: {:?}", err),
-        }
-    }
-
-    pub fn write_len(&self) -> u64 {
-        match cookie_factory::gen(serializer::request(self.clone()), std::io::sink()) {
-            Ok((_w, pos)) => pos,
-            // This case can't really happen with our serializer!
-            Err(err) => panic!("Failed to calculate write length: {:?}", err),
-        }
-    }
-
     #[allow(dead_code)]
     pub fn method(&self) -> &MethodRef<'a> {
         &self.method
@@ -207,7 +213,7 @@ impl<'a> RequestRef<'a> {
     }
 
     #[allow(dead_code)]
-    pub fn headers(&self) -> impl Iterator<Item = &HeaderRef> {
+    pub fn headers(&self) -> impl Iterator<Item = &HeaderRef<'_>> {
         self.headers.iter()
     }
 }
@@ -231,36 +237,16 @@ pub struct ResponseRef<'a> {
 }
 
 impl<'a> ResponseRef<'a> {
-    pub fn to_owned<T: From<&'a [u8]>>(&self) -> Response<T> {
+    pub fn to_owned<T: From<&'a [u8]>>(&self, options: &ParseOptions) -> Response<T> {
         Response {
             version: self.version,
             status: self.status,
             reason_phrase: self.reason_phrase.into(),
-            headers: Headers::from_headers_ref(&self.headers),
+            headers: Headers::from_headers_ref(&self.headers, options.preserve_raw_headers),
             body: self.body.into(),
         }
     }
 
-    pub fn write<'b, W: std::io::Write + 'b>(self, w: &'b mut W) -> Result<(), WriteError>
-    where
-        'b: 'a,
-    {
-        match cookie_factory::gen_simple(serializer::response(self), w) {
-            Ok(_) => Ok(()),
-            Err(cookie_factory::GenError::IoError(io)) => Err(WriteError::IoError(io)),
-            // This case can't really happen with our serializer!
-            Err(err) => panic!("Failed to write message: {:?}", err),
-        }
-    }
-
-    pub fn write_len(&self) -> u64 {
-        match cookie_factory::gen(serializer::response(self.clone()), std::io::sink()) {
-            Ok((_w, pos)) => pos,
-            // This case can't really happen with our serializer!
-            Err(err) => panic!("Failed to calculate write length: {:?}", err),
-        }
-    }
-
     #[allow(dead_code)]
     pub fn version(&self) -> Version {
         self.version
@@ -282,7 +268,7 @@ impl<'a> ResponseRef<'a> {
     }
 
     #[allow(dead_code)]
-    pub fn headers(&self) -> impl Iterator<Item = &HeaderRef> {
+    pub fn headers(&self) -> impl Iterator<Item = &HeaderRef<'_>> {
         self.headers.iter()
     }
 }
@@ -310,6 +296,23 @@ impl<'a> DataRef<'a> {
         }
     }
 
+    pub fn parse(buf: &'a [u8]) -> Result<(Self, usize), ParseError> {
+        let (remainder, res) = match parser::data(buf) {
+            Ok(res) => res,
+            Err(nom::Err::Incomplete(needed)) => {
+                return Err(ParseError::Incomplete(match needed {
+                    nom::Needed::Size(needed_len) => Some(needed_len),
+                    _ => None,
+                }))
+            }
+            Err(_) => return Err(ParseError::Error),
+        };
+
+        let consumed = buf.len() - remainder.len();
+
+        Ok((res, consumed))
+    }
+
     pub fn write<'b, W: std::io::Write + 'b>(self, w: &'b mut W) -> Result<(), WriteError>
     where
         'b: 'a,
@@ -323,11 +326,8 @@ impl<'a> DataRef<'a> {
     }
 
     pub fn write_len(&self) -> u64 {
-        match cookie_factory::gen(serializer::data(self.clone()), std::io::sink()) {
-            Ok((_w, pos)) => pos,
-            // This case can't really happen with our serializer!
-            Err(err) => panic!("Failed to calculate write length: {:?}", err),
-        }
+        // No need to go through the serializer for the fixed size framing
+        Data::frame_len(self.len()) as u64
     }
 
     #[allow(dead_code)]
@@ -367,6 +367,9 @@ impl<'a> AsRef<[u8]> for DataRef<'a> {
     }
 }
 
+/// Borrowed RTSP header name and value.
+///
+/// This is returned by [`Request::header_refs`] and [`Response::header_refs`].
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HeaderRef<'a> {
@@ -375,18 +378,18 @@ pub struct HeaderRef<'a> {
 }
 
 impl<'a> HeaderRef<'a> {
-    #[allow(dead_code)]
+    /// Header name.
     pub fn name(&self) -> &'a str {
         self.name
     }
 
-    #[allow(dead_code)]
+    /// Header value.
     pub fn value(&self) -> &'a str {
         self.value
     }
 
     #[allow(dead_code)]
-    pub fn set_value(&mut self, value: &'a str) {
+    pub(crate) fn set_value(&mut self, value: &'a str) {
         self.value = value;
     }
 }
@@ -0,0 +1,165 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+use std::io;
+
+/// Resumable writer for a message.
+///
+/// The message is serialized once into an internal buffer when creating the writer. Afterwards
+/// [`write_more`](#method.write_more) can be called repeatedly, e.g. whenever a non-blocking
+/// socket is writable again, and continues where the previous call stopped.
+///
+/// ## Writing to a non-blocking socket
+///
+/// ```rust,no_run
+/// # fn main() -> std::io::Result<()> {
+/// let request: rtsp_types::Message<Vec<u8>> = rtsp_types::Request::builder(
+///         rtsp_types::Method::Options,
+///         rtsp_types::Version::V2_0
+///     )
+///     .header(rtsp_types::headers::CSEQ, "1")
+///     .build(Vec::new())
+///     .into();
+///
+/// let mut socket = std::net::TcpStream::connect("127.0.0.1:554")?;
+/// socket.set_nonblocking(true)?;
+///
+/// let mut writer = rtsp_types::MessageWriter::new(&request);
+/// while !writer.write_more(&mut socket)? {
+///     // Wait until the socket is writable again
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MessageWriter {
+    buf: Vec<u8>,
+    pos: usize,
+}
+
+impl MessageWriter {
+    /// Creates a new writer for the message.
+    pub fn new<Body: AsRef<[u8]>>(message: &Message<Body>) -> Self {
+        Self::with_options(message, &WriteOptions::default())
+    }
+
+    /// Creates a new writer for the message with the given options.
+    pub fn with_options<Body: AsRef<[u8]>>(
+        message: &Message<Body>,
+        options: &WriteOptions,
+    ) -> Self {
+        let mut buf = Vec::with_capacity(message.write_len_with_options(options) as usize);
+        message
+            .write_with_options(&mut buf, options)
+            .expect("Writing to a Vec can't fail");
+
+        MessageWriter { buf, pos: 0 }
+    }
+
+    /// Writes as much of the remaining message as possible.
+    ///
+    /// Returns `Ok(true)` once the whole message is written and `Ok(false)` if the writer
+    /// returned `std::io::ErrorKind::WouldBlock`. In the latter case this can be called again
+    /// later to resume writing. Interrupted writes are retried.
+    pub fn write_more<W: io::Write>(&mut self, w: &mut W) -> io::Result<bool> {
+        while self.pos < self.buf.len() {
+            match w.write(&self.buf[self.pos..]) {
+                Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+                Ok(len) => self.pos += len,
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => return Ok(false),
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => (),
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Checks if the whole message was written.
+    pub fn is_done(&self) -> bool {
+        self.pos == self.buf.len()
+    }
+
+    /// Number of bytes that were already written.
+    pub fn written(&self) -> usize {
+        self.pos
+    }
+
+    /// Number of bytes that still have to be written.
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Accepts at most a few bytes per call and every other call would block
+    struct SlowWriter {
+        data: Vec<u8>,
+        would_block: bool,
+    }
+
+    impl io::Write for SlowWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.would_block = !self.would_block;
+            if self.would_block {
+                return Err(io::ErrorKind::WouldBlock.into());
+            }
+
+            let len = std::cmp::min(buf.len(), 3);
+            self.data.extend_from_slice(&buf[..len]);
+            Ok(len)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_message_writer() {
+        let message: Message<Vec<u8>> = Request::builder(Method::SetParameter, Version::V2_0)
+            .header(crate::headers::CSEQ, "2")
+            .header(crate::headers::CONTENT_TYPE, "text/parameters")
+            .build(Vec::from(&b"barparam: barstuff"[..]))
+            .into();
+
+        let mut expected = Vec::new();
+        message.write(&mut expected).unwrap();
+
+        let mut w = SlowWriter {
+            data: Vec::new(),
+            would_block: false,
+        };
+        let mut writer = MessageWriter::new(&message);
+        assert_eq!(writer.remaining(), expected.len());
+
+        let mut calls = 0;
+        while !writer.write_more(&mut w).unwrap() {
+            assert!(!writer.is_done());
+            assert_eq!(writer.written(), w.data.len());
+            assert_eq!(&w.data[..], &expected[..writer.written()]);
+            calls += 1;
+        }
+
+        assert!(writer.is_done());
+        assert_eq!(writer.remaining(), 0);
+        assert_eq!(w.data, expected);
+        assert_eq!(calls, (expected.len() + 2) / 3);
+
+        // Writing again after finishing does nothing
+        assert!(writer.write_more(&mut w).unwrap());
+        assert_eq!(w.data, expected);
+
+        let mut buf = [0u8; 4];
+        let mut writer = MessageWriter::new(&message);
+        let err = writer.write_more(&mut &mut buf[..]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+        assert_eq!(writer.written(), 4);
+    }
+}
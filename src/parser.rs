@@ -9,13 +9,42 @@ use nom::bytes::streaming::{tag, take, take_until, take_while, take_while_m_n};
 use nom::character::streaming::{char, one_of};
 use nom::character::{is_alphanumeric, is_digit, is_space};
 use nom::combinator::{flat_map, map, map_res, opt, value};
+use nom::error::{ErrorKind, FromExternalError};
 use nom::multi::fold_many0;
-use nom::number::streaming::be_u16;
-use nom::sequence::{terminated, tuple};
-use nom::{Err, IResult, Needed};
+use nom::number::streaming::{be_u16, be_u8};
+use nom::sequence::tuple;
+use nom::{Err, Needed};
 use std::str;
 use tinyvec::TinyVec;
 
+// Error of the message parsers.
+//
+// In addition to the errors of the nom parsers this distinguishes lines in the header block that
+// are not a header, see `ParseError::InvalidHeader`.
+#[derive(Debug, PartialEq)]
+pub(crate) enum Error<I> {
+    Nom(nom::error::Error<I>),
+    InvalidHeader(I),
+}
+
+impl<I> nom::error::ParseError<I> for Error<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        Error::Nom(nom::error::Error::new(input, kind))
+    }
+
+    fn append(_input: I, _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<I, E> FromExternalError<I, E> for Error<I> {
+    fn from_external_error(input: I, kind: ErrorKind, _e: E) -> Self {
+        Error::Nom(nom::error::Error::new(input, kind))
+    }
+}
+
+type IResult<I, O> = nom::IResult<I, O, Error<I>>;
+
 fn token(input: &[u8]) -> IResult<&[u8], &[u8]> {
     fn is_token_char(i: u8) -> bool {
         is_alphanumeric(i) || b"!#$%&'*+-.^_`|~".contains(&i)
@@ -34,6 +63,26 @@ fn crlf(input: &[u8]) -> IResult<&[u8], ()> {
     value((), tag("\r\n"))(input)
 }
 
+// Matches and consumes a CRLF, or a bare LF if `accept_lf` is set
+fn line_ending(input: &[u8], accept_lf: bool) -> IResult<&[u8], ()> {
+    if accept_lf {
+        value((), alt((tag("\r\n"), tag("\n"))))(input)
+    } else {
+        crlf(input)
+    }
+}
+
+// Length of the line ending at the start of the input, if any
+fn line_ending_len(input: &[u8], accept_lf: bool) -> Option<usize> {
+    if input.starts_with(b"\r\n") {
+        Some(2)
+    } else if accept_lf && input.starts_with(b"\n") {
+        Some(1)
+    } else {
+        None
+    }
+}
+
 // Matches a run of printable ASCII characters
 fn vchar_1(input: &[u8]) -> IResult<&[u8], &[u8]> {
     fn is_vchar(i: u8) -> bool {
@@ -57,7 +106,7 @@ fn rtsp_version(input: &[u8]) -> IResult<&[u8], Version> {
     )(input)
 }
 
-fn request_line(input: &[u8]) -> IResult<&[u8], RequestLine> {
+fn request_line(input: &[u8], accept_lf: bool) -> IResult<&[u8], RequestLine<'_>> {
     map(
         tuple((
             map(map_res(token, str::from_utf8), MethodRef::from),
@@ -68,7 +117,7 @@ fn request_line(input: &[u8]) -> IResult<&[u8], RequestLine> {
             )),
             sp,
             rtsp_version,
-            crlf,
+            |i| line_ending(i, accept_lf),
         )),
         |(method, _, request_uri, _, version, _)| RequestLine {
             method,
@@ -82,7 +131,9 @@ fn from_digit(input: &str) -> Result<u16, std::num::ParseIntError> {
     str::parse::<u16>(input)
 }
 
-fn status_line(input: &[u8]) -> IResult<&[u8], StatusLine> {
+fn status_line(input: &[u8], accept_lf: bool) -> IResult<&[u8], StatusLine<'_>> {
+    let line_end: &[u8] = if accept_lf { b"\n" } else { b"\r\n" };
+
     map(
         tuple((
             rtsp_version,
@@ -92,27 +143,37 @@ fn status_line(input: &[u8]) -> IResult<&[u8], StatusLine> {
                 from_digit,
             ),
             sp,
-            map_res(take_until(&b"\r\n"[..]), str::from_utf8),
-            crlf,
+            map_res(take_until(line_end), str::from_utf8),
+            |i| line_ending(i, accept_lf),
         )),
         |(version, _, status, _, reason_phrase, _)| StatusLine {
             version,
             status: status.into(),
-            reason_phrase,
+            // Some servers send an additional CR before the line ending, which would otherwise
+            // end up in the reason phrase
+            reason_phrase: reason_phrase.trim_end_matches('\r'),
         },
     )(input)
 }
 
-fn header_value(i: &[u8]) -> IResult<&[u8], &[u8]> {
+// Control characters, including DEL
+fn is_ctl(i: u8) -> bool {
+    i < 32 || i == 127
+}
+
+fn header_value(i: &[u8], accept_lf: bool) -> IResult<&[u8], &[u8]> {
     // Header values can be split over multiple lines, in which case there
     // will be a CRLF followed by one or more spaces/tabs.
     let mut o = i;
     while !o.is_empty() {
-        if o.len() >= 3 && o.starts_with(b"\r\n") && (o[2] == b' ' || o[2] == b'\t') {
+        let line_end_len = line_ending_len(o, accept_lf);
+        if let Some(len) =
+            line_end_len.filter(|len| o.len() > *len && (o[*len] == b' ' || o[*len] == b'\t'))
+        {
             if let Some((no_space_pos, _)) = o
                 .iter()
                 .enumerate()
-                .skip(3)
+                .skip(len + 1)
                 .find(|(_, b)| **b != b' ' && **b != b'\t')
             {
                 // Header continues on the next line
@@ -121,8 +182,18 @@ fn header_value(i: &[u8]) -> IResult<&[u8], &[u8]> {
                 // Incomplete
                 o = &[];
             }
-        } else if !o.starts_with(b"\r\n") {
-            // Normal header character
+        } else if o == b"\r" {
+            // Incomplete, might be followed by a LF
+            o = &[];
+        } else if line_end_len.is_none() {
+            // Normal header character. Only visible characters, obs-text, SP and HTAB
+            // are allowed.
+            if is_ctl(o[0]) && o[0] != b'\t' {
+                return Err(Err::Failure(Error::Nom(nom::error::Error::new(
+                    o,
+                    ErrorKind::Char,
+                ))));
+            }
             o = &o[1..];
         } else {
             // Not a header character
@@ -152,45 +223,52 @@ fn header_value(i: &[u8]) -> IResult<&[u8], &[u8]> {
     Err(Err::Incomplete(Needed::Unknown))
 }
 
-fn message_header(input: &[u8]) -> IResult<&[u8], HeaderRef> {
+fn message_header(input: &[u8], accept_lf: bool) -> IResult<&[u8], HeaderRef<'_>> {
     map(
         tuple((
             map_res(token, str::from_utf8),
             char(':'),
             opt(take_while(is_space)),
-            map_res(header_value, str::from_utf8),
-            crlf,
+            map_res(|i| header_value(i, accept_lf), str::from_utf8),
+            |i| line_ending(i, accept_lf),
         )),
         |(name, _, _, value, _)| HeaderRef { name, value },
     )(input)
 }
 
-fn headers(input: &[u8]) -> IResult<&[u8], TinyVec<[HeaderRef; 16]>> {
-    terminated(many0_tinyvec(message_header), crlf)(input)
+fn headers(input: &[u8], accept_lf: bool) -> IResult<&[u8], TinyVec<[HeaderRef<'_>; 16]>> {
+    let (input, headers) = many0_tinyvec(|i| message_header(i, accept_lf))(input)?;
+
+    // Anything but the empty line after the headers is an invalid header line. Fail instead of
+    // trying to parse the message differently.
+    match line_ending(input, accept_lf) {
+        Ok((input, _)) => Ok((input, headers)),
+        Err(Err::Error(_)) => Err(Err::Failure(Error::InvalidHeader(input))),
+        Err(err) => Err(err),
+    }
 }
 
-fn content_length<'a>(
-    headers: &[HeaderRef<'a>],
-) -> Result<usize, nom::Err<nom::error::Error<&'a [u8]>>> {
+fn content_length<'a>(headers: &[HeaderRef<'a>]) -> Result<usize, Err<Error<&'a [u8]>>> {
     if let Some(h) = headers
         .iter()
         .find(|h| &h.name.to_ascii_uppercase() == "CONTENT-LENGTH")
     {
-        return str::parse::<usize>(h.value).map_err(|_| {
-            nom::Err::Failure(nom::error::Error::new(
+        // Some devices send whitespace or a leading `+` (which `usize::from_str` accepts), and
+        // failing here would abort parsing of the whole message
+        return str::parse::<usize>(h.value.trim()).map_err(|_| {
+            Err::Failure(Error::Nom(nom::error::Error::new(
                 h.value.as_bytes(),
-                nom::error::ErrorKind::MapRes,
-            ))
+                ErrorKind::MapRes,
+            )))
         });
     }
     Ok(0)
 }
 
-fn request(input: &[u8]) -> IResult<&[u8], RequestRef> {
-    let (input, request_line) = request_line(input)?;
-    let (input, headers) = headers(input)?;
-    let content_length = content_length(&headers)?;
-    let (input, body) = take(content_length)(input)?;
+// Request line and headers, with an empty body.
+fn request_head(input: &[u8], accept_lf: bool) -> IResult<&[u8], RequestRef<'_>> {
+    let (input, request_line) = request_line(input, accept_lf)?;
+    let (input, headers) = headers(input, accept_lf)?;
 
     Ok((
         input,
@@ -199,16 +277,24 @@ fn request(input: &[u8]) -> IResult<&[u8], RequestRef> {
             request_uri: request_line.request_uri,
             version: request_line.version,
             headers,
-            body,
+            body: &[],
         },
     ))
 }
 
-fn response(input: &[u8]) -> IResult<&[u8], ResponseRef> {
-    let (input, status_line) = status_line(input)?;
-    let (input, headers) = headers(input)?;
-    let content_length = content_length(&headers)?;
+fn request(input: &[u8], accept_lf: bool) -> IResult<&[u8], RequestRef<'_>> {
+    let (input, mut request) = request_head(input, accept_lf)?;
+    let content_length = content_length(&request.headers)?;
     let (input, body) = take(content_length)(input)?;
+    request.body = body;
+
+    Ok((input, request))
+}
+
+// Status line and headers, with an empty body.
+fn response_head(input: &[u8], accept_lf: bool) -> IResult<&[u8], ResponseRef<'_>> {
+    let (input, status_line) = status_line(input, accept_lf)?;
+    let (input, headers) = headers(input, accept_lf)?;
 
     Ok((
         input,
@@ -217,29 +303,175 @@ fn response(input: &[u8]) -> IResult<&[u8], ResponseRef> {
             status: status_line.status,
             reason_phrase: status_line.reason_phrase,
             headers,
-            body,
+            body: &[],
         },
     ))
 }
 
-fn data(input: &[u8]) -> IResult<&[u8], DataRef> {
+fn response(input: &[u8], accept_lf: bool) -> IResult<&[u8], ResponseRef<'_>> {
+    let (input, mut response) = response_head(input, accept_lf)?;
+    let content_length = content_length(&response.headers)?;
+    let (input, body) = take(content_length)(input)?;
+    response.body = body;
+
+    Ok((input, response))
+}
+
+pub(crate) fn data(input: &[u8]) -> IResult<&[u8], DataRef<'_>> {
     map(
-        tuple((char('$'), take(1usize), flat_map(be_u16, take))),
-        |(_, channel_id, body): (_, &[u8], _)| DataRef {
-            channel_id: channel_id[0],
-            body,
+        tuple((char('$'), be_u8, flat_map(be_u16, take))),
+        |(_, channel_id, body)| DataRef { channel_id, body },
+    )(input)
+}
+
+// Fails if the request or status line at the start of the message, after any empty lines, is
+// longer than `max_len` bytes. Data messages have no start line and are not checked.
+pub(crate) fn check_start_line_len(
+    mut input: &[u8],
+    max_len: usize,
+    accept_lf: bool,
+) -> Result<(), ParseError> {
+    while let Some(len) = line_ending_len(input, accept_lf) {
+        input = &input[len..];
+    }
+
+    if input.starts_with(b"$") {
+        return Ok(());
+    }
+
+    // A CR, or LF, right after `max_len` bytes still ends a line of the maximum length
+    let end = std::cmp::min(input.len(), max_len.saturating_add(1));
+    if input.len() > max_len
+        && !input[..end]
+            .iter()
+            .any(|b| *b == b'\r' || (accept_lf && *b == b'\n'))
+    {
+        return Err(ParseError::LimitExceeded);
+    }
+
+    Ok(())
+}
+
+// Checks if the message has an SDP body but no `Content-Length` header.
+fn has_sdp_body_without_content_length(headers: &[HeaderRef<'_>]) -> bool {
+    !headers
+        .iter()
+        .any(|h| h.name.eq_ignore_ascii_case("Content-Length"))
+        && headers.iter().any(|h| {
+            h.name.eq_ignore_ascii_case("Content-Type")
+                && h.value
+                    .split(';')
+                    .next()
+                    .unwrap()
+                    .trim()
+                    .eq_ignore_ascii_case("application/sdp")
+        })
+}
+
+// Checks if a line, without line ending, looks like the start of a new message or a keep-alive.
+fn is_message_start(line: &[u8]) -> bool {
+    if line.is_empty() || line.starts_with(b"$") || line.starts_with(b"RTSP/") {
+        return true;
+    }
+
+    // Request line, i.e. `METHOD URI RTSP/x.y`
+    let mut parts = line.split(|b| *b == b' ');
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(method), Some(uri), Some(version), None) => {
+            !method.is_empty() && !uri.is_empty() && version.starts_with(b"RTSP/")
+        }
+        _ => false,
+    }
+}
+
+// Length of an SDP body that is not delimited by a `Content-Length` header.
+//
+// The body ends before the first line that looks like the start of a new message or interleaved
+// data, or at the end of the input if it ends with a complete line.
+fn sdp_body_len(input: &[u8]) -> Result<usize, ParseError> {
+    let mut pos = 0;
+    while let Some(line_len) = input[pos..].iter().position(|b| *b == b'\n') {
+        let line = &input[pos..(pos + line_len)];
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        if is_message_start(line) {
+            return Ok(pos);
+        }
+        pos += line_len + 1;
+    }
+
+    // Interleaved data has no line ending
+    if input[pos..].starts_with(b"$") {
+        return Ok(pos);
+    }
+
+    if pos == 0 || pos < input.len() {
+        return Err(ParseError::Incomplete(None));
+    }
+
+    Ok(pos)
+}
+
+// Extends an empty body of a request or response with an SDP `Content-Type` but without a
+// `Content-Length` header to the following SDP lines.
+pub(crate) fn sdp_body_without_content_length<'a>(
+    msg: &mut MessageRef<'a>,
+    remainder: &mut &'a [u8],
+) -> Result<(), ParseError> {
+    let body = match msg {
+        MessageRef::Request(ref mut request)
+            if has_sdp_body_without_content_length(&request.headers) =>
+        {
+            &mut request.body
+        }
+        MessageRef::Response(ref mut response)
+            if has_sdp_body_without_content_length(&response.headers) =>
+        {
+            &mut response.body
+        }
+        _ => return Ok(()),
+    };
+
+    let len = sdp_body_len(remainder)?;
+    let (new_body, new_remainder) = remainder.split_at(len);
+    *body = new_body;
+    *remainder = new_remainder;
+
+    Ok(())
+}
+
+pub(crate) fn message(input: &[u8], accept_lf: bool) -> IResult<&[u8], MessageRef<'_>> {
+    flat_map(
+        fold_many0(|i| line_ending(i, accept_lf), || (), |_acc, _item| ()),
+        |_| {
+            alt((
+                map(data, MessageRef::Data),
+                map(|i| request(i, accept_lf), MessageRef::Request),
+                map(|i| response(i, accept_lf), MessageRef::Response),
+            ))
         },
     )(input)
 }
 
-pub(crate) fn message(input: &[u8]) -> IResult<&[u8], MessageRef> {
-    flat_map(fold_many0(crlf, || (), |_acc, _item| ()), |_| {
-        alt((
-            map(data, MessageRef::Data),
-            map(request, MessageRef::Request),
-            map(response, MessageRef::Response),
-        ))
-    })(input)
+// Start line and headers of a request or response, without the body.
+pub(crate) fn message_head(input: &[u8], accept_lf: bool) -> IResult<&[u8], MessageRef<'_>> {
+    let (input, head) = flat_map(
+        fold_many0(|i| line_ending(i, accept_lf), || (), |_acc, _item| ()),
+        |_| {
+            alt((
+                map(|i| request_head(i, accept_lf), MessageRef::Request),
+                map(|i| response_head(i, accept_lf), MessageRef::Response),
+            ))
+        },
+    )(input)?;
+
+    // Fail the same way as for the complete message if the body length is invalid
+    match head {
+        MessageRef::Request(ref request) => content_length(&request.headers)?,
+        MessageRef::Response(ref response) => content_length(&response.headers)?,
+        MessageRef::Data(_) => unreachable!(),
+    };
+
+    Ok((input, head))
 }
 
 #[cfg(test)]
@@ -251,7 +483,10 @@ mod tests {
     #[test]
     fn test_request_line() {
         assert_eq!(
-            request_line(b"OPTIONS rtsp://media.example.com/movie/twister.3gp RTSP/2.0\r\n"),
+            request_line(
+                b"OPTIONS rtsp://media.example.com/movie/twister.3gp RTSP/2.0\r\n",
+                false
+            ),
             Ok((
                 &b""[..],
                 RequestLine {
@@ -266,7 +501,7 @@ mod tests {
     #[test]
     fn test_request_line_without_uri() {
         assert_eq!(
-            request_line(b"OPTIONS * RTSP/2.0\r\n"),
+            request_line(b"OPTIONS * RTSP/2.0\r\n", false),
             Ok((
                 &b""[..],
                 RequestLine {
@@ -281,7 +516,7 @@ mod tests {
     #[test]
     fn test_status_line() {
         assert_eq!(
-            status_line(b"RTSP/2.0 200 All Good\r\n"),
+            status_line(b"RTSP/2.0 200 All Good\r\n", false),
             Ok((
                 &b""[..],
                 StatusLine {
@@ -293,6 +528,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_status_line_trailing_cr() {
+        for line in [
+            &b"RTSP/2.0 200 All Good\r\r\n"[..],
+            b"RTSP/2.0 200 All Good\r\r\r\n",
+        ] {
+            assert_eq!(
+                status_line(line, false),
+                Ok((
+                    &b""[..],
+                    StatusLine {
+                        version: Version::V2_0,
+                        status: StatusCode::Ok,
+                        reason_phrase: "All Good",
+                    }
+                ))
+            );
+        }
+
+        let (message, _) =
+            Message::<&[u8]>::parse(b"RTSP/2.0 200 OK\r\r\nCSeq: 1\r\n\r\n").unwrap();
+        match message {
+            Message::Response(response) => assert_eq!(response.reason_phrase(), "OK"),
+            _ => unreachable!(),
+        }
+    }
+
     #[test]
     fn test_options() {
         assert_eq!(
@@ -302,7 +564,8 @@ mod tests {
         Supported: play.basic, play.scale\r\n\
         User-Agent: PhonyClient/1.2\r\n\
         \r\n\
-        REMAINDER"
+        REMAINDER",
+                false
             ),
             Ok((
                 &b"REMAINDER"[..],
@@ -339,9 +602,13 @@ CSeq: 1\r\n\
 Supported: play.basic,\r\n play.scale\r\n\
 User-Agent: PhonyClient/1.2\r\n\
 \r\n\
-REMAINDER"
+REMAINDER",
+                false
             )
-            .map(|(rem, req)| (rem, RequestRef::to_owned(&req).unwrap())),
+            .map(|(rem, req)| (
+                rem,
+                RequestRef::to_owned(&req, &ParseOptions::default()).unwrap()
+            )),
             Ok((
                 &b"REMAINDER"[..],
                 Request::builder(Method::Options, Version::V2_0)
@@ -363,9 +630,13 @@ CSeq: 1 \r\n\
 Supported: \tplay.basic,\r\n play.scale\r\n\
 User-Agent: PhonyClient/1.2\t\r\n\
 \r\n\
-REMAINDER"
+REMAINDER",
+                false
             )
-            .map(|(rem, req)| (rem, RequestRef::to_owned(&req).unwrap())),
+            .map(|(rem, req)| (
+                rem,
+                RequestRef::to_owned(&req, &ParseOptions::default()).unwrap()
+            )),
             Ok((
                 &b"REMAINDER"[..],
                 Request::builder(Method::Options, Version::V2_0)
@@ -387,7 +658,8 @@ CSeq: 1\r\n\
 Supported: play.basic, play.scale\r\n\
 User-Agent: PhonyClient/1.2\r\n\
 \r\n\
-REMAINDER"
+REMAINDER",
+                false
             ),
             Ok((
                 &b"REMAINDER"[..],
@@ -426,7 +698,8 @@ User-Agent: PhonyClient/1.2\r\n\
 Content-Length: 10\r\n\
 \r\n\
 0123456789\
-REMAINDER"
+REMAINDER",
+                false
             ),
             Ok((
                 &b"REMAINDER"[..],
@@ -469,7 +742,8 @@ User-Agent: PhonyClient/1.2\r\n\
 Content-Length: 10\r\n\
 \r\n\
 0123456789\
-REMAINDER"
+REMAINDER",
+                false
             ),
             Ok((
                 &b"REMAINDER"[..],
@@ -510,7 +784,8 @@ CSeq: 1\r\n\
 Supported: play.basic, play.scale\r\n\
 User-Agent: PhonyClient/1.2\r\n\
 \r\n\
-REMAINDER"
+REMAINDER",
+                false
             ),
             Ok((
                 &b"REMAINDER"[..],
@@ -549,7 +824,8 @@ User-Agent: PhonyClient/1.2\r\n\
 Content-Length: 10\r\n\
 \r\n\
 0123456789\
-REMAINDER"
+REMAINDER",
+                false
             ),
             Ok((
                 &b"REMAINDER"[..],
@@ -592,10 +868,62 @@ User-Agent: PhonyClient/1.2\r\n\
 Content-Length: bad\r\n\
 \r\n\
 0123456789\
-REMAINDER"
+REMAINDER",
+                false
+            ),
+            Err(nom::Err::Failure(_))
+        ));
+    }
+
+    #[test]
+    fn test_header_value_control_chars() {
+        assert!(matches!(
+            request(
+                b"OPTIONS * RTSP/2.0\r\n\
+CSeq: 1\r\n\
+User-Agent: Phony\0Client/1.2\r\n\
+\r\n",
+                false
             ),
             Err(nom::Err::Failure(_))
         ));
+
+        assert!(matches!(
+            request(
+                b"OPTIONS * RTSP/2.0\r\n\
+CSeq: 1\r\n\
+User-Agent: PhonyClient/1.2\rInjected: 1\r\n\
+\r\n",
+                false
+            ),
+            Err(nom::Err::Failure(_))
+        ));
+
+        assert_eq!(
+            request(
+                b"OPTIONS * RTSP/2.0\r\n\
+CSeq: 1\r\n\
+User-Agent: PhonyClient\t1.2\r\n\
+\r\n",
+                false
+            )
+            .map(|(rem, req)| (
+                rem,
+                RequestRef::to_owned(&req, &ParseOptions::default()).unwrap()
+            )),
+            Ok((
+                &b""[..],
+                Request::builder(Method::Options, Version::V2_0)
+                    .header(crate::headers::CSEQ, "1")
+                    .header(crate::headers::USER_AGENT, "PhonyClient\t1.2")
+                    .build(vec![])
+            ))
+        );
+
+        assert!(matches!(
+            header_value(b"PhonyClient/1.2\r", false),
+            Err(nom::Err::Incomplete(_))
+        ));
     }
 
     #[test]
@@ -608,4 +936,697 @@ REMAINDER"
             ))
         );
     }
+
+    #[test]
+    fn test_parse_utf8() {
+        let input = b"SET_PARAMETER rtsp://example.com/test RTSP/2.0\r\n\
+Content-Length: 19\r\n\
+CSeq: 2\r\n\
+\r\n\
+barparam: b\xc3\xa4rstuff";
+
+        let (message, consumed) = Message::<&str>::parse_utf8(&input[..]).unwrap();
+        assert_eq!(consumed, input.len());
+        match message {
+            Message::Request(request) => {
+                assert_eq!(request.method(), Method::SetParameter);
+                assert_eq!(*request.body(), "barparam: bärstuff");
+            }
+            _ => unreachable!(),
+        }
+
+        let input = b"RTSP/2.0 200 OK\r\n\
+Content-Length: 3\r\n\
+\r\n\
+a\xffb";
+        assert!(matches!(
+            Message::<&str>::parse_utf8(&input[..]),
+            Err(ParseError::InvalidUtf8(len)) if len == input.len()
+        ));
+
+        // Interleaved binary data can be skipped
+        let input = b"$\x01\x00\x03a\xffbRTSP/2.0 200 OK\r\n\r\n";
+        assert!(matches!(
+            Message::<&str>::parse_utf8(&input[..]),
+            Err(ParseError::InvalidUtf8(7))
+        ));
+        let (message, consumed) = Message::<&str>::parse_utf8(&input[7..]).unwrap();
+        assert_eq!(consumed, input.len() - 7);
+        assert!(message.is_response());
+
+        let input = b"$\x01\x00\x03abc";
+        let (message, _) = Message::<&str>::parse_utf8(&input[..]).unwrap();
+        match message {
+            Message::Data(data) => assert_eq!(data.into_body(), "abc"),
+            _ => unreachable!(),
+        }
+
+        let input = b"RTSP/2.0 200 OK\r\n\r\n";
+        let (message, _) = Message::<&str>::parse_utf8(&input[..]).unwrap();
+        match message {
+            Message::Response(response) => assert_eq!(*response.body(), ""),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_data_empty() {
+        assert_eq!(
+            data(&[b'$', 3, 0, 0, b'$'][..]),
+            Ok((&b"$"[..], DataRef::from_slice(3, &[]))),
+        );
+
+        let (data, consumed) = Data::<Vec<u8>>::parse(&[b'$', 3, 0, 0][..]).unwrap();
+        assert_eq!(consumed, 4);
+        assert!(data.is_empty());
+
+        let mut v = Vec::new();
+        data.write(&mut v).unwrap();
+        assert_eq!(v, [b'$', 3, 0, 0]);
+        assert_eq!(data.write_len(), 4);
+    }
+
+    #[test]
+    fn test_data_owned() {
+        let input = [b'$', 12, 0, 10, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, b'a', b'b'];
+
+        let (data, consumed) = Data::<Vec<u8>>::parse(&input).unwrap();
+        assert_eq!(consumed, 14);
+        assert_eq!(data, Data::new(12, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]));
+
+        assert!(matches!(
+            Data::<Vec<u8>>::parse(&input[..8]),
+            Err(ParseError::Incomplete(Some(needed))) if needed.get() == 6
+        ));
+        assert!(matches!(
+            Data::<Vec<u8>>::parse(
+                &b"OPTIONS * RTSP/2.0
+
+"[..]
+            ),
+            Err(ParseError::Error)
+        ));
+    }
+
+    #[test]
+    fn test_parse_remaining() {
+        let buf =
+            b"$\x01\x00\x04abcdRTSP/2.0 200 OK\r\nCSeq: 1\r\nContent-Length: 2\r\n\r\nxy$\x00\x00";
+
+        let (msg, consumed) = Message::<Vec<u8>>::parse(buf).unwrap();
+        let (msg_remaining, remaining) = Message::<Vec<u8>>::parse_remaining(buf).unwrap();
+        assert_eq!(msg_remaining, msg);
+        assert_eq!(remaining, &buf[consumed..]);
+
+        let (msg, consumed) = Message::<Vec<u8>>::parse(remaining).unwrap();
+        let (msg_remaining, tail) = Message::<Vec<u8>>::parse_remaining(remaining).unwrap();
+        assert_eq!(msg_remaining, msg);
+        assert_eq!(tail, &remaining[consumed..]);
+        assert_eq!(tail, b"$\x00\x00");
+
+        assert!(matches!(
+            Message::<Vec<u8>>::parse_remaining(tail),
+            Err(ParseError::Incomplete(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_frame() {
+        let buf = b"$\x01\x00\x04abcdRTSP/2.0 200 OK\r\nCSeq: 1\r\nContent-Length: 2\r\n\r\nxy$\x00\x00\x00";
+
+        let (msg, frame) = Message::<&[u8]>::parse_frame(buf).unwrap();
+        let msg = msg.unwrap();
+        assert_eq!(msg.frame_kind(), FrameKind::Data);
+        assert_eq!(
+            frame,
+            Frame {
+                kind: FrameKind::Data,
+                span: 0..8,
+                body: 4..8,
+                keep_alives: 0,
+            }
+        );
+        assert_eq!(&buf[frame.body], b"abcd");
+
+        let buf = &buf[frame.span.end..];
+        let (msg, frame) = Message::<&[u8]>::parse_frame(buf).unwrap();
+        let msg = msg.unwrap();
+        assert_eq!(msg.frame_kind(), FrameKind::Response);
+        assert_eq!(frame.kind, FrameKind::Response);
+        assert_eq!(frame.span, 0..(buf.len() - 4));
+        assert_eq!(&buf[frame.body], b"xy");
+
+        let buf = &buf[frame.span.end..];
+        let (msg, frame) = Message::<&[u8]>::parse_frame(buf).unwrap();
+        let msg = msg.unwrap();
+        assert_eq!(msg.frame_kind(), FrameKind::Data);
+        assert_eq!(frame.span, 0..4);
+        assert!(frame.body.is_empty());
+
+        assert!(matches!(
+            Message::<&[u8]>::parse_frame(b"OPTIONS * RTSP/2.0\r\n"),
+            Err(ParseError::Incomplete(_))
+        ));
+    }
+
+    #[test]
+    fn test_message_kind() {
+        let (msg, _) = Message::<&[u8]>::parse(b"OPTIONS * RTSP/2.0\r\nCSeq: 1\r\n\r\n").unwrap();
+        assert_eq!(msg.frame_kind(), FrameKind::Request);
+        assert!(msg.is_request());
+        assert!(!msg.is_response());
+        assert!(!msg.is_data());
+
+        let (msg, _) = Message::<&[u8]>::parse(b"RTSP/2.0 200 OK\r\nCSeq: 1\r\n\r\n").unwrap();
+        assert_eq!(msg.frame_kind(), FrameKind::Response);
+        assert!(!msg.is_request());
+        assert!(msg.is_response());
+        assert!(!msg.is_data());
+
+        let (msg, _) = Message::<&[u8]>::parse(b"$\x00\x00\x01a").unwrap();
+        assert_eq!(msg.frame_kind(), FrameKind::Data);
+        assert!(!msg.is_request());
+        assert!(!msg.is_response());
+        assert!(msg.is_data());
+    }
+
+    #[test]
+    fn test_message_map_body() {
+        for buf in [
+            &b"SET_PARAMETER * RTSP/2.0\r\nCSeq: 1\r\nContent-Length: 5\r\n\r\nhello"[..],
+            &b"RTSP/2.0 200 OK\r\nCSeq: 1\r\nContent-Length: 5\r\n\r\nhello"[..],
+            &b"$\x01\x00\x05hello"[..],
+        ] {
+            let (msg, _) = Message::<Vec<u8>>::parse(buf).unwrap();
+            let kind = msg.frame_kind();
+
+            let msg = msg.map_body(|body| String::from_utf8(body).unwrap().to_uppercase() + "!");
+            assert_eq!(msg.frame_kind(), kind);
+
+            let mut data = Vec::new();
+            msg.write(&mut data).unwrap();
+            let (msg, _) = Message::<&[u8]>::parse(&data).unwrap();
+            match msg {
+                Message::Request(ref request) => {
+                    assert_eq!(request.content_length(), Some(6));
+                }
+                Message::Response(ref response) => {
+                    assert_eq!(response.content_length(), Some(6));
+                }
+                Message::Data(ref data) => {
+                    assert_eq!(data.channel_id(), 1);
+                }
+            }
+            assert_eq!(msg.into_body(), b"HELLO!");
+        }
+    }
+
+    #[test]
+    fn test_parse_frame_keep_alive() {
+        let buf = b"\r\n\r\nOPTIONS * RTSP/2.0\r\nCSeq: 1\r\n\r\n\r\n";
+
+        let (msg, frame) = Message::<&[u8]>::parse_frame(buf).unwrap();
+        let msg = msg.unwrap();
+        assert_eq!(msg.frame_kind(), FrameKind::Request);
+        assert_eq!(frame.keep_alives, 2);
+        assert_eq!(frame.span, 4..(buf.len() - 2));
+        assert_eq!(
+            &buf[frame.span.clone()],
+            b"OPTIONS * RTSP/2.0\r\nCSeq: 1\r\n\r\n"
+        );
+        assert!(frame.body.is_empty());
+
+        // Keep-alives without a following complete message form their own frame
+        let buf = &buf[frame.span.end..];
+        let (msg, frame) = Message::<&[u8]>::parse_frame(buf).unwrap();
+        assert!(msg.is_none());
+        assert_eq!(
+            frame,
+            Frame {
+                kind: FrameKind::KeepAlive,
+                span: 0..2,
+                body: 2..2,
+                keep_alives: 1,
+            }
+        );
+
+        let buf = b"\r\n\r\nOPTIONS * RTSP/2.0\r\n";
+        let (msg, frame) = Message::<&[u8]>::parse_frame(buf).unwrap();
+        assert!(msg.is_none());
+        assert_eq!(frame.kind, FrameKind::KeepAlive);
+        assert_eq!(frame.span, 0..4);
+        assert!(matches!(
+            Message::<&[u8]>::parse_frame(&buf[frame.span.end..]),
+            Err(ParseError::Incomplete(_))
+        ));
+
+        for buf in [&b""[..], b"\r"] {
+            assert!(matches!(
+                Message::<&[u8]>::parse_frame(buf),
+                Err(ParseError::Incomplete(_))
+            ));
+        }
+
+        let buf = b"\r\n$\x00\x00\x01a";
+        let (_, frame) = Message::<&[u8]>::parse_frame(buf).unwrap();
+        assert_eq!(frame.kind, FrameKind::Data);
+        assert_eq!(frame.keep_alives, 1);
+        assert_eq!(frame.span, 2..7);
+        assert_eq!(frame.body, 6..7);
+    }
+
+    #[test]
+    fn test_start_line_limit() {
+        let uri = format!("rtsp://example.com/{}", "a".repeat(100));
+        let request = format!("OPTIONS {uri} RTSP/2.0\r\nCSeq: 1\r\n\r\n");
+        let line_len = request.find("\r\n").unwrap();
+
+        let options = ParseOptions::new().max_start_line_len(line_len);
+        let (msg, consumed) =
+            Message::<&[u8]>::parse_with_options(request.as_bytes(), &options).unwrap();
+        assert_eq!(msg.frame_kind(), FrameKind::Request);
+        assert_eq!(consumed, request.len());
+
+        let options = ParseOptions::new().max_start_line_len(line_len - 1);
+        assert!(matches!(
+            Message::<&[u8]>::parse_with_options(request.as_bytes(), &options),
+            Err(ParseError::LimitExceeded)
+        ));
+
+        // Incomplete lines are rejected as soon as they are too long
+        assert!(matches!(
+            Message::<&[u8]>::parse_with_options(&request.as_bytes()[..50], &options),
+            Err(ParseError::Incomplete(_))
+        ));
+        let options = ParseOptions::new().max_start_line_len(40);
+        assert!(matches!(
+            Message::<&[u8]>::parse_with_options(&request.as_bytes()[..50], &options),
+            Err(ParseError::LimitExceeded)
+        ));
+
+        // Data messages have no start line
+        let options = ParseOptions::new().max_start_line_len(1);
+        assert!(Message::<&[u8]>::parse_with_options(b"\r\n$\x00\x00\x04abcd", &options).is_ok());
+
+        // Default limit
+        let uri = format!(
+            "rtsp://example.com/{}",
+            "a".repeat(ParseOptions::DEFAULT_MAX_START_LINE_LEN)
+        );
+        let request = format!("OPTIONS {uri} RTSP/2.0\r\nCSeq: 1\r\n\r\n");
+        assert!(matches!(
+            Message::<&[u8]>::parse(request.as_bytes()),
+            Err(ParseError::LimitExceeded)
+        ));
+    }
+
+    #[test]
+    fn test_raw_header_values() {
+        use crate::headers::{Headers, CSEQ, SUPPORTED};
+
+        let buf = b"OPTIONS * RTSP/2.0\r\n\
+                    CSeq: 1\r\n\
+                    Supported: play.basic,\r\n \t play.scale \r\n\
+                    Supported: setup.rtp.rtcp.mux\r\n\
+                    \r\n";
+
+        let (msg, _) = Message::<&[u8]>::parse(buf).unwrap();
+        let request = match msg {
+            Message::Request(request) => request,
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            request.header(&SUPPORTED).unwrap().as_str(),
+            "play.basic, play.scale, setup.rtp.rtcp.mux"
+        );
+        assert_eq!(
+            AsRef::<Headers>::as_ref(&request).raw_value(&SUPPORTED),
+            None
+        );
+
+        let options = ParseOptions::new().preserve_raw_headers(true);
+        let (msg, _) = Message::<&[u8]>::parse_with_options(buf, &options).unwrap();
+        let mut request2 = match msg {
+            Message::Request(request) => request,
+            _ => unreachable!(),
+        };
+        assert_eq!(request2, request);
+
+        let headers = AsRef::<Headers>::as_ref(&request2);
+        assert_eq!(headers.raw_value(&CSEQ), Some(&b"1"[..]));
+        assert_eq!(
+            headers.raw_value(&SUPPORTED),
+            Some(&b"play.basic,\r\n \t play.scale"[..])
+        );
+        assert_eq!(
+            headers.raw_values(&SUPPORTED).collect::<Vec<_>>(),
+            [&b"play.basic,\r\n \t play.scale"[..], b"setup.rtp.rtcp.mux"]
+        );
+
+        // Modifying a header discards its raw value
+        request2.append_header(SUPPORTED, "play.speed");
+        assert_eq!(
+            AsRef::<Headers>::as_ref(&request2).raw_value(&SUPPORTED),
+            None
+        );
+        assert_eq!(
+            AsRef::<Headers>::as_ref(&request2).raw_value(&CSEQ),
+            Some(&b"1"[..])
+        );
+        request2.remove_header(&CSEQ);
+        assert_eq!(AsRef::<Headers>::as_ref(&request2).raw_value(&CSEQ), None);
+    }
+
+    #[test]
+    fn test_sdp_body_without_content_length() {
+        let sdp = b"v=0\r\n\
+                    o=- 0 0 IN IP4 127.0.0.1\r\n\
+                    s=Test\r\n\
+                    m=video 0 RTP/AVP 96\r\n";
+        let request = b"ANNOUNCE rtsp://example.com/test RTSP/1.0\r\n\
+                        CSeq: 2\r\n\
+                        Content-Type: application/sdp\r\n\
+                        \r\n";
+        let next = b"RECORD rtsp://example.com/test RTSP/1.0\r\nCSeq: 3\r\n\r\n";
+
+        let mut buf = request.to_vec();
+        buf.extend_from_slice(sdp);
+        buf.extend_from_slice(next);
+
+        // By default the body is empty and the SDP is not a valid message
+        let (msg, consumed) = Message::<&[u8]>::parse(&buf).unwrap();
+        assert_eq!(consumed, request.len());
+        match msg {
+            Message::Request(request) => assert!(request.body().is_empty()),
+            _ => unreachable!(),
+        }
+
+        let options = ParseOptions::new().sdp_body_without_content_length(true);
+        let (msg, consumed) = Message::<&[u8]>::parse_with_options(&buf, &options).unwrap();
+        assert_eq!(consumed, request.len() + sdp.len());
+        match msg {
+            Message::Request(request) => assert_eq!(*request.body(), &sdp[..]),
+            _ => unreachable!(),
+        }
+        let (msg, consumed) =
+            Message::<&[u8]>::parse_with_options(&buf[consumed..], &options).unwrap();
+        assert_eq!(consumed, next.len());
+        assert!(msg.is_request());
+
+        // Body until the end of the input if it consists of complete lines
+        let buf = &buf[..(request.len() + sdp.len())];
+        let (msg, consumed) = Message::<&[u8]>::parse_with_options(buf, &options).unwrap();
+        assert_eq!(consumed, buf.len());
+        match msg {
+            Message::Request(request) => assert_eq!(*request.body(), &sdp[..]),
+            _ => unreachable!(),
+        }
+        for len in [request.len(), request.len() + 2] {
+            assert!(matches!(
+                Message::<&[u8]>::parse_with_options(&buf[..len], &options),
+                Err(ParseError::Incomplete(_))
+            ));
+        }
+
+        // Body until interleaved data or an empty line
+        for delimiter in [&b"$\x00\x00\x00"[..], b"\r\n"] {
+            let mut buf = buf.to_vec();
+            buf.extend_from_slice(delimiter);
+            let (_, consumed) = Message::<&[u8]>::parse_with_options(&buf, &options).unwrap();
+            assert_eq!(consumed, request.len() + sdp.len());
+        }
+
+        // Messages with Content-Length or other content types are not affected
+        let buf = b"ANNOUNCE rtsp://example.com/test RTSP/1.0\r\n\
+                    CSeq: 2\r\n\
+                    Content-Type: text/parameters\r\n\
+                    \r\n\
+                    v=0\r\n";
+        let (_, consumed) = Message::<&[u8]>::parse_with_options(buf, &options).unwrap();
+        assert_eq!(consumed, buf.len() - 5);
+        let buf = b"ANNOUNCE rtsp://example.com/test RTSP/1.0\r\n\
+                    CSeq: 2\r\n\
+                    Content-Type: application/sdp\r\n\
+                    Content-Length: 0\r\n\
+                    \r\n\
+                    v=0\r\n";
+        let (_, consumed) = Message::<&[u8]>::parse_with_options(buf, &options).unwrap();
+        assert_eq!(consumed, buf.len() - 5);
+    }
+
+    #[test]
+    fn test_content_length_lenient() {
+        for value in [" 10", "10 ", "+10", "\t+10 "] {
+            let mut buf = b"RTSP/2.0 200 OK\r\nCSeq: 1\r\nContent-Length:".to_vec();
+            buf.extend_from_slice(value.as_bytes());
+            buf.extend_from_slice(b"\r\n\r\n0123456789");
+
+            let (msg, consumed) = Message::<&[u8]>::parse(&buf).unwrap();
+            assert_eq!(consumed, buf.len(), "{}", value);
+            match msg {
+                Message::Response(response) => {
+                    assert_eq!(response.body(), b"0123456789");
+                    assert_eq!(
+                        response.typed_header::<crate::headers::ContentLength>(),
+                        Ok(Some(crate::headers::ContentLength::from(10)))
+                    );
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        for value in ["-10", "1 0", "+-10"] {
+            let mut buf = b"RTSP/2.0 200 OK\r\nCSeq: 1\r\nContent-Length: ".to_vec();
+            buf.extend_from_slice(value.as_bytes());
+            buf.extend_from_slice(b"\r\n\r\n0123456789");
+
+            assert!(
+                matches!(Message::<&[u8]>::parse(&buf), Err(ParseError::Error)),
+                "{}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_request_uri() {
+        let buf = b"DESCRIBE rtsp://example.com:99999/media RTSP/2.0\r\nCSeq: 1\r\n\r\n";
+
+        // The message itself is complete and valid
+        assert!(MessageRef::parse(buf).is_ok());
+
+        let err = Message::<Vec<u8>>::parse(buf).unwrap_err();
+        assert!(matches!(
+            err,
+            ParseError::InvalidUri(url::ParseError::InvalidPort)
+        ));
+        assert!(std::error::Error::source(&err).is_some());
+        assert_eq!(
+            err.to_string(),
+            format!("Invalid request URI: {}", url::ParseError::InvalidPort)
+        );
+    }
+
+    #[test]
+    fn test_response_content_length_states() {
+        let write = |response: &Response<&[u8]>| {
+            let mut data = Vec::new();
+            response.write(&mut data).unwrap();
+            String::from_utf8(data).unwrap()
+        };
+
+        // Omitted for empty bodies by default
+        let response = Response::builder(Version::V2_0, StatusCode::Ok)
+            .header(crate::headers::CSEQ, "1")
+            .build(&b""[..]);
+        assert!(response.header(&crate::headers::CONTENT_LENGTH).is_none());
+        assert_eq!(write(&response), "RTSP/2.0 200 Ok\r\nCSeq: 1\r\n\r\n");
+
+        // Zero for empty bodies if always inserted
+        let response = Response::builder(Version::V2_0, StatusCode::Ok)
+            .header(crate::headers::CSEQ, "1")
+            .always_content_length(true)
+            .build(&b""[..]);
+        assert_eq!(response.content_length(), Some(0));
+        assert_eq!(
+            write(&response),
+            "RTSP/2.0 200 Ok\r\nContent-Length: 0\r\nCSeq: 1\r\n\r\n"
+        );
+        let empty = Response::builder(Version::V2_0, StatusCode::Ok)
+            .always_content_length(true)
+            .empty();
+        assert_eq!(empty.content_length(), Some(0));
+
+        // Present for non-empty bodies, with or without the option
+        for always in [false, true] {
+            let response = Response::builder(Version::V2_0, StatusCode::Ok)
+                .header(crate::headers::CSEQ, "1")
+                .always_content_length(always)
+                .build(&b"0123456789"[..]);
+            assert_eq!(response.content_length(), Some(10));
+            assert_eq!(
+                write(&response),
+                "RTSP/2.0 200 Ok\r\nContent-Length: 10\r\nCSeq: 1\r\n\r\n0123456789"
+            );
+
+            // Unless explicitly omitted
+            let response = response.without_content_length();
+            assert_eq!(response.content_length(), None);
+            assert_eq!(
+                write(&response),
+                "RTSP/2.0 200 Ok\r\nCSeq: 1\r\n\r\n0123456789"
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_header_line() {
+        for buf in [
+            &b"OPTIONS * RTSP/2.0\r\nCSeq: 1\r\ngarbage\r\nSession: 12345\r\n\r\n"[..],
+            &b"OPTIONS * RTSP/2.0\r\nCSeq: 1\r\nDESCRIBE * RTSP/2.0\r\n\r\n"[..],
+            &b"RTSP/2.0 200 OK\r\nCSeq: 1\r\nRTSP/2.0 200 OK\r\n\r\n"[..],
+            &b"RTSP/2.0 200 OK\r\nCSeq 1\r\n\r\n"[..],
+        ] {
+            assert!(
+                matches!(Message::<&[u8]>::parse(buf), Err(ParseError::InvalidHeader)),
+                "{}",
+                String::from_utf8_lossy(buf)
+            );
+        }
+
+        // An incomplete header line still needs more data
+        assert!(matches!(
+            Message::<&[u8]>::parse(b"OPTIONS * RTSP/2.0\r\nCSeq: 1\r\nSess"),
+            Err(ParseError::Incomplete(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_head() {
+        let data = b"\r\nANNOUNCE rtsp://example.com/media.mp4 RTSP/2.0\r\n\
+            CSeq: 7\r\n\
+            Content-Type: application/sdp\r\n\
+            Content-Length: 300\r\n\
+            \r\n\
+            v=0\r\n";
+
+        // The body is only partially available
+        assert!(matches!(
+            Message::<Vec<u8>>::parse(data),
+            Err(ParseError::Incomplete(_))
+        ));
+
+        let (head, body_offset) = Message::parse_head(data).unwrap();
+        assert_eq!(&data[body_offset..], b"v=0\r\n");
+        assert_eq!(head.content_length(), Some(300));
+        let content_type = head.content_type().unwrap();
+        assert_eq!(
+            content_type.media_type,
+            crate::headers::MediaType::Application
+        );
+        assert_eq!(content_type.media_subtype, "sdp");
+        assert_eq!(
+            head.typed_header::<crate::headers::CSeq>().unwrap(),
+            Some(crate::headers::CSeq::from(7))
+        );
+        match head {
+            Head::Request(ref request) => {
+                assert_eq!(request.method(), Method::Announce);
+                assert_eq!(
+                    request.request_uri().map(Url::as_str),
+                    Some("rtsp://example.com/media.mp4")
+                );
+            }
+            _ => unreachable!(),
+        }
+
+        // Only the head is needed
+        let (head2, body_offset2) = Message::parse_head(&data[..body_offset]).unwrap();
+        assert_eq!(head, head2);
+        assert_eq!(body_offset, body_offset2);
+
+        let data = b"RTSP/2.0 200 OK\r\nCSeq: 7\r\n\r\n";
+        let (head, body_offset) = Message::parse_head(data).unwrap();
+        assert_eq!(body_offset, data.len());
+        assert_eq!(head.content_length(), None);
+        match head {
+            Head::Response(ref response) => assert_eq!(response.status(), StatusCode::Ok),
+            _ => unreachable!(),
+        }
+
+        // Same as for the complete message
+        let data = b"RTSP/2.0 200 OK\r\nCSeq: 7\r\nContent-Length: abc\r\n\r\n";
+        assert!(matches!(Message::parse_head(data), Err(ParseError::Error)));
+        assert!(matches!(
+            Message::<Vec<u8>>::parse(data),
+            Err(ParseError::Error)
+        ));
+
+        let data = b"RTSP/2.0 200 OK\r\nCSeq: 7\r\nContent-Length: 10\r\n";
+        assert!(matches!(
+            Message::parse_head(data),
+            Err(ParseError::Incomplete(_))
+        ));
+
+        let data = b"$\x01\x00\x04abcd";
+        assert!(matches!(Message::parse_head(data), Err(ParseError::Error)));
+
+        let data = b"RTSP/2.0 200 OK\nCSeq: 7\nSupported: play.basic,\n play.scale\nContent-Length: 4\n\nabcd";
+        assert!(Message::parse_head(data).is_err());
+        let options = ParseOptions::new()
+            .accept_lf_line_endings(true)
+            .preserve_raw_headers(true);
+        let (head, body_offset) = Message::parse_head_with_options(data, &options).unwrap();
+        assert_eq!(&data[body_offset..], b"abcd");
+        assert_eq!(
+            AsRef::<Headers>::as_ref(&head).raw_value(&crate::headers::SUPPORTED),
+            Some(&b"play.basic,\n play.scale"[..])
+        );
+
+        let options = ParseOptions::new().max_start_line_len(8);
+        assert!(matches!(
+            Message::parse_head_with_options(data, &options),
+            Err(ParseError::LimitExceeded)
+        ));
+    }
+
+    #[test]
+    fn test_lf_line_endings() {
+        let data = b"\n\r\nRTSP/2.0 200 OK\r\nCSeq: 7\nServer: Phony\n  Server/1.0\nContent-Length: 4\n\nabcdREMAINDER";
+
+        assert!(Message::<&[u8]>::parse(data).is_err());
+
+        let options = ParseOptions::new().accept_lf_line_endings(true);
+        let (msg, consumed) = Message::<&[u8]>::parse_with_options(data, &options).unwrap();
+        assert_eq!(consumed, data.len() - b"REMAINDER".len());
+        match msg {
+            Message::Response(ref response) => {
+                assert_eq!(response.reason_phrase(), "OK");
+                assert_eq!(
+                    response.header(&crate::headers::SERVER).unwrap().as_str(),
+                    "Phony Server/1.0"
+                );
+                assert_eq!(response.body(), b"abcd");
+            }
+            _ => unreachable!(),
+        }
+
+        // Still incomplete without the empty line after the headers
+        assert!(matches!(
+            Message::<&[u8]>::parse_with_options(b"OPTIONS * RTSP/2.0\nCSeq: 1\n", &options),
+            Err(ParseError::Incomplete(_))
+        ));
+
+        // The start line limit also applies to LF-terminated lines
+        let options = options.max_start_line_len(18);
+        assert!(
+            Message::<&[u8]>::parse_with_options(b"OPTIONS * RTSP/2.0\nCSeq: 1\n\n", &options)
+                .is_ok()
+        );
+        let options = options.max_start_line_len(17);
+        assert!(matches!(
+            Message::<&[u8]>::parse_with_options(b"OPTIONS * RTSP/2.0\nCSeq: 1\n\n", &options),
+            Err(ParseError::LimitExceeded)
+        ));
+    }
 }
@@ -39,39 +39,48 @@ fn header<'a, W: Write + 'a>(header: HeaderRef<'a>) -> impl SerializeFn<W> + 'a
     tuple((string(header.name), string(": "), string(header.value)))
 }
 
-fn headers<'a, W: Write + 'a>(headers: TinyVec<[HeaderRef<'a>; 16]>) -> impl SerializeFn<W> + 'a {
+fn headers<'a, W: Write + 'a, I>(headers: I, line_ending: LineEnding) -> impl SerializeFn<W> + 'a
+where
+    I: Iterator<Item = HeaderRef<'a>> + Clone + 'a,
+{
     move |mut w: WriteContext<W>| {
-        let headers = headers.clone();
-        for h in headers {
+        for h in headers.clone() {
             w = header(h)(w)?;
-            w = string("\r\n")(w)?;
+            w = string(line_ending.as_str())(w)?;
         }
 
         Ok(w)
     }
 }
 
-fn request_line<'a, W: Write + 'a>(request_line: RequestLine<'a>) -> impl SerializeFn<W> + 'a {
+fn request_line<'a, W: Write + 'a>(
+    request_line: RequestLine<'a>,
+    line_ending: LineEnding,
+) -> impl SerializeFn<W> + 'a {
     tuple((
         method(request_line.method),
         string(" "),
         string(request_line.request_uri.unwrap_or("*")),
         string(" "),
         rtsp_version(request_line.version),
-        string("\r\n"),
+        string(line_ending.as_str()),
     ))
 }
 
-pub(crate) fn request<'a, W: Write + 'a>(request: RequestRef<'a>) -> impl SerializeFn<W> + 'a {
+pub(crate) fn request<'a, W: Write + 'a, I>(
+    request: RequestLine<'a>,
+    request_headers: I,
+    body: &'a [u8],
+    line_ending: LineEnding,
+) -> impl SerializeFn<W> + 'a
+where
+    I: Iterator<Item = HeaderRef<'a>> + Clone + 'a,
+{
     tuple((
-        request_line(RequestLine {
-            method: request.method,
-            request_uri: request.request_uri,
-            version: request.version,
-        }),
-        headers(request.headers),
-        string("\r\n"),
-        slice(request.body),
+        request_line(request, line_ending),
+        headers(request_headers, line_ending),
+        string(line_ending.as_str()),
+        slice(body),
     ))
 }
 
@@ -82,27 +91,34 @@ fn status_code<W: Write>(status: StatusCode) -> impl SerializeFn<W> {
     }
 }
 
-fn status_line<'a, W: Write + 'a>(status_line: StatusLine<'a>) -> impl SerializeFn<W> + 'a {
+fn status_line<'a, W: Write + 'a>(
+    status_line: StatusLine<'a>,
+    line_ending: LineEnding,
+) -> impl SerializeFn<W> + 'a {
     tuple((
         rtsp_version(status_line.version),
         string(" "),
         status_code(status_line.status),
         string(" "),
         string(status_line.reason_phrase),
-        string("\r\n"),
+        string(line_ending.as_str()),
     ))
 }
 
-pub(crate) fn response<'a, W: Write + 'a>(response: ResponseRef<'a>) -> impl SerializeFn<W> + 'a {
+pub(crate) fn response<'a, W: Write + 'a, I>(
+    response: StatusLine<'a>,
+    response_headers: I,
+    body: &'a [u8],
+    line_ending: LineEnding,
+) -> impl SerializeFn<W> + 'a
+where
+    I: Iterator<Item = HeaderRef<'a>> + Clone + 'a,
+{
     tuple((
-        status_line(StatusLine {
-            version: response.version,
-            status: response.status,
-            reason_phrase: response.reason_phrase,
-        }),
-        headers(response.headers),
-        string("\r\n"),
-        slice(response.body),
+        status_line(response, line_ending),
+        headers(response_headers, line_ending),
+        string(line_ending.as_str()),
+        slice(body),
     ))
 }
 
@@ -115,31 +131,51 @@ pub(crate) fn data<'a, W: Write + 'a>(data: DataRef<'a>) -> impl SerializeFn<W>
     ))
 }
 
-pub(crate) fn message<'a, W: Write + 'a>(message: MessageRef<'a>) -> impl SerializeFn<W> + 'a {
-    move |w: WriteContext<W>| {
-        let message = message.clone();
-        match message {
-            MessageRef::Request(req) => request(req)(w),
-            MessageRef::Response(resp) => response(resp)(w),
-            MessageRef::Data(d) => data(d)(w),
-        }
+pub(crate) fn write<W: Write>(serializer: impl SerializeFn<W>, w: W) -> Result<(), WriteError> {
+    match cf::gen_simple(serializer, w) {
+        Ok(_) => Ok(()),
+        Err(GenError::IoError(io)) => Err(WriteError::IoError(io)),
+        // This case can't really happen with our serializer!
+        Err(err) => panic!("Failed to write message: {:?}", err),
+    }
+}
+
+pub(crate) fn write_len(serializer: impl SerializeFn<std::io::Sink>) -> u64 {
+    match cf::gen(serializer, std::io::sink()) {
+        Ok((_w, pos)) => pos,
+        // This case can't really happen with our serializer!
+        Err(err) => panic!("Failed to calculate write length: {:?}", err),
+    }
+}
+
+pub(crate) fn write_to_slice<'b>(
+    serializer: impl SerializeFn<&'b mut [u8]>,
+    buf: &'b mut [u8],
+) -> Result<usize, WriteError> {
+    match cf::gen(serializer, buf) {
+        Ok((_w, pos)) => Ok(pos as usize),
+        Err(GenError::IoError(io)) => Err(WriteError::IoError(io)),
+        // This case can't really happen with our serializer!
+        Err(err) => panic!("Failed to write message: {:?}", err),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use tinyvec::tiny_vec;
 
     #[test]
     fn test_request_line() {
         let mut v = vec![];
         cf::gen_simple(
-            request_line(RequestLine {
-                method: MethodRef::Options,
-                request_uri: Some("rtsp://media.example.com/movie/twister.3gp"),
-                version: Version::V2_0,
-            }),
+            request_line(
+                RequestLine {
+                    method: MethodRef::Options,
+                    request_uri: Some("rtsp://media.example.com/movie/twister.3gp"),
+                    version: Version::V2_0,
+                },
+                LineEnding::CrLf,
+            ),
             &mut v,
         )
         .unwrap();
@@ -154,11 +190,14 @@ mod tests {
     fn test_request_line_without_uri() {
         let mut v = vec![];
         cf::gen_simple(
-            request_line(RequestLine {
-                method: MethodRef::Options,
-                request_uri: None,
-                version: Version::V2_0,
-            }),
+            request_line(
+                RequestLine {
+                    method: MethodRef::Options,
+                    request_uri: None,
+                    version: Version::V2_0,
+                },
+                LineEnding::CrLf,
+            ),
             &mut v,
         )
         .unwrap();
@@ -170,11 +209,14 @@ mod tests {
     fn test_status_line() {
         let mut v = vec![];
         cf::gen_simple(
-            status_line(StatusLine {
-                version: Version::V2_0,
-                status: StatusCode::Ok,
-                reason_phrase: "All Good",
-            }),
+            status_line(
+                StatusLine {
+                    version: Version::V2_0,
+                    status: StatusCode::Ok,
+                    reason_phrase: "All Good",
+                },
+                LineEnding::CrLf,
+            ),
             &mut v,
         )
         .unwrap();
@@ -186,26 +228,31 @@ mod tests {
     fn test_options() {
         let mut v = vec![];
         cf::gen_simple(
-            request(RequestRef {
-                method: MethodRef::Options,
-                version: Version::V2_0,
-                request_uri: Some("rtsp://media.example.com/movie/twister.3gp"),
-                headers: tiny_vec!(
+            request(
+                RequestLine {
+                    method: MethodRef::Options,
+                    version: Version::V2_0,
+                    request_uri: Some("rtsp://media.example.com/movie/twister.3gp"),
+                },
+                [
                     HeaderRef {
                         name: "CSeq",
-                        value: "1"
+                        value: "1",
                     },
                     HeaderRef {
                         name: "Supported",
-                        value: "play.basic, play.scale"
+                        value: "play.basic, play.scale",
                     },
                     HeaderRef {
                         name: "User-Agent",
-                        value: "PhonyClient/1.2"
-                    }
-                ),
-                body: &[],
-            }),
+                        value: "PhonyClient/1.2",
+                    },
+                ]
+                .iter()
+                .cloned(),
+                &[],
+                LineEnding::CrLf,
+            ),
             &mut v,
         )
         .unwrap();
@@ -224,26 +271,31 @@ User-Agent: PhonyClient/1.2\r\n\
     fn test_options_without_uri() {
         let mut v = vec![];
         cf::gen_simple(
-            request(RequestRef {
-                method: MethodRef::Options,
-                version: Version::V2_0,
-                request_uri: None,
-                headers: tiny_vec!(
+            request(
+                RequestLine {
+                    method: MethodRef::Options,
+                    version: Version::V2_0,
+                    request_uri: None,
+                },
+                [
                     HeaderRef {
                         name: "CSeq",
-                        value: "1"
+                        value: "1",
                     },
                     HeaderRef {
                         name: "Supported",
-                        value: "play.basic, play.scale"
+                        value: "play.basic, play.scale",
                     },
                     HeaderRef {
                         name: "User-Agent",
-                        value: "PhonyClient/1.2"
-                    }
-                ),
-                body: &[],
-            }),
+                        value: "PhonyClient/1.2",
+                    },
+                ]
+                .iter()
+                .cloned(),
+                &[],
+                LineEnding::CrLf,
+            ),
             &mut v,
         )
         .unwrap();
@@ -262,30 +314,35 @@ User-Agent: PhonyClient/1.2\r\n\
     fn test_options_with_body() {
         let mut v = vec![];
         cf::gen_simple(
-            request(RequestRef {
-                method: MethodRef::Options,
-                version: Version::V2_0,
-                request_uri: Some("rtsp://media.example.com/movie/twister.3gp"),
-                headers: tiny_vec!(
+            request(
+                RequestLine {
+                    method: MethodRef::Options,
+                    version: Version::V2_0,
+                    request_uri: Some("rtsp://media.example.com/movie/twister.3gp"),
+                },
+                [
                     HeaderRef {
                         name: "CSeq",
-                        value: "1"
+                        value: "1",
                     },
                     HeaderRef {
                         name: "Supported",
-                        value: "play.basic, play.scale"
+                        value: "play.basic, play.scale",
                     },
                     HeaderRef {
                         name: "User-Agent",
-                        value: "PhonyClient/1.2"
+                        value: "PhonyClient/1.2",
                     },
                     HeaderRef {
                         name: "Content-Length",
-                        value: "10"
-                    }
-                ),
-                body: &b"0123456789"[..],
-            }),
+                        value: "10",
+                    },
+                ]
+                .iter()
+                .cloned(),
+                &b"0123456789"[..],
+                LineEnding::CrLf,
+            ),
             &mut v,
         )
         .unwrap();
@@ -306,30 +363,35 @@ Content-Length: 10\r\n\
     fn test_options_with_body_without_uri() {
         let mut v = vec![];
         cf::gen_simple(
-            request(RequestRef {
-                method: MethodRef::Options,
-                version: Version::V2_0,
-                request_uri: None,
-                headers: tiny_vec!(
+            request(
+                RequestLine {
+                    method: MethodRef::Options,
+                    version: Version::V2_0,
+                    request_uri: None,
+                },
+                [
                     HeaderRef {
                         name: "CSeq",
-                        value: "1"
+                        value: "1",
                     },
                     HeaderRef {
                         name: "Supported",
-                        value: "play.basic, play.scale"
+                        value: "play.basic, play.scale",
                     },
                     HeaderRef {
                         name: "User-Agent",
-                        value: "PhonyClient/1.2"
+                        value: "PhonyClient/1.2",
                     },
                     HeaderRef {
                         name: "Content-Length",
-                        value: "10"
-                    }
-                ),
-                body: &b"0123456789"[..],
-            }),
+                        value: "10",
+                    },
+                ]
+                .iter()
+                .cloned(),
+                &b"0123456789"[..],
+                LineEnding::CrLf,
+            ),
             &mut v,
         )
         .unwrap();
@@ -349,26 +411,31 @@ Content-Length: 10\r\n\
     fn test_ok() {
         let mut v = vec![];
         cf::gen_simple(
-            response(ResponseRef {
-                version: Version::V2_0,
-                status: StatusCode::Ok,
-                reason_phrase: "All Good",
-                headers: tiny_vec!(
+            response(
+                StatusLine {
+                    version: Version::V2_0,
+                    status: StatusCode::Ok,
+                    reason_phrase: "All Good",
+                },
+                [
                     HeaderRef {
                         name: "CSeq",
-                        value: "1"
+                        value: "1",
                     },
                     HeaderRef {
                         name: "Supported",
-                        value: "play.basic, play.scale"
+                        value: "play.basic, play.scale",
                     },
                     HeaderRef {
                         name: "User-Agent",
-                        value: "PhonyClient/1.2"
-                    }
-                ),
-                body: &[],
-            }),
+                        value: "PhonyClient/1.2",
+                    },
+                ]
+                .iter()
+                .cloned(),
+                &[],
+                LineEnding::CrLf,
+            ),
             &mut v,
         )
         .unwrap();
@@ -387,30 +454,35 @@ User-Agent: PhonyClient/1.2\r\n\
     fn test_ok_with_body() {
         let mut v = vec![];
         cf::gen_simple(
-            response(ResponseRef {
-                version: Version::V2_0,
-                status: StatusCode::Ok,
-                reason_phrase: "All Good",
-                headers: tiny_vec!(
+            response(
+                StatusLine {
+                    version: Version::V2_0,
+                    status: StatusCode::Ok,
+                    reason_phrase: "All Good",
+                },
+                [
                     HeaderRef {
                         name: "CSeq",
-                        value: "1"
+                        value: "1",
                     },
                     HeaderRef {
                         name: "Supported",
-                        value: "play.basic, play.scale"
+                        value: "play.basic, play.scale",
                     },
                     HeaderRef {
                         name: "User-Agent",
-                        value: "PhonyClient/1.2"
+                        value: "PhonyClient/1.2",
                     },
                     HeaderRef {
                         name: "Content-Length",
-                        value: "10"
-                    }
-                ),
-                body: &b"0123456789"[..],
-            }),
+                        value: "10",
+                    },
+                ]
+                .iter()
+                .cloned(),
+                &b"0123456789"[..],
+                LineEnding::CrLf,
+            ),
             &mut v,
         )
         .unwrap();
@@ -0,0 +1,176 @@
+// Copyright (C) 2020 Sebastian Dröge <sebastian@centricular.com>
+//
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use super::*;
+
+use crate::headers::{
+    Accept, CSeq, ContentLength, ContentType, HeaderParseError, Public, Range, Require, RtpInfos,
+    Scale, Session, Speed, Supported, Transports, TypedHeader,
+};
+
+/// Summary of a message and all typed headers that could be recognized.
+///
+/// This is mostly useful for debugging and logging, see
+/// [`Message::describe`](enum.Message.html#method.describe).
+///
+/// Headers that are not present are `None`. Headers that are present but fail to parse are also
+/// `None` and the corresponding error is collected in `errors`.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct MessageSummary {
+    /// Kind of the message.
+    pub kind: FrameKind,
+    /// RTSP version of a request or response.
+    pub version: Option<Version>,
+    /// Method of a request.
+    pub method: Option<Method>,
+    /// Request URI of a request.
+    pub request_uri: Option<Url>,
+    /// Status code of a response.
+    pub status: Option<StatusCode>,
+    /// Channel id of a data message.
+    pub channel_id: Option<u8>,
+    /// `CSeq` header.
+    pub cseq: Option<CSeq>,
+    /// `Session` header.
+    pub session: Option<Session>,
+    /// `Transport` header.
+    pub transports: Option<Transports>,
+    /// `Range` header.
+    pub range: Option<Range>,
+    /// `RTP-Info` header.
+    pub rtp_info: Option<RtpInfos>,
+    /// `Scale` header.
+    pub scale: Option<Scale>,
+    /// `Speed` header.
+    pub speed: Option<Speed>,
+    /// `Accept` header.
+    pub accept: Option<Accept>,
+    /// `Content-Type` header.
+    pub content_type: Option<ContentType>,
+    /// `Content-Length` header.
+    pub content_length: Option<ContentLength>,
+    /// `Public` header.
+    pub public: Option<Public>,
+    /// `Require` header.
+    pub require: Option<Require>,
+    /// `Supported` header.
+    pub supported: Option<Supported>,
+    /// Errors of all headers that failed to parse, in the order of the fields above.
+    pub errors: Vec<HeaderParseError>,
+}
+
+impl MessageSummary {
+    pub(crate) fn new<Body>(msg: &Message<Body>) -> Self {
+        let (version, method, request_uri, status, channel_id, msg_headers) = match msg {
+            Message::Request(request) => (
+                Some(request.version()),
+                Some(request.method().clone()),
+                request.request_uri().cloned(),
+                None,
+                None,
+                Some(request.as_ref()),
+            ),
+            Message::Response(response) => (
+                Some(response.version()),
+                None,
+                None,
+                Some(response.status()),
+                None,
+                Some(response.as_ref()),
+            ),
+            Message::Data(data) => (None, None, None, None, Some(data.channel_id()), None),
+        };
+
+        let mut errors = Vec::new();
+
+        MessageSummary {
+            kind: msg.frame_kind(),
+            version,
+            method,
+            request_uri,
+            status,
+            channel_id,
+            cseq: typed(msg_headers, headers::CSEQ, &mut errors),
+            session: typed(msg_headers, headers::SESSION, &mut errors),
+            transports: typed(msg_headers, headers::TRANSPORT, &mut errors),
+            range: typed(msg_headers, headers::RANGE, &mut errors),
+            rtp_info: typed(msg_headers, headers::RTP_INFO, &mut errors),
+            scale: typed(msg_headers, headers::SCALE, &mut errors),
+            speed: typed(msg_headers, headers::SPEED, &mut errors),
+            accept: typed(msg_headers, headers::ACCEPT, &mut errors),
+            content_type: typed(msg_headers, headers::CONTENT_TYPE, &mut errors),
+            content_length: typed(msg_headers, headers::CONTENT_LENGTH, &mut errors),
+            public: typed(msg_headers, headers::PUBLIC, &mut errors),
+            require: typed(msg_headers, headers::REQUIRE, &mut errors),
+            supported: typed(msg_headers, headers::SUPPORTED, &mut errors),
+            errors,
+        }
+    }
+}
+
+// Parses a typed header and collects the error if that fails.
+fn typed<H: TypedHeader>(
+    headers: Option<&Headers>,
+    name: HeaderName,
+    errors: &mut Vec<HeaderParseError>,
+) -> Option<H> {
+    match H::from_headers(headers?) {
+        Ok(header) => header,
+        Err(err) => {
+            errors.push(if err.header_name().is_some() {
+                err
+            } else {
+                HeaderParseError::for_header(name)
+            });
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_setup() {
+        let request = Request::builder(Method::Setup, Version::V2_0)
+            .request_uri(Url::parse("rtsp://example.com/media/trackID=1").unwrap())
+            .header(headers::CSEQ, "3")
+            .header(headers::SESSION, "12345678")
+            .header(headers::TRANSPORT, "RTP/AVP/TCP;unicast;interleaved=0-1")
+            .header(headers::SCALE, "fast")
+            .header(headers::USER_AGENT, "PhonyClient/1.2")
+            .empty();
+
+        let summary = Message::from(request).describe();
+        assert_eq!(summary.kind, FrameKind::Request);
+        assert_eq!(summary.version, Some(Version::V2_0));
+        assert_eq!(summary.method, Some(Method::Setup));
+        assert_eq!(
+            summary.request_uri.as_ref().map(Url::as_str),
+            Some("rtsp://example.com/media/trackID=1")
+        );
+        assert_eq!(summary.status, None);
+        assert_eq!(summary.cseq.map(u32::from), Some(3));
+        assert_eq!(summary.session.as_deref(), Some("12345678"));
+        assert_eq!(
+            summary.transports.unwrap().channel_pairs(),
+            vec![(0, Some(1))]
+        );
+        assert!(summary.range.is_none());
+        assert!(summary.content_length.is_none());
+
+        // The invalid `Scale` header is collected but does not fail the summary
+        assert!(summary.scale.is_none());
+        assert_eq!(summary.errors.len(), 1);
+        assert_eq!(summary.errors[0].header_name(), Some(&headers::SCALE));
+
+        let summary = Message::from(Data::new(2, Vec::<u8>::new())).describe();
+        assert_eq!(summary.kind, FrameKind::Data);
+        assert_eq!(summary.channel_id, Some(2));
+        assert!(summary.cseq.is_none());
+        assert!(summary.errors.is_empty());
+    }
+}